@@ -2,3 +2,95 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+// Longest reason that can be recorded when cancelling a payout schedule
+#[constant]
+pub const MAX_CANCEL_REASON_LEN: usize = 64;
+
+// Most recipients a payout schedule can share its amount with
+#[constant]
+pub const MAX_PAYOUT_SPLITS: usize = 5;
+
+// Decimals of USD amounts on oracle-priced payout schedules
+#[constant]
+pub const USD_DECIMALS: u8 = 6;
+
+// Decimals of lamports in one SOL
+#[constant]
+pub const SOL_DECIMALS: u8 = 9;
+
+// Most amount tiers an approval policy can hold
+#[constant]
+pub const MAX_APPROVAL_TIERS: usize = 5;
+
+// Most approvers an approval request can record
+#[constant]
+pub const MAX_REQUEST_APPROVERS: usize = 10;
+
+// Most rules an outflow policy can hold
+#[constant]
+pub const MAX_POLICY_RULES: usize = 6;
+
+// Most destinations an allowed-destinations rule can list
+#[constant]
+pub const MAX_POLICY_DESTINATIONS: usize = 8;
+
+// How long an approval request can be approved and spent, counted from its creation
+// or, for spending proposals, from the end of the vote
+#[constant]
+pub const APPROVAL_REQUEST_LIFETIME_SECONDS: i64 = 30 * 86_400;
+
+// Approvers an emergency withdrawal needs to go below the reserve floor
+#[constant]
+pub const MIN_FLOOR_OVERRIDE_APPROVALS: usize = 2;
+
+// Most payouts a treasury's pending payout queue can hold
+#[constant]
+pub const MAX_QUEUED_PAYOUTS: usize = 16;
+
+// Longest invoice URI a payment request can carry
+#[constant]
+pub const MAX_INVOICE_URI_LEN: usize = 200;
+
+// Most status changes a payment request can record, its submission included
+#[constant]
+pub const MAX_PAYMENT_REQUEST_EVENTS: usize = 8;
+
+// Most milestones a grant can hold
+#[constant]
+pub const MAX_MILESTONES: usize = 8;
+
+// Most approvers a milestone can have
+#[constant]
+pub const MAX_MILESTONE_APPROVERS: usize = 5;
+
+// Member permissions, combined into `Member::permissions`
+#[constant]
+pub const PERMISSION_CREATE_SCHEDULE: u16 = 1 << 0;
+#[constant]
+pub const PERMISSION_EXECUTE_PAYOUT: u16 = 1 << 1;
+#[constant]
+pub const PERMISSION_MANAGE_RECIPIENTS: u16 = 1 << 2;
+#[constant]
+pub const PERMISSION_MANAGE_VAULTS: u16 = 1 << 3;
+#[constant]
+pub const PERMISSION_EMERGENCY: u16 = 1 << 4;
+#[constant]
+pub const PERMISSION_CONFIG: u16 = 1 << 5;
+#[constant]
+pub const PERMISSION_APPROVE: u16 = 1 << 6;
+#[constant]
+pub const PERMISSION_ALL: u16 = (1 << 7) - 1;
+
+// What the treasurer could always do, kept so existing treasuries work without members
+#[constant]
+pub const TREASURER_PERMISSIONS: u16 = PERMISSION_CREATE_SCHEDULE
+    | PERMISSION_EXECUTE_PAYOUT
+    | PERMISSION_MANAGE_RECIPIENTS
+    | PERMISSION_MANAGE_VAULTS;
+
+// Permissions only the admin, or a member already holding them, can grant or revoke
+#[constant]
+pub const PRIVILEGED_PERMISSIONS: u16 = PERMISSION_EMERGENCY
+    | PERMISSION_CONFIG
+    | PERMISSION_APPROVE;
@@ -35,4 +35,162 @@ pub enum ErrorCode {
     NoWithdrawableAmount,
     #[msg("Stream cannot be cancelled")]
     StreamNotCancelable,
-}
\ No newline at end of file
+    #[msg("The treasury is winding down and cannot accept new schedules")]
+    TreasuryWindingDown,
+    #[msg("The treasury must be winding down before it can be decommissioned")]
+    TreasuryNotWindingDown,
+    #[msg("The treasury still has open schedules, streams, escrows, grants rounds, bounties or token vaults")]
+    OpenObligationsRemaining,
+    #[msg("The successor account does not match the treasury configuration")]
+    InvalidSuccessor,
+    #[msg("The recipient still has open schedules or streams; deactivate or reactivate the recipient instead")]
+    RecipientHasOpenSchedules,
+    #[msg("Every active schedule and stream of the recipient must be provided")]
+    IncompleteRecipientSchedules,
+    #[msg("The recipient's whitelist entry has expired and must be renewed")]
+    RecipientExpired,
+    #[msg("The whitelist expiry must be in the future")]
+    InvalidExpiry,
+    #[msg("The treasury requires an attester signature for this recipient")]
+    MissingAttestation,
+    #[msg("The attester signature does not cover this treasury, recipient and expiry")]
+    InvalidAttestation,
+    #[msg("The permission bitmask contains unknown permissions")]
+    InvalidPermissions,
+    #[msg("Only the recipient can change their payout destination")]
+    NotRecipient,
+    #[msg("The treasury requires a recipient manager to co-sign payout destination changes")]
+    MissingPayoutCosign,
+    #[msg("Funds can only be sent to the recipient's bound payout destination")]
+    InvalidPayoutDestination,
+    #[msg("The recurrence rule has an out of range day, weekday or week interval")]
+    InvalidRecurrence,
+    #[msg("The schedule's end time must not be before its start time")]
+    InvalidEndTime,
+    #[msg("The payout schedule has completed or been cancelled")]
+    ScheduleClosed,
+    #[msg("The cancel reason is too long")]
+    CancelReasonTooLong,
+    #[msg("The payout splits or the accounts provided for them are invalid")]
+    InvalidPayoutSplit,
+    #[msg("The oracle pricing needs a staleness limit and basis points of at most 10000")]
+    InvalidOraclePricing,
+    #[msg("The price feed does not match the schedule or holds no trading price")]
+    InvalidPriceFeed,
+    #[msg("The price feed has not been updated recently enough")]
+    StalePrice,
+    #[msg("The price feed's confidence interval is too wide")]
+    PriceConfidenceTooWide,
+    #[msg("The price has moved too far from its moving average")]
+    PriceSlippageExceeded,
+    #[msg("A per period cap needs a period length")]
+    InvalidRecipientCaps,
+    #[msg("This payout would exceed one of the recipient's caps")]
+    RecipientCapExceeded,
+    #[msg("Payouts to the recipient are held until their cooling period ends")]
+    RecipientCoolingDown,
+    #[msg("Approval tiers must ascend and need at most MAX_REQUEST_APPROVERS approvals")]
+    InvalidApprovalPolicy,
+    #[msg("The amount is above every tier of the treasury's approval policy")]
+    AmountAboveApprovalTiers,
+    #[msg("The approval policy requires an approval request for this amount")]
+    ApprovalRequired,
+    #[msg("The approval request does not match this treasury, action and amount, or has been used")]
+    ApprovalMismatch,
+    #[msg("The approval request does not have the approvals this outflow requires")]
+    InsufficientApprovals,
+    #[msg("The approval request's delay has not passed yet")]
+    ApprovalDelayPending,
+    #[msg("The signer has already approved this request")]
+    AlreadyApproved,
+    #[msg("The outflow policy has too many rules or a rule is out of range")]
+    InvalidPolicy,
+    #[msg("The outflow exceeds the policy's per-transaction maximum")]
+    TransactionLimitExceeded,
+    #[msg("The outflow would exceed one of the policy's period caps")]
+    PeriodCapExceeded,
+    #[msg("The policy does not allow outflows to this destination")]
+    DestinationNotAllowed,
+    #[msg("The policy does not allow outflows at this time")]
+    OutsideAllowedHours,
+    #[msg("The outflow would leave less than the policy's minimum reserve")]
+    MinimumReserveBreached,
+    #[msg("The outflow would take the balance below the asset's reserve floor")]
+    ReserveFloorBreached,
+    #[msg("The outflow would leave the treasury account below its rent-exempt minimum")]
+    RentExemptionBreached,
+    #[msg("The pending payout queue is full")]
+    PayoutQueueFull,
+    #[msg("The accounts passed do not match the queued payouts in order")]
+    InvalidQueuedPayout,
+    #[msg("A keeper tip needs a tip period")]
+    InvalidKeeperTip,
+    #[msg("Claim mode needs a SOL schedule without splits whose payouts fall due at distinct times")]
+    InvalidClaimWindow,
+    #[msg("Claim mode schedules are only paid through claims, and other schedules only through execution")]
+    PayoutModeMismatch,
+    #[msg("The payment request's amount, invoice URI or approved amount is invalid")]
+    InvalidPaymentRequest,
+    #[msg("The payment request has been paid or rejected, or has no room to record another partial approval")]
+    PaymentRequestClosed,
+    #[msg("Grants need 1 to MAX_MILESTONES milestones with amounts, ascending future deadlines and enough distinct approvers")]
+    InvalidMilestones,
+    #[msg("Every earlier milestone must be released or reclaimed first")]
+    MilestoneOutOfOrder,
+    #[msg("The milestone is not at the stage this action needs")]
+    InvalidMilestoneStatus,
+    #[msg("The milestone's deadline has passed")]
+    MilestoneDeadlinePassed,
+    #[msg("The milestone's deadline has not passed yet")]
+    MilestoneDeadlineNotPassed,
+    #[msg("Escrows need an amount, a future release time and an arbitrator other than the vendor")]
+    InvalidEscrow,
+    #[msg("The escrow is not at the stage this action needs")]
+    InvalidEscrowStatus,
+    #[msg("The escrow's release time has passed, so it can no longer be disputed")]
+    DisputeWindowClosed,
+    #[msg("Grants rounds need a start before their end, an end in the future and a matching pool")]
+    InvalidGrantsRound,
+    #[msg("The grants round is not taking registrations or contributions")]
+    RoundNotOpen,
+    #[msg("The grants round is not at the stage this action needs")]
+    InvalidRoundStatus,
+    #[msg("The round projects passed are neither the round's next projects in order nor ones with a deferred match")]
+    InvalidRoundProject,
+    #[msg("Contributions need an amount and cannot come from the project itself")]
+    InvalidContribution,
+    #[msg("Bounties need a reward and a future deadline")]
+    InvalidBounty,
+    #[msg("The bounty has been awarded or has expired")]
+    BountyClosed,
+    #[msg("The bounty's deadline has passed")]
+    BountyDeadlinePassed,
+    #[msg("The bounty's deadline has not passed yet")]
+    BountyDeadlineNotPassed,
+    #[msg("Governance needs a mint, a vault of that mint, a quorum, a threshold of at most 10000 basis points and a voting period")]
+    InvalidGovernance,
+    #[msg("Token-holder voting is not enabled for this treasury")]
+    GovernanceDisabled,
+    #[msg("Only votable spending actions with an amount can be proposed, by holders with deposited tokens")]
+    InvalidProposal,
+    #[msg("The proposal's voting period has ended or it has been decided")]
+    VotingClosed,
+    #[msg("The proposal's voting period has not ended yet")]
+    VotingNotEnded,
+    #[msg("The voter has no deposited voting weight")]
+    NoVotingWeight,
+    #[msg("Deposited tokens stay locked until the voter's latest vote ends")]
+    VotingTokensLocked,
+    #[msg("The voter's weight is delegated; revoke the delegation first")]
+    VotesDelegated,
+    #[msg("Delegations need deposited weight and a delegate other than the voter, and only count for requests created after them")]
+    InvalidDelegation,
+    #[msg("The delegated weight passed is below the treasury's delegate threshold")]
+    InsufficientDelegatedWeight,
+    #[msg("The approval request has expired")]
+    ApprovalRequestExpired,
+    #[msg("Oracle-priced schedules cannot be held to approval tiers set in the paid asset's units")]
+    OraclePricingUnderApprovalPolicy,
+    #[msg("The project's share of the matching pool overflowed")]
+    MatchOverflow,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::DeactivationPolicy;
+
+/// Emitted when a recipient is deactivated together with everything they own
+#[event]
+pub struct RecipientDeactivated {
+    pub treasury: Pubkey,
+    pub recipient: Pubkey,
+    pub schedules_paused: u32,
+    pub streams_paused: u32,
+    pub clawed_back_amount: u64,
+    pub policy: DeactivationPolicy,
+    pub timestamp: i64,
+}
+
+/// Emitted when a recipient and their schedules and streams are resumed
+#[event]
+pub struct RecipientReactivated {
+    pub treasury: Pubkey,
+    pub recipient: Pubkey,
+    pub schedules_resumed: u32,
+    pub streams_resumed: u32,
+    pub timestamp: i64,
+}
@@ -0,0 +1,150 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    bounty_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct AcceptSolBountyClaim<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bounty",
+            treasury.key().as_ref(),
+            bounty_id.to_le_bytes().as_ref(),
+        ],
+        bump = bounty.bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        seeds = [
+            b"bounty_claim",
+            bounty.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump = bounty_claim.bump
+    )]
+    pub bounty_claim: Account<'info, BountyClaim>,
+
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            reviewer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[writable]` recipient: [Recipient] The hunter whose claim is accepted
+/// 3. `[]` bounty_claim: [BountyClaim] The accepted claim
+/// 4. `[writable]` payout_address: [AccountInfo] The hunter's bound payout address
+/// 5. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 6. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission
+/// 7. `[]` authority_member: [Member] (optional) Membership of the reviewer
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<AcceptSolBountyClaim>,
+) -> Result<()> {
+    // Verify reviewer holds the execute payout permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.reviewer.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_EXECUTE_PAYOUT,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Verify the bounty is an open SOL bounty, claimed by its deadline
+    let bounty = &ctx.accounts.bounty;
+    bounty.validate_acceptable(&ctx.accounts.bounty_claim)?;
+    if bounty.token_mint.is_some() {
+        return Err(crate::error::ErrorCode::InvalidBounty.into());
+    }
+    let reward = bounty.reward;
+    
+    // The reward goes through the same checks as any other payout
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, reward, current_time)?;
+    
+    // The treasury account stays rent-exempt; the reserve floor was checked when the reward was reserved
+    crate::utils::validate_reserved_sol_outflow(&ctx.accounts.treasury, reward)?;
+    
+    // The reserved reward left the booked balance when the bounty was
+    // posted, so it counts toward the balance the payout leaves
+    let outflow = Outflow {
+        amount: reward,
+        destinations: vec![ctx.accounts.payout_address.key()],
+        balance: ctx.accounts.treasury.total_balance.saturating_add(reward),
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Transfer SOL
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? -= reward;
+    **ctx.accounts.payout_address.to_account_info().try_borrow_mut_lamports()? += reward;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_bounties = treasury.open_bounties.saturating_sub(1);
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(reward, current_time);
+    
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.status = BountyStatus::Awarded;
+    bounty.winner = Some(recipient.key());
+    
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(
+    bounty_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct AcceptTokenBountyClaim<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bounty",
+            treasury.key().as_ref(),
+            bounty_id.to_le_bytes().as_ref(),
+        ],
+        bump = bounty.bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        seeds = [
+            b"bounty_claim",
+            bounty.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump = bounty_claim.bump
+    )]
+    pub bounty_claim: Account<'info, BountyClaim>,
+
+    #[account(
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+    )]
+    pub authority: Signer<'info>,
+
+    pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            reviewer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"payout_destination",
+            recipient.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `expected_payout_token_account`
+    pub payout_destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(Some(token_mint.key())).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+}
+
+impl<'info> AcceptTokenBountyClaim<'info> {
+    pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
+}
+
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[writable]` recipient: [Recipient] The hunter whose claim is accepted
+/// 3. `[]` bounty_claim: [BountyClaim] The accepted claim
+/// 4. `[]` token_vault: [TokenVault] 
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` source: [AccountInfo] The source account.
+/// 7. `[writable]` destination: [AccountInfo] The hunter's bound payout token account.
+/// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission
+/// 11. `[]` authority_member: [Member] (optional) Membership of the reviewer
+/// 12. `[]` payout_destination: [PayoutDestination] The hunter's token account override for this mint, if registered
+/// 13. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<AcceptTokenBountyClaim>,
+) -> Result<()> {
+    // Verify reviewer holds the execute payout permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.reviewer.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_EXECUTE_PAYOUT,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let token_mint = ctx.accounts.token_mint.key();
+    
+    // Verify the bounty is an open bounty of this mint, claimed by its deadline
+    let bounty = &ctx.accounts.bounty;
+    bounty.validate_acceptable(&ctx.accounts.bounty_claim)?;
+    if bounty.token_mint != Some(token_mint) {
+        return Err(crate::error::ErrorCode::InvalidBounty.into());
+    }
+    let reward = bounty.reward;
+    
+    // Verify tokens go only to the recipient's bound payout token account
+    let expected_destination = crate::utils::expected_payout_token_account(
+        &ctx.accounts.recipient,
+        &ctx.accounts.payout_destination,
+        &token_mint,
+    )?;
+    if ctx.accounts.destination.key() != expected_destination {
+        return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+    }
+    
+    // The reward goes through the same checks as any other payout
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, reward, current_time)?;
+    
+    // The reserved reward left the vault's booked balance when the bounty
+    // was posted, so it counts toward the balance the payout leaves
+    let outflow = Outflow {
+        amount: reward,
+        destinations: vec![ctx.accounts.destination.key()],
+        balance: ctx.accounts.token_vault.balance.saturating_add(reward),
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Transfer tokens
+    ctx.accounts.cpi_csl_spl_token_transfer(reward)?;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_bounties = treasury.open_bounties.saturating_sub(1);
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(reward, current_time);
+    
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.status = BountyStatus::Awarded;
+    bounty.winner = Some(recipient.key());
+    
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    member_address: Pubkey,
+    permissions: u16,
+    treasury_seed_name: String,
+)]
+pub struct AddMember<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        init,
+        space=115,
+        payer=authority,
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            member_address.as_ref(),
+        ],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `load_governance`
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` member: [Member] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission, plus any privileged permission it changes unless it is the admin
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - member_address: [Pubkey] The member's wallet address
+/// - permissions: [u16] Bitmask of the PERMISSION_* constants
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<AddMember>,
+    member_address: Pubkey,
+    permissions: u16,
+) -> Result<()> {
+    // Verify authority may manage the treasury configuration
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    
+    // While governance requires it, the council co-signs this exact change
+    let config_hash = crate::utils::args_hash("add_member", &(member_address, permissions))?;
+    crate::utils::enforce_council_cosign(
+        &ctx.accounts.treasury.key(),
+        crate::utils::load_governance(&ctx.accounts.governance)?.as_ref(),
+        ctx.accounts.approval_request.as_mut(),
+        config_hash,
+    )?;
+    
+    if permissions & !PERMISSION_ALL != 0 {
+        return Err(crate::error::ErrorCode::InvalidPermissions.into());
+    }
+    
+    // Privileged permissions are granted only by the admin or a member holding them
+    crate::utils::authorize_grant(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        permissions,
+    )?;
+    
+    // Initialize the member account
+    let member = &mut ctx.accounts.member;
+    member.treasury = ctx.accounts.treasury.key();
+    member.member = member_address;
+    member.permissions = permissions;
+    member.added_by = ctx.accounts.authority.key();
+    member.added_at = Clock::get()?.unix_timestamp;
+    member.bump = ctx.bumps.member;
+    
+    msg!("Member added: {} with permissions {:#08b}", member_address, permissions);
+    
+    Ok(())
+}
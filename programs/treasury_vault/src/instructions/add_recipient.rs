@@ -13,6 +13,8 @@ use anchor_spl::{
     recipient_address: Pubkey,
     name: String,
     role: u8,
+    valid_until: i64,
+    attestation_ref: [u8; 32],
     treasury_seed_name: String,
 )]
 pub struct AddRecipient<'info> {
@@ -27,7 +29,7 @@ pub struct AddRecipient<'info> {
 
     #[account(
         init,
-        space=127,
+        space=271,
         payer=authority,
         seeds = [
             b"recipient",
@@ -52,6 +54,20 @@ pub struct AddRecipient<'info> {
 
     /// CHECK: Token program for validation
     pub token_program: Option<Program<'info, Token>>,
+
+    /// CHECK: Only required if treasury.attester is set; address checked against the instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
 }
 
 pub fn handler(
@@ -59,13 +75,32 @@ pub fn handler(
     recipient_address: Pubkey,
     name: String,
     role: u8,
+    valid_until: i64,
+    attestation_ref: [u8; 32],
 ) -> Result<()> {
-    // Verify authority is admin or treasurer
+    // Verify authority holds the manage recipients permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_MANAGE_RECIPIENTS,
+    )?;
     let treasury = &ctx.accounts.treasury;
-    if ctx.accounts.authority.key() != treasury.admin && ctx.accounts.authority.key() != treasury.treasurer {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
+    
+    // An attestation that has already lapsed cannot admit anyone
+    let current_time = Clock::get()?.unix_timestamp;
+    if valid_until != 0 && valid_until <= current_time {
+        return Err(crate::error::ErrorCode::InvalidExpiry.into());
     }
     
+    // Verify the attester approved this recipient and expiry
+    crate::utils::validate_attestation(
+        treasury,
+        ctx.accounts.instructions_sysvar.as_ref().map(|a| a.as_ref()),
+        &recipient_address,
+        valid_until,
+    )?;
+    
     // Validate token gating requirements if enabled
     if treasury.require_token_gate {
         // Ensure token gate mint is provided and matches treasury config
@@ -115,6 +150,16 @@ pub fn handler(
     recipient.total_received = 0;
     recipient.last_payout_time = 0;
     recipient.bump = ctx.bumps.recipient;
+    recipient.active_schedules = 0;
+    recipient.active_streams = 0;
+    recipient.valid_until = valid_until;
+    recipient.attestation_ref = attestation_ref;
+    recipient.attested_at = current_time;
+    recipient.payout_address = recipient_address;
+    recipient.caps = RecipientCaps::default();
+    recipient.period_received = 0;
+    recipient.period_start = 0;
+    recipient.added_at = current_time;
     
     msg!(
         "Recipient added: {} with role {} (token gating: {})",
@@ -0,0 +1,65 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    grant_id: u64,
+    milestone_index: u8,
+    treasury_seed_name: String,
+)]
+pub struct ApproveMilestone<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"milestone_grant",
+            treasury.key().as_ref(),
+            grant_id.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_grant.bump
+    )]
+    pub milestone_grant: Account<'info, MilestoneGrant>,
+
+    pub approver: Signer<'info>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 2. `[signer]` approver: [AccountInfo] One of the milestone's approvers
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ApproveMilestone>,
+    milestone_index: u8,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let milestone = ctx.accounts.milestone_grant.current_milestone(milestone_index)?;
+    milestone.validate_open(current_time)?;
+    
+    // Approvers sign off on submitted evidence
+    if milestone.status != MilestoneStatus::Submitted {
+        return Err(crate::error::ErrorCode::InvalidMilestoneStatus.into());
+    }
+    milestone.approve(&ctx.accounts.approver.key())?;
+    
+    msg!(
+        "Milestone {} has {} of {} approvals",
+        milestone_index,
+        milestone.approvals.count_ones(),
+        milestone.approvals_required
+    );
+    
+    Ok(())
+}
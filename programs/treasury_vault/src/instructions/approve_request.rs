@@ -0,0 +1,94 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct ApproveRequest<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"approval_request",
+            treasury.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = approval_request.bump
+    )]
+    pub approval_request: Account<'info, ApprovalRequest>,
+
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            approver.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` approval_request: [ApprovalRequest] 
+/// 2. `[signer]` approver: [AccountInfo] Must hold the approve permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the approver
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for this request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ApproveRequest>,
+    _request_id: u64,
+) -> Result<()> {
+    // Verify approver holds the approve permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approver.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_APPROVE,
+    )?;
+    
+    let approver = ctx.accounts.approver.key();
+    let approval_request = &mut ctx.accounts.approval_request;
+    
+    if approval_request.is_executed {
+        return Err(crate::error::ErrorCode::ApprovalMismatch.into());
+    }
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    approval_request.validate_unexpired(current_time)?;
+    
+    if approval_request.approvers.contains(&approver) {
+        return Err(crate::error::ErrorCode::AlreadyApproved.into());
+    }
+    
+    if approval_request.approvers.len() >= MAX_REQUEST_APPROVERS {
+        return Err(crate::error::ErrorCode::InvalidApprovalPolicy.into());
+    }
+    
+    approval_request.approvers.push(approver);
+    approval_request.admin_approved |= approver == ctx.accounts.treasury.admin;
+    approval_request.last_approved_at = current_time;
+    
+    msg!(
+        "Request {} approved by {} ({} approvals)",
+        approval_request.request_id,
+        approver,
+        approval_request.approvers.len()
+    );
+    
+    Ok(())
+}
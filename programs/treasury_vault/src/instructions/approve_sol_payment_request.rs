@@ -0,0 +1,177 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    amount: u64,
+    treasury_seed_name: String,
+)]
+pub struct ApproveSolPaymentRequest<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"payment_request",
+            recipient.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = payment_request.bump
+    )]
+    pub payment_request: Account<'info, PaymentRequest>,
+
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            reviewer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_commitment_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] The recipient that submitted the request
+/// 2. `[writable]` payment_request: [PaymentRequest] 
+/// 3. `[writable]` payout_address: [AccountInfo] The recipient's bound payout address
+/// 4. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission unless the approval request passed a token-holder vote
+/// 5. `[]` authority_member: [Member] (optional) Membership of the reviewer
+/// 6. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 7. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for SOL, if set
+/// 8. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the invoiced amount's tier needs approvals or a delay, or passed by token-holder vote
+///
+/// Data:
+/// - request_id: [u64] Identifier of the request, unique for the recipient
+/// - amount: [u64] Amount to approve and pay now, up to what remains of the request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ApproveSolPaymentRequest>,
+    amount: u64,
+) -> Result<()> {
+    // The call's full arguments, which a passed vote must match exactly
+    let terms_hash = crate::utils::args_hash("approve_sol_payment_request", &amount)?;
+    
+    // Verify reviewer holds the execute payout permission, unless token holders voted this exact call through
+    crate::utils::authorize_action(
+        &ctx.accounts.treasury,
+        &ctx.accounts.reviewer.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.approval_request.as_deref(),
+        ApprovalAction::PayPaymentRequest { payment_request: ctx.accounts.payment_request.key() },
+        terms_hash,
+        PERMISSION_EXECUTE_PAYOUT,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Verify the request is for SOL and the amount fits what remains of it
+    if ctx.accounts.payment_request.token_mint.is_some() {
+        return Err(crate::error::ErrorCode::InvalidPaymentRequest.into());
+    }
+    ctx.accounts.payment_request.validate_approval(amount)?;
+    
+    // The tier follows the whole invoice, so splitting it into partial
+    // approvals cannot bring each one under a lower tier
+    let invoiced_amount = ctx.accounts.payment_request.amount;
+    crate::utils::enforce_commitment_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::PayPaymentRequest { payment_request: ctx.accounts.payment_request.key() },
+        terms_hash,
+        amount,
+        Some(invoiced_amount),
+        None,
+        current_time,
+    )?;
+    
+    // The payment goes through the same checks as any other payout
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, amount, current_time)?;
+    
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    if **treasury_info.lamports.borrow() < amount || ctx.accounts.treasury.total_balance < amount {
+        return Err(crate::error::ErrorCode::InsufficientFunds.into());
+    }
+    crate::utils::validate_sol_reserve(&ctx.accounts.treasury, amount, false)?;
+    
+    let outflow = Outflow {
+        amount,
+        destinations: vec![ctx.accounts.payout_address.key()],
+        balance: ctx.accounts.treasury.total_balance,
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Transfer SOL
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.payout_address.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_balance = treasury.total_balance.checked_sub(amount).unwrap();
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(amount, current_time);
+    
+    let payment_request = &mut ctx.accounts.payment_request;
+    payment_request.record_payment(ctx.accounts.reviewer.key(), amount, current_time);
+    
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    amount: u64,
+    treasury_seed_name: String,
+)]
+pub struct ApproveTokenPaymentRequest<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"payment_request",
+            recipient.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = payment_request.bump
+    )]
+    pub payment_request: Account<'info, PaymentRequest>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+    )]
+    pub authority: Signer<'info>,
+
+    pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            reviewer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"payout_destination",
+            recipient.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `expected_payout_token_account`
+    pub payout_destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(Some(token_mint.key())).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(Some(token_mint.key())).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_commitment_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+impl<'info> ApproveTokenPaymentRequest<'info> {
+    pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
+}
+
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] The recipient that submitted the request
+/// 2. `[writable]` payment_request: [PaymentRequest] 
+/// 3. `[writable]` token_vault: [TokenVault] 
+/// 4. `[]` token_mint: [Mint] 
+/// 5. `[writable]` source: [AccountInfo] The source account.
+/// 6. `[writable]` destination: [AccountInfo] The recipient's bound payout token account.
+/// 7. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission unless the approval request passed a token-holder vote
+/// 10. `[]` authority_member: [Member] (optional) Membership of the reviewer
+/// 11. `[]` payout_destination: [PayoutDestination] The recipient's token account override for this mint, if registered
+/// 12. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+/// 13. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for this mint, if set
+/// 14. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the invoiced amount's tier needs approvals or a delay, or passed by token-holder vote
+///
+/// Data:
+/// - request_id: [u64] Identifier of the request, unique for the recipient
+/// - amount: [u64] Amount to approve and pay now, up to what remains of the request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ApproveTokenPaymentRequest>,
+    amount: u64,
+) -> Result<()> {
+    // The call's full arguments, which a passed vote must match exactly
+    let terms_hash = crate::utils::args_hash("approve_token_payment_request", &amount)?;
+    
+    // Verify reviewer holds the execute payout permission, unless token holders voted this exact call through
+    crate::utils::authorize_action(
+        &ctx.accounts.treasury,
+        &ctx.accounts.reviewer.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.approval_request.as_deref(),
+        ApprovalAction::PayPaymentRequest { payment_request: ctx.accounts.payment_request.key() },
+        terms_hash,
+        PERMISSION_EXECUTE_PAYOUT,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let token_mint = ctx.accounts.token_mint.key();
+    
+    // Verify the request is for this mint and the amount fits what remains of it
+    if ctx.accounts.payment_request.token_mint != Some(token_mint) {
+        return Err(crate::error::ErrorCode::InvalidPaymentRequest.into());
+    }
+    ctx.accounts.payment_request.validate_approval(amount)?;
+    
+    // Verify tokens go only to the recipient's bound payout token account
+    let expected_destination = crate::utils::expected_payout_token_account(
+        &ctx.accounts.recipient,
+        &ctx.accounts.payout_destination,
+        &token_mint,
+    )?;
+    if ctx.accounts.destination.key() != expected_destination {
+        return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+    }
+    
+    // The tier follows the whole invoice, so splitting it into partial
+    // approvals cannot bring each one under a lower tier
+    let invoiced_amount = ctx.accounts.payment_request.amount;
+    crate::utils::enforce_commitment_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::PayPaymentRequest { payment_request: ctx.accounts.payment_request.key() },
+        terms_hash,
+        amount,
+        Some(invoiced_amount),
+        Some(token_mint),
+        current_time,
+    )?;
+    
+    // The payment goes through the same checks as any other payout
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, amount, current_time)?;
+    
+    if ctx.accounts.token_vault.balance < amount {
+        return Err(crate::error::ErrorCode::InsufficientFunds.into());
+    }
+    crate::utils::validate_token_reserve(&ctx.accounts.token_vault, amount, false)?;
+    
+    let outflow = Outflow {
+        amount,
+        destinations: vec![ctx.accounts.destination.key()],
+        balance: ctx.accounts.token_vault.balance,
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Transfer tokens
+    ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
+    
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.balance = token_vault.balance.checked_sub(amount).unwrap();
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(amount, current_time);
+    
+    let payment_request = &mut ctx.accounts.payment_request;
+    payment_request.record_payment(ctx.accounts.reviewer.key(), amount, current_time);
+    
+    Ok(())
+}
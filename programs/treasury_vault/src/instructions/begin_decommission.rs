@@ -0,0 +1,76 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    successor: Pubkey,
+    treasury_seed_name: String,
+)]
+pub struct BeginDecommission<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            admin.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[signer]` admin: [AccountInfo] Must hold the config permission
+/// 2. `[]` authority_member: [Member] (optional) Membership of the admin
+///
+/// Data:
+/// - successor: [Pubkey] Wallet that receives the remaining SOL and tokens
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<BeginDecommission>,
+    successor: Pubkey,
+) -> Result<()> {
+    // Verify admin holds the config permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    let treasury = &mut ctx.accounts.treasury;
+    
+    // The treasury PDA itself can never be the successor
+    if successor == treasury.key() || successor == Pubkey::default() {
+        return Err(crate::error::ErrorCode::InvalidSuccessor.into());
+    }
+    
+    // Block new schedules and streams from now on
+    treasury.status = TreasuryStatus::WindingDown;
+    treasury.successor = Some(successor);
+    
+    msg!(
+        "Treasury winding down: {} open schedules, {} open streams, {} open escrows, {} open grants rounds, {} open bounties, {} open milestone grants, {} token vaults, successor {}",
+        treasury.open_schedules,
+        treasury.open_streams,
+        treasury.open_escrows,
+        treasury.open_rounds,
+        treasury.open_bounties,
+        treasury.open_grants,
+        treasury.token_vault_count,
+        successor
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    recipient_address: Pubkey,
+    schedule_id: u64,
+    reason: String,
+    treasury_seed_name: String,
+)]
+pub struct CancelPayoutSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_address.as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"schedule",
+            treasury.key().as_ref(),
+            recipient.key().as_ref(),
+            schedule_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub payout_schedule: Account<'info, PayoutSchedule>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[writable]` payout_schedule: [PayoutSchedule] 
+/// 3. `[signer]` authority: [AccountInfo] Must hold the create schedule permission
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - reason: [String] Why the schedule is cancelled, up to MAX_CANCEL_REASON_LEN bytes
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<CancelPayoutSchedule>,
+    _recipient_address: Pubkey,
+    schedule_id: u64,
+    reason: String,
+) -> Result<()> {
+    // Verify authority holds the create schedule permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+    
+    if reason.len() > MAX_CANCEL_REASON_LEN {
+        return Err(crate::error::ErrorCode::CancelReasonTooLong.into());
+    }
+    
+    // Completed and cancelled schedules are final
+    let payout_schedule = &mut ctx.accounts.payout_schedule;
+    if !payout_schedule.status.is_open() {
+        return Err(crate::error::ErrorCode::ScheduleClosed.into());
+    }
+    
+    payout_schedule.status = ScheduleStatus::Cancelled;
+    payout_schedule.cancelled_by = Some(ctx.accounts.authority.key());
+    payout_schedule.cancelled_at = Clock::get()?.unix_timestamp;
+    payout_schedule.cancel_reason = reason;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_schedules = treasury.open_schedules.saturating_sub(1);
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.active_schedules = recipient.active_schedules.saturating_sub(1);
+    
+    msg!(
+        "Payout schedule {} cancelled by {}: {}",
+        schedule_id,
+        ctx.accounts.authority.key(),
+        ctx.accounts.payout_schedule.cancel_reason
+    );
+    
+    Ok(())
+}
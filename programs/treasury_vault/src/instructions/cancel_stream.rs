@@ -20,6 +20,7 @@ pub struct CancelStream<'info> {
     pub treasury: Account<'info, TreasuryConfig>,
 
     #[account(
+        mut,
         seeds = [
             b"recipient",
             treasury.key().as_ref(),
@@ -45,9 +46,22 @@ pub struct CancelStream<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    #[account(mut)]
-    /// CHECK: Recipient address for final withdrawal
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address for the final withdrawal
     pub recipient_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
 }
 
 pub fn handler(
@@ -58,14 +72,14 @@ pub fn handler(
     let current_time = Clock::get()?.unix_timestamp;
     let streaming_schedule = &ctx.accounts.streaming_schedule;
 
-    // Verify authority can cancel (admin, treasurer, or stream creator)
-    let treasury = &ctx.accounts.treasury;
-    let is_authorized = ctx.accounts.authority.key() == treasury.admin ||
-                       ctx.accounts.authority.key() == treasury.treasurer ||
-                       ctx.accounts.authority.key() == streaming_schedule.created_by;
-
-    if !is_authorized {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
+    // Verify authority created the stream or holds the create schedule permission
+    if ctx.accounts.authority.key() != streaming_schedule.created_by {
+        crate::utils::authorize(
+            &ctx.accounts.treasury,
+            &ctx.accounts.authority.key(),
+            ctx.accounts.authority_member.as_ref(),
+            PERMISSION_CREATE_SCHEDULE,
+        )?;
     }
 
     // Check if stream is cancelable
@@ -76,31 +90,48 @@ pub fn handler(
     // Calculate final withdrawable amount for recipient
     let final_withdrawable = streaming_schedule.calculate_withdrawable_amount(current_time);
     
-    if final_withdrawable > 0 {
-        // Transfer final amount to recipient
-        match streaming_schedule.token_mint {
-            None => {
-                // SOL transfer
-                let treasury_info = ctx.accounts.treasury.to_account_info();
-                let recipient_info = ctx.accounts.recipient_wallet.to_account_info();
-
-                **treasury_info.try_borrow_mut_lamports()? -= final_withdrawable;
-                **recipient_info.try_borrow_mut_lamports()? += final_withdrawable;
-
-                let treasury_mut = &mut ctx.accounts.treasury;
-                treasury_mut.total_balance = treasury_mut.total_balance
-                    .saturating_sub(final_withdrawable);
-            },
-            Some(_) => {
-                // Token transfer implementation needed
-                return Err(crate::error::ErrorCode::InvalidTokenVault.into());
-            }
-        }
+    // Cancelling always succeeds: a final payout that could not go through the
+    // usual payout checks is withheld and stays in the treasury. Token streams
+    // have no transfer here, so their final amount stays in the vault.
+    let final_payout = if final_withdrawable > 0
+        && streaming_schedule.token_mint.is_none()
+        && !ctx.accounts.recipient.is_expired(current_time)
+        && ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, final_withdrawable, current_time).is_ok()
+        && ctx.accounts.treasury.total_balance >= final_withdrawable
+        && crate::utils::validate_sol_reserve(&ctx.accounts.treasury, final_withdrawable, false).is_ok()
+    {
+        final_withdrawable
+    } else {
+        0
+    };
+
+    if final_payout > 0 {
+        // SOL transfer
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let recipient_info = ctx.accounts.recipient_wallet.to_account_info();
+
+        **treasury_info.try_borrow_mut_lamports()? -= final_payout;
+        **recipient_info.try_borrow_mut_lamports()? += final_payout;
+
+        let treasury_mut = &mut ctx.accounts.treasury;
+        treasury_mut.total_balance = treasury_mut.total_balance.checked_sub(final_payout).unwrap();
+
+        ctx.accounts.recipient.record_payout(final_payout, current_time);
+    }
+
+    // A cancelled stream no longer blocks decommissioning
+    if ctx.accounts.streaming_schedule.is_active {
+        let treasury_mut = &mut ctx.accounts.treasury;
+        treasury_mut.open_streams = treasury_mut.open_streams.saturating_sub(1);
+
+        let recipient = &mut ctx.accounts.recipient;
+        recipient.active_streams = recipient.active_streams.saturating_sub(1);
     }
 
     msg!(
-        "Stream cancelled: {} tokens transferred to recipient, stream closed",
-        final_withdrawable
+        "Stream cancelled: {} transferred to recipient, {} withheld, stream closed",
+        final_payout,
+        final_withdrawable - final_payout
     );
 
     // Account automatically closed due to close constraint
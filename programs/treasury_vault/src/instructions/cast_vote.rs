@@ -0,0 +1,119 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    approve: bool,
+    treasury_seed_name: String,
+)]
+pub struct CastVote<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"approval_request",
+            treasury.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = approval_request.bump
+    )]
+    pub approval_request: Account<'info, ApprovalRequest>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            approval_request.key().as_ref(),
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, SpendingProposal>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"voter",
+            treasury.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump = voter_record.bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    #[account(
+        init,
+        space=82,
+        payer=voter,
+        seeds = [
+            b"vote",
+            proposal.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vote: Account<'info, Vote>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` approval_request: [ApprovalRequest] 
+/// 2. `[writable]` proposal: [SpendingProposal] 
+/// 3. `[writable]` voter_record: [VoterRecord] Deposits of the voter, locked until voting ends
+/// 4. `[writable]` vote: [Vote] 
+/// 5. `[writable, signer]` voter: [AccountInfo] Must have deposited governance tokens
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for the proposal's approval request
+/// - approve: [bool] Whether to vote for the proposal
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<CastVote>,
+    _request_id: u64,
+    approve: bool,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.proposal.validate_voting(current_time)?;
+    
+    // Delegated weight is used by the delegate, not voted again directly
+    ctx.accounts.voter_record.validate_undelegated()?;
+    
+    let weight = ctx.accounts.voter_record.deposited;
+    if weight == 0 {
+        return Err(crate::error::ErrorCode::NoVotingWeight.into());
+    }
+    
+    // Lock the deposits behind the vote so they cannot be moved and voted again
+    let voting_ends_at = ctx.accounts.proposal.voting_ends_at;
+    let voter_record = &mut ctx.accounts.voter_record;
+    voter_record.locked_until = voter_record.locked_until.max(voting_ends_at);
+    
+    let proposal = &mut ctx.accounts.proposal;
+    if approve {
+        proposal.yes_weight = proposal.yes_weight.checked_add(weight).unwrap();
+    } else {
+        proposal.no_weight = proposal.no_weight.checked_add(weight).unwrap();
+    }
+    
+    let vote = &mut ctx.accounts.vote;
+    vote.proposal = ctx.accounts.proposal.key();
+    vote.voter = ctx.accounts.voter.key();
+    vote.weight = weight;
+    vote.approve = approve;
+    vote.bump = ctx.bumps.vote;
+    
+    Ok(())
+}
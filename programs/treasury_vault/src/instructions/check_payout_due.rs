@@ -0,0 +1,71 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    schedule_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct CheckPayoutDue<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"schedule",
+            treasury.key().as_ref(),
+            payout_schedule.recipient.as_ref(),
+            schedule_id.to_le_bytes().as_ref(),
+        ],
+        bump = payout_schedule.bump
+    )]
+    pub payout_schedule: Account<'info, PayoutSchedule>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` payout_schedule: [PayoutSchedule] 
+///
+/// Data:
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+///
+/// Returns the schedule's [PayoutDue], for keepers to simulate before executing.
+/// The recipient and the treasury's funds and limits are not checked.
+pub fn handler(
+    ctx: Context<CheckPayoutDue>,
+) -> Result<PayoutDue> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let payout_schedule = &ctx.accounts.payout_schedule;
+    
+    // Paused and finished schedules have nothing due now or later
+    if !payout_schedule.is_payable() || payout_schedule.is_finished() {
+        return Ok(PayoutDue {
+            due_periods: 0,
+            next_due_time: None,
+            keeper_tip: 0,
+        });
+    }
+    
+    // Claim mode schedules are claimed by their recipient, never executed by keepers
+    if payout_schedule.is_claim_mode() {
+        let expired_periods = payout_schedule.expired_claim_periods(current_time);
+        return Ok(PayoutDue {
+            due_periods: payout_schedule.due_periods(current_time) - expired_periods,
+            next_due_time: payout_schedule.due_time(payout_schedule.executions + expired_periods),
+            keeper_tip: 0,
+        });
+    }
+    
+    Ok(PayoutDue {
+        due_periods: payout_schedule.due_periods(current_time),
+        next_due_time: payout_schedule.next_due_time(),
+        keeper_tip: ctx.accounts.treasury.keeper_tip_due(current_time),
+    })
+}
@@ -0,0 +1,199 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    schedule_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct ClaimPayout<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_signer.key().as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"schedule",
+            treasury.key().as_ref(),
+            recipient.key().as_ref(),
+            schedule_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub payout_schedule: Account<'info, PayoutSchedule>,
+
+    #[account(mut)]
+    pub recipient_signer: Signer<'info>,
+
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    /// CHECK: Matched against the schedule's price feed by `period_payout_amount`
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[writable]` payout_schedule: [PayoutSchedule] 
+/// 3. `[writable, signer]` recipient_signer: [AccountInfo] The recipient's wallet address
+/// 4. `[writable]` payout_address: [AccountInfo] The recipient's bound payout address
+/// 5. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 6. `[]` price_feed: [AccountInfo] (optional) The schedule's price feed, required for oracle-priced schedules
+///
+/// Data:
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ClaimPayout>,
+    _schedule_id: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Verify payout schedule is neither paused nor finished
+    if !ctx.accounts.payout_schedule.is_payable() {
+        return Err(crate::error::ErrorCode::InvalidSchedule.into());
+    }
+    
+    // Only claim mode schedules can be claimed
+    if !ctx.accounts.payout_schedule.is_claim_mode() {
+        return Err(crate::error::ErrorCode::PayoutModeMismatch.into());
+    }
+    
+    // Token schedules are claimed through claim_token_payout
+    if ctx.accounts.payout_schedule.token_mint.is_some() {
+        return Err(crate::error::ErrorCode::InvalidTokenVault.into());
+    }
+    
+    // Verify recipient is active and their whitelist entry has not expired
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    
+    // Periods left unclaimed past their window expire, their amounts staying in the treasury
+    let expired_periods = ctx.accounts.payout_schedule.expired_claim_periods(current_time);
+    let payout_schedule = &mut ctx.accounts.payout_schedule;
+    payout_schedule.executions = payout_schedule.executions.checked_add(expired_periods).unwrap();
+    
+    let due_periods = ctx.accounts.payout_schedule.due_periods(current_time);
+    if due_periods == 0 && expired_periods == 0 {
+        return Err(crate::error::ErrorCode::PayoutNotDue.into());
+    }
+    
+    // With nothing left in its window, commit the expired periods alone,
+    // which may complete the schedule
+    if due_periods == 0 {
+        crate::utils::complete_claim_schedule(
+            &mut ctx.accounts.payout_schedule,
+            &mut ctx.accounts.treasury,
+            &mut ctx.accounts.recipient,
+        );
+        
+        msg!("Nothing to claim, {} unclaimed periods expired", expired_periods);
+        
+        return Ok(());
+    }
+    
+    // Claim every period still in its window at once, within the outflow
+    // policy and the recipient's caps
+    let schedule_amount = crate::utils::period_payout_amount(
+        &ctx.accounts.payout_schedule,
+        ctx.accounts.price_feed.as_ref(),
+        SOL_DECIMALS,
+        current_time,
+    )?;
+    let paid_periods = if schedule_amount == 0 {
+        1
+    } else {
+        let allowance = crate::utils::outflow_allowance(
+            &ctx.accounts.treasury,
+            &ctx.accounts.policy,
+            ctx.accounts.treasury.total_balance,
+            current_time,
+        )?
+            .min(ctx.accounts.recipient.remaining_allowance(&ctx.accounts.treasury, current_time));
+        due_periods.min(allowance.checked_div(schedule_amount).unwrap()).max(1)
+    };
+    
+    // Check if treasury has enough funds, leaving those reserved for escrows, grants and bounties
+    let amount = schedule_amount.checked_mul(paid_periods).unwrap();
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    if **treasury_info.lamports.borrow() < amount || ctx.accounts.treasury.total_balance < amount {
+        return Err(crate::error::ErrorCode::InsufficientFunds.into());
+    }
+    
+    // Claims keep the reserve floor and the account's rent-exempt minimum
+    crate::utils::validate_sol_reserve(&ctx.accounts.treasury, amount, false)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, amount, current_time)?;
+    
+    // Check the claim against the spending limits and the outflow policy
+    let outflow = Outflow {
+        amount,
+        destinations: vec![ctx.accounts.payout_address.key()],
+        balance: ctx.accounts.treasury.total_balance,
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Transfer SOL
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.payout_address.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_balance = treasury.total_balance.checked_sub(amount).unwrap();
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(amount, current_time);
+    
+    // Update payout schedule
+    let payout_schedule = &mut ctx.accounts.payout_schedule;
+    payout_schedule.executions = payout_schedule.executions.checked_add(paid_periods).unwrap();
+    payout_schedule.last_execution_time = current_time;
+    
+    crate::utils::complete_claim_schedule(
+        &mut ctx.accounts.payout_schedule,
+        &mut ctx.accounts.treasury,
+        &mut ctx.accounts.recipient,
+    );
+    
+    msg!(
+        "Claimed {} lamports for {} periods, {} unclaimed periods expired",
+        amount,
+        paid_periods,
+        expired_periods
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,265 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(
+    schedule_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct ClaimTokenPayout<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_signer.key().as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"schedule",
+            treasury.key().as_ref(),
+            recipient.key().as_ref(),
+            schedule_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub payout_schedule: Account<'info, PayoutSchedule>,
+
+    #[account(mut)]
+    pub recipient_signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        address = token_vault.token_account @ crate::error::ErrorCode::InvalidTokenVault,
+    )]
+    /// CHECK: Bound to the vault's token account
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub destination: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    #[account(
+        seeds = [
+            b"payout_destination",
+            recipient.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `expected_payout_token_account`
+    pub payout_destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(Some(token_mint.key())).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    /// CHECK: Matched against the schedule's price feed by `period_payout_amount`
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> ClaimTokenPayout<'info> {
+    pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
+}
+
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[writable]` payout_schedule: [PayoutSchedule] 
+/// 3. `[writable, signer]` recipient_signer: [AccountInfo] The recipient's wallet address
+/// 4. `[writable]` token_vault: [TokenVault] 
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` source: [AccountInfo] The vault's token account.
+/// 7. `[writable]` destination: [AccountInfo] The recipient's bound payout token account.
+/// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` payout_destination: [PayoutDestination] The recipient's token account override for this mint, if registered
+/// 11. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+/// 12. `[]` price_feed: [AccountInfo] (optional) The schedule's price feed, required for oracle-priced schedules
+///
+/// Data:
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ClaimTokenPayout>,
+    _schedule_id: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Verify payout schedule is neither paused nor finished
+    if !ctx.accounts.payout_schedule.is_payable() {
+        return Err(crate::error::ErrorCode::InvalidSchedule.into());
+    }
+    
+    // Only claim mode schedules can be claimed
+    if !ctx.accounts.payout_schedule.is_claim_mode() {
+        return Err(crate::error::ErrorCode::PayoutModeMismatch.into());
+    }
+    
+    // Verify token mint matches the schedule
+    if ctx.accounts.payout_schedule.token_mint != Some(ctx.accounts.token_mint.key()) {
+        return Err(crate::error::ErrorCode::InvalidTokenVault.into());
+    }
+    
+    // Verify recipient is active and their whitelist entry has not expired
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    
+    // Verify tokens go only to the recipient's bound payout token account
+    let expected_destination = crate::utils::expected_payout_token_account(
+        &ctx.accounts.recipient,
+        &ctx.accounts.payout_destination,
+        &ctx.accounts.token_mint.key(),
+    )?;
+    if ctx.accounts.destination.key() != expected_destination {
+        return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+    }
+    
+    // Periods left unclaimed past their window expire, their amounts staying in the vault
+    let expired_periods = ctx.accounts.payout_schedule.expired_claim_periods(current_time);
+    let payout_schedule = &mut ctx.accounts.payout_schedule;
+    payout_schedule.executions = payout_schedule.executions.checked_add(expired_periods).unwrap();
+    
+    let due_periods = ctx.accounts.payout_schedule.due_periods(current_time);
+    if due_periods == 0 && expired_periods == 0 {
+        return Err(crate::error::ErrorCode::PayoutNotDue.into());
+    }
+    
+    // With nothing left in its window, commit the expired periods alone,
+    // which may complete the schedule
+    if due_periods == 0 {
+        crate::utils::complete_claim_schedule(
+            &mut ctx.accounts.payout_schedule,
+            &mut ctx.accounts.treasury,
+            &mut ctx.accounts.recipient,
+        );
+        
+        msg!("Nothing to claim, {} unclaimed periods expired", expired_periods);
+        
+        return Ok(());
+    }
+    
+    // Claim every period still in its window at once, within the outflow
+    // policy and the recipient's caps
+    let schedule_amount = crate::utils::period_payout_amount(
+        &ctx.accounts.payout_schedule,
+        ctx.accounts.price_feed.as_ref(),
+        ctx.accounts.token_mint.decimals,
+        current_time,
+    )?;
+    let paid_periods = if schedule_amount == 0 {
+        1
+    } else {
+        let allowance = crate::utils::outflow_allowance(
+            &ctx.accounts.treasury,
+            &ctx.accounts.policy,
+            ctx.accounts.token_vault.balance,
+            current_time,
+        )?
+            .min(ctx.accounts.recipient.remaining_allowance(&ctx.accounts.treasury, current_time));
+        due_periods.min(allowance.checked_div(schedule_amount).unwrap()).max(1)
+    };
+    
+    // Check if token vault has enough funds
+    let amount = schedule_amount.checked_mul(paid_periods).unwrap();
+    if ctx.accounts.token_vault.balance < amount {
+        return Err(crate::error::ErrorCode::InsufficientFunds.into());
+    }
+    
+    // Claims keep the vault's reserve floor
+    crate::utils::validate_token_reserve(&ctx.accounts.token_vault, amount, false)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, amount, current_time)?;
+    
+    // Check the claim against the spending limits and the outflow policy
+    let outflow = Outflow {
+        amount,
+        destinations: vec![ctx.accounts.destination.key()],
+        balance: ctx.accounts.token_vault.balance,
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Transfer tokens
+    ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
+    
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.balance = token_vault.balance.checked_sub(amount).unwrap();
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(amount, current_time);
+    
+    // Update payout schedule
+    let payout_schedule = &mut ctx.accounts.payout_schedule;
+    payout_schedule.executions = payout_schedule.executions.checked_add(paid_periods).unwrap();
+    payout_schedule.last_execution_time = current_time;
+    
+    crate::utils::complete_claim_schedule(
+        &mut ctx.accounts.payout_schedule,
+        &mut ctx.accounts.treasury,
+        &mut ctx.accounts.recipient,
+    );
+    
+    msg!(
+        "Claimed {} tokens for {} periods, {} unclaimed periods expired",
+        amount,
+        paid_periods,
+        expired_periods
+    );
+    
+    Ok(())
+}
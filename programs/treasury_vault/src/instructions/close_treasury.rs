@@ -0,0 +1,77 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    treasury_seed_name: String,
+)]
+pub struct CloseTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump,
+        close = successor // Remaining SOL and rent are swept to the successor
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = Some(successor.key()) == treasury.successor @ crate::error::ErrorCode::InvalidSuccessor,
+    )]
+    /// CHECK: validated against treasury.successor
+    pub successor: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            admin.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[signer]` admin: [AccountInfo] Must hold the config permission
+/// 2. `[writable]` successor: [AccountInfo] Receives all remaining lamports
+/// 3. `[]` authority_member: [Member] (optional) Membership of the admin
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<CloseTreasury>,
+) -> Result<()> {
+    // Verify admin holds the config permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    let treasury = &ctx.accounts.treasury;
+    
+    if !treasury.is_winding_down() {
+        return Err(crate::error::ErrorCode::TreasuryNotWindingDown.into());
+    }
+    
+    // Everything must be settled and every token vault swept first
+    if treasury.has_open_obligations() || treasury.token_vault_count > 0 {
+        return Err(crate::error::ErrorCode::OpenObligationsRemaining.into());
+    }
+    
+    msg!(
+        "Treasury {} closed: {} lamports swept to successor",
+        treasury.name,
+        ctx.accounts.treasury.to_account_info().lamports()
+    );
+    
+    // Account automatically closed due to close constraint
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    round_id: u64,
+    amount: u64,
+    treasury_seed_name: String,
+)]
+pub struct ContributeToRound<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"grants_round",
+            treasury.key().as_ref(),
+            round_id.to_le_bytes().as_ref(),
+        ],
+        bump = grants_round.bump
+    )]
+    pub grants_round: Account<'info, GrantsRound>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"round_project",
+            grants_round.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump = round_project.bump
+    )]
+    pub round_project: Account<'info, RoundProject>,
+
+    #[account(
+        init_if_needed,
+        space=81,
+        payer=contributor,
+        seeds = [
+            b"contribution",
+            round_project.key().as_ref(),
+            contributor.key().as_ref(),
+        ],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` grants_round: [GrantsRound] 
+/// 2. `[]` recipient: [Recipient] The project's recipient entry
+/// 3. `[writable]` round_project: [RoundProject] 
+/// 4. `[writable]` contribution: [Contribution] The contributor's running total for the project
+/// 5. `[writable]` payout_address: [AccountInfo] The project's bound payout address, which receives the contribution
+/// 6. `[writable, signer]` contributor: [AccountInfo] The community wallet contributing
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - amount: [u64] Lamports to contribute
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ContributeToRound>,
+    amount: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.grants_round.validate_contributable(current_time)?;
+    
+    // Projects cannot raise their own match by contributing to themselves
+    let contributor = ctx.accounts.contributor.key();
+    if amount == 0
+        || contributor == ctx.accounts.recipient.recipient_address
+        || contributor == ctx.accounts.recipient.payout_address
+    {
+        return Err(crate::error::ErrorCode::InvalidContribution.into());
+    }
+    
+    // Contributions go straight to the project
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.contributor.to_account_info(),
+                to: ctx.accounts.payout_address.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    // Each contributor's total counts once towards the project's score
+    let contribution = &mut ctx.accounts.contribution;
+    let previous = contribution.amount;
+    contribution.round_project = ctx.accounts.round_project.key();
+    contribution.contributor = contributor;
+    contribution.amount = previous.checked_add(amount).unwrap();
+    contribution.bump = ctx.bumps.contribution;
+    
+    let round_project = &mut ctx.accounts.round_project;
+    round_project.record_contribution(previous, amount);
+    
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct CosignRequest<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"approval_request",
+            treasury.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = approval_request.bump
+    )]
+    pub approval_request: Account<'info, ApprovalRequest>,
+
+    pub delegate: Signer<'info>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` governance: [GovernanceConfig] 
+/// 2. `[writable]` approval_request: [ApprovalRequest] 
+/// 3. `[signer]` delegate: [AccountInfo] Council wallet holding delegated weight
+/// 4. `[]` remaining_accounts: the [Delegation] accounts delegated to the delegate, each at most once
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for this request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CosignRequest<'info>>,
+    _request_id: u64,
+) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    if governance.delegate_threshold == 0 {
+        return Err(crate::error::ErrorCode::GovernanceDisabled.into());
+    }
+    
+    // Spent and expired requests can no longer be co-signed
+    let current_time = Clock::get()?.unix_timestamp;
+    if ctx.accounts.approval_request.is_executed {
+        return Err(crate::error::ErrorCode::ApprovalMismatch.into());
+    }
+    ctx.accounts.approval_request.validate_unexpired(current_time)?;
+    
+    let treasury_key = ctx.accounts.treasury.key();
+    let delegate = ctx.accounts.delegate.key();
+    let created_at = ctx.accounts.approval_request.created_at;
+    
+    // Sum the delegations as they stood when the request was created, so weight
+    // moved to another delegate afterwards cannot be counted twice
+    let mut weight: u64 = 0;
+    for (index, account) in ctx.remaining_accounts.iter().enumerate() {
+        if account.owner != &crate::ID || ctx.remaining_accounts[..index].iter().any(|other| other.key == account.key) {
+            return Err(crate::error::ErrorCode::InvalidDelegation.into());
+        }
+        
+        let delegation = Delegation::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        if delegation.treasury != treasury_key || delegation.delegate != delegate || delegation.delegated_at >= created_at {
+            return Err(crate::error::ErrorCode::InvalidDelegation.into());
+        }
+        
+        weight = weight.checked_add(delegation.weight).unwrap();
+    }
+    
+    if weight < governance.delegate_threshold {
+        return Err(crate::error::ErrorCode::InsufficientDelegatedWeight.into());
+    }
+    
+    let approval_request = &mut ctx.accounts.approval_request;
+    
+    if approval_request.approvers.contains(&delegate) {
+        return Err(crate::error::ErrorCode::AlreadyApproved.into());
+    }
+    
+    if approval_request.approvers.len() >= MAX_REQUEST_APPROVERS {
+        return Err(crate::error::ErrorCode::InvalidApprovalPolicy.into());
+    }
+    
+    // The co-sign counts as an approval toward the request's tier
+    approval_request.approvers.push(delegate);
+    approval_request.council_weight = approval_request.council_weight.checked_add(weight).unwrap();
+    approval_request.last_approved_at = current_time;
+    
+    msg!(
+        "Request {} co-signed by {} with {} delegated weight ({} approvals)",
+        approval_request.request_id,
+        delegate,
+        weight,
+        approval_request.approvers.len()
+    );
+    
+    Ok(())
+}
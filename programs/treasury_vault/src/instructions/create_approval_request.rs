@@ -0,0 +1,100 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    action: ApprovalAction,
+    amount: u64,
+    token_mint: Option<Pubkey>,
+    treasury_seed_name: String,
+)]
+pub struct CreateApprovalRequest<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        init,
+        space=8 + ApprovalRequest::INIT_SPACE,
+        payer=proposer,
+        seeds = [
+            b"approval_request",
+            treasury.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub approval_request: Account<'info, ApprovalRequest>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            proposer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` approval_request: [ApprovalRequest] 
+/// 2. `[writable, signer]` proposer: [AccountInfo] Must hold the permission the action needs
+/// 3. `[]` authority_member: [Member] (optional) Membership of the proposer
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for this request
+/// - action: [ApprovalAction] The action to be approved
+/// - amount: [u64] Amount the action moves, as passed to it
+/// - token_mint: [Option<Pubkey>] Asset the action moves (null for SOL)
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<CreateApprovalRequest>,
+    request_id: u64,
+    action: ApprovalAction,
+    amount: u64,
+    token_mint: Option<Pubkey>,
+) -> Result<()> {
+    // Verify proposer could perform the action themselves once approved
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.proposer.key(),
+        ctx.accounts.authority_member.as_ref(),
+        action.permission(),
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let approval_request = &mut ctx.accounts.approval_request;
+    approval_request.treasury = ctx.accounts.treasury.key();
+    approval_request.request_id = request_id;
+    approval_request.proposer = ctx.accounts.proposer.key();
+    approval_request.action = action;
+    approval_request.amount = amount;
+    approval_request.token_mint = token_mint;
+    approval_request.approvers = Vec::new();
+    approval_request.admin_approved = false;
+    approval_request.created_at = current_time;
+    approval_request.last_approved_at = 0;
+    approval_request.is_executed = false;
+    approval_request.bump = ctx.bumps.approval_request;
+    approval_request.vote_passed = false;
+    approval_request.council_weight = 0;
+    approval_request.terms_hash = [0u8; 32];
+    approval_request.expires_at = current_time.checked_add(APPROVAL_REQUEST_LIFETIME_SECONDS).unwrap();
+    
+    Ok(())
+}
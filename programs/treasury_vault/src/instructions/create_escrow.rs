@@ -0,0 +1,198 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    escrow_id: u64,
+    token_mint: Option<Pubkey>,
+    amount: u64,
+    arbitrator: Pubkey,
+    release_time: i64,
+    treasury_seed_name: String,
+)]
+pub struct CreateEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        init,
+        space=204,
+        payer=authority,
+        seeds = [
+            b"escrow",
+            treasury.key().as_ref(),
+            escrow_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_vault.token_mint.as_ref(),
+        ],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Option<Account<'info, TokenVault>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(token_mint).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The vendor
+/// 2. `[writable]` escrow: [Escrow] 
+/// 3. `[writable]` token_vault: [TokenVault] (optional) The vault of the escrow's mint, required for token escrows
+/// 4. `[writable, signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
+/// 5. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 6. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for the escrow's asset, if set
+/// 7. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the amount's tier needs approvals or a delay, or passed by token-holder vote
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - token_mint: [Option<Pubkey>] Mint the escrow holds (null for SOL)
+/// - amount: [u64] Amount to hold for the vendor
+/// - arbitrator: [Pubkey] Settles the escrow if either party disputes it
+/// - release_time: [i64] When the dispute window closes and the amount can be released to the vendor
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<CreateEscrow>,
+    escrow_id: u64,
+    token_mint: Option<Pubkey>,
+    amount: u64,
+    arbitrator: Pubkey,
+    release_time: i64,
+) -> Result<()> {
+    // The call's full arguments, which a passed vote must match exactly
+    let terms_hash = crate::utils::args_hash("create_escrow", &(escrow_id, token_mint, amount, arbitrator, release_time))?;
+    
+    // Verify authority holds the create schedule permission, unless token holders voted this exact call through
+    crate::utils::authorize_action(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.approval_request.as_deref(),
+        ApprovalAction::CreateEscrow { recipient: ctx.accounts.recipient.key(), escrow_id },
+        terms_hash,
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Escrows are new obligations, like schedules and streams
+    if ctx.accounts.treasury.is_winding_down() {
+        return Err(crate::error::ErrorCode::TreasuryWindingDown.into());
+    }
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    
+    // The arbitrator must be neutral, so neither the vendor nor the default key
+    if amount == 0
+        || release_time <= current_time
+        || arbitrator == Pubkey::default()
+        || arbitrator == ctx.accounts.recipient.recipient_address
+    {
+        return Err(crate::error::ErrorCode::InvalidEscrow.into());
+    }
+    
+    // Amounts above the lowest approval tier need an approved request
+    crate::utils::enforce_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::CreateEscrow { recipient: ctx.accounts.recipient.key(), escrow_id },
+        terms_hash,
+        amount,
+        token_mint,
+        current_time,
+    )?;
+    
+    // The funds stay in the treasury's custody but leave its booked balance
+    match token_mint {
+        None => {
+            if ctx.accounts.treasury.total_balance < amount {
+                return Err(crate::error::ErrorCode::InsufficientFunds.into());
+            }
+            crate::utils::validate_sol_reserve(&ctx.accounts.treasury, amount, false)?;
+            
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.total_balance -= amount;
+        },
+        Some(mint) => {
+            let token_vault = ctx.accounts.token_vault.as_mut()
+                .filter(|token_vault| token_vault.token_mint == mint)
+                .ok_or(crate::error::ErrorCode::InvalidTokenVault)?;
+            if token_vault.balance < amount {
+                return Err(crate::error::ErrorCode::InsufficientFunds.into());
+            }
+            crate::utils::validate_token_reserve(token_vault, amount, false)?;
+            
+            token_vault.balance -= amount;
+        },
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_escrows = treasury.open_escrows.checked_add(1).unwrap();
+    
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.treasury = treasury.key();
+    escrow.escrow_id = escrow_id;
+    escrow.recipient = ctx.accounts.recipient.key();
+    escrow.token_mint = token_mint;
+    escrow.amount = amount;
+    escrow.arbitrator = arbitrator;
+    escrow.release_time = release_time;
+    escrow.status = EscrowStatus::Held;
+    escrow.disputed_by = None;
+    escrow.vendor_amount = 0;
+    escrow.bump = ctx.bumps.escrow;
+    
+    Ok(())
+}
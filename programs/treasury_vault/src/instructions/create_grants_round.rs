@@ -0,0 +1,117 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    round_id: u64,
+    matching_pool: u64,
+    start_time: i64,
+    end_time: i64,
+    treasury_seed_name: String,
+)]
+pub struct CreateGrantsRound<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        init,
+        space=114,
+        payer=authority,
+        seeds = [
+            b"grants_round",
+            treasury.key().as_ref(),
+            round_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub grants_round: Account<'info, GrantsRound>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` grants_round: [GrantsRound] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the create schedule permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - matching_pool: [u64] Lamports of the treasury's SOL set aside for matching
+/// - start_time: [i64] When contributions open
+/// - end_time: [i64] When contributions close and matching can be computed
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<CreateGrantsRound>,
+    round_id: u64,
+    matching_pool: u64,
+    start_time: i64,
+    end_time: i64,
+) -> Result<()> {
+    // Verify authority holds the create schedule permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Rounds are new obligations, like schedules and streams
+    if ctx.accounts.treasury.is_winding_down() {
+        return Err(crate::error::ErrorCode::TreasuryWindingDown.into());
+    }
+    
+    if matching_pool == 0 || start_time >= end_time || end_time <= current_time {
+        return Err(crate::error::ErrorCode::InvalidGrantsRound.into());
+    }
+    
+    // The pool stays in the treasury's custody but leaves its booked balance
+    if ctx.accounts.treasury.total_balance < matching_pool {
+        return Err(crate::error::ErrorCode::InsufficientFunds.into());
+    }
+    crate::utils::validate_sol_reserve(&ctx.accounts.treasury, matching_pool, false)?;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_balance -= matching_pool;
+    treasury.open_rounds = treasury.open_rounds.checked_add(1).unwrap();
+    
+    let grants_round = &mut ctx.accounts.grants_round;
+    grants_round.treasury = treasury.key();
+    grants_round.round_id = round_id;
+    grants_round.matching_pool = matching_pool;
+    grants_round.start_time = start_time;
+    grants_round.end_time = end_time;
+    grants_round.project_count = 0;
+    grants_round.status = RoundStatus::Open;
+    grants_round.qf_total = 0;
+    grants_round.tally_cursor = 0;
+    grants_round.distribute_cursor = 0;
+    grants_round.matched_total = 0;
+    grants_round.deferred_count = 0;
+    grants_round.bump = ctx.bumps.grants_round;
+    
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    grant_id: u64,
+    token_mint: Option<Pubkey>,
+    milestones: Vec<MilestoneTerms>,
+    treasury_seed_name: String,
+)]
+pub struct CreateMilestoneGrant<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        init,
+        space=1838,
+        payer=authority,
+        seeds = [
+            b"milestone_grant",
+            treasury.key().as_ref(),
+            grant_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub milestone_grant: Account<'info, MilestoneGrant>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_vault.token_mint.as_ref(),
+        ],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Option<Account<'info, TokenVault>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(token_mint).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The grantee
+/// 2. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 3. `[writable]` token_vault: [TokenVault] (optional) The vault of the grant's mint, required for token grants
+/// 4. `[writable, signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
+/// 5. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 6. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for the grant's asset, if set
+/// 7. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the grant total's tier needs approvals or a delay, or passed by token-holder vote
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - token_mint: [Option<Pubkey>] Mint the grant is paid in (null for SOL)
+/// - milestones: [Vec<MilestoneTerms>] The milestones in the order they are worked through
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<CreateMilestoneGrant>,
+    grant_id: u64,
+    token_mint: Option<Pubkey>,
+    milestones: Vec<MilestoneTerms>,
+) -> Result<()> {
+    // The call's full arguments, which a passed vote must match exactly
+    let terms_hash = crate::utils::args_hash("create_milestone_grant", &(grant_id, token_mint, &milestones))?;
+    
+    // Verify authority holds the create schedule permission, unless token holders voted this exact call through
+    crate::utils::authorize_action(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.approval_request.as_deref(),
+        ApprovalAction::CreateMilestoneGrant { recipient: ctx.accounts.recipient.key(), grant_id },
+        terms_hash,
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Grants are new obligations, like schedules and streams
+    if ctx.accounts.treasury.is_winding_down() {
+        return Err(crate::error::ErrorCode::TreasuryWindingDown.into());
+    }
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    
+    MilestoneGrant::validate_terms(&milestones, current_time)?;
+    
+    let milestone_grant = &mut ctx.accounts.milestone_grant;
+    milestone_grant.treasury = ctx.accounts.treasury.key();
+    milestone_grant.grant_id = grant_id;
+    milestone_grant.recipient = ctx.accounts.recipient.key();
+    milestone_grant.token_mint = token_mint;
+    milestone_grant.milestones = milestones.into_iter().map(Milestone::from).collect();
+    milestone_grant.bump = ctx.bumps.milestone_grant;
+    let total_amount = milestone_grant.total_amount()?;
+    
+    // Amounts above the lowest approval tier need an approved request
+    crate::utils::enforce_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::CreateMilestoneGrant { recipient: ctx.accounts.recipient.key(), grant_id },
+        terms_hash,
+        total_amount,
+        token_mint,
+        current_time,
+    )?;
+    
+    // The grant's total stays in the treasury's custody but leaves its booked balance
+    match token_mint {
+        None => {
+            if ctx.accounts.treasury.total_balance < total_amount {
+                return Err(crate::error::ErrorCode::InsufficientFunds.into());
+            }
+            crate::utils::validate_sol_reserve(&ctx.accounts.treasury, total_amount, false)?;
+            
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.total_balance -= total_amount;
+        },
+        Some(mint) => {
+            let token_vault = ctx.accounts.token_vault.as_mut()
+                .filter(|token_vault| token_vault.token_mint == mint)
+                .ok_or(crate::error::ErrorCode::InvalidTokenVault)?;
+            if token_vault.balance < total_amount {
+                return Err(crate::error::ErrorCode::InsufficientFunds.into());
+            }
+            crate::utils::validate_token_reserve(token_vault, total_amount, false)?;
+            
+            token_vault.balance -= total_amount;
+        },
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_grants = treasury.open_grants.checked_add(1).unwrap();
+    
+    Ok(())
+}
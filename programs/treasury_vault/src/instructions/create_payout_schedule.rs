@@ -16,10 +16,14 @@ use anchor_spl::{
     start_time: i64,
     interval_seconds: u64,
     max_executions: u64,
+    recurrence: Recurrence,
+    end_time: i64,
+    oracle_pricing: Option<OraclePricing>,
     treasury_seed_name: String,
 )]
 pub struct CreatePayoutSchedule<'info> {
     #[account(
+        mut,
         seeds = [
             b"treasury",
             treasury_seed_name.as_bytes().as_ref(),
@@ -29,6 +33,7 @@ pub struct CreatePayoutSchedule<'info> {
     pub treasury: Account<'info, TreasuryConfig>,
 
     #[account(
+        mut,
         seeds = [
             b"recipient",
             treasury.key().as_ref(),
@@ -40,7 +45,7 @@ pub struct CreatePayoutSchedule<'info> {
 
     #[account(
         init,
-        space=171,
+        space=520,
         payer=authority,
         seeds = [
             b"schedule",
@@ -55,14 +60,41 @@ pub struct CreatePayoutSchedule<'info> {
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(token_mint).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
 }
 
 /// Accounts:
-/// 0. `[]` treasury: [TreasuryConfig] 
-/// 1. `[]` recipient: [Recipient] 
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
 /// 2. `[writable]` payout_schedule: [PayoutSchedule] 
-/// 3. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 3. `[signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
 /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 6. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for this asset, if set
+/// 7. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the amount's tier needs approvals or a delay, or passed by token-holder vote
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
@@ -72,6 +104,9 @@ pub struct CreatePayoutSchedule<'info> {
 /// - start_time: [i64] When this schedule starts
 /// - interval_seconds: [u64] Interval between payouts in seconds (0 for one-time)
 /// - max_executions: [u64] Maximum number of executions (0 for unlimited)
+/// - recurrence: [Recurrence] When payouts fall due, counted from start_time
+/// - end_time: [i64] No payouts fall due after this time (0 for no end)
+/// - oracle_pricing: [Option<OraclePricing>] Price feed converting a USD amount at execution (null for a raw amount); not allowed while the asset has approval tiers
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
 pub fn handler(
     ctx: Context<CreatePayoutSchedule>,
@@ -82,7 +117,21 @@ pub fn handler(
     start_time: i64,
     interval_seconds: u64,
     max_executions: u64,
+    recurrence: Recurrence,
+    end_time: i64,
+    oracle_pricing: Option<OraclePricing>,
 ) -> Result<()> {
+    recurrence.validate()?;
+    
+    if let Some(pricing) = oracle_pricing {
+        pricing.validate()?;
+        crate::utils::validate_oracle_pricing_allowed(&ctx.accounts.approval_policy)?;
+    }
+
+    if end_time != 0 && end_time < start_time {
+        return Err(crate::error::ErrorCode::InvalidEndTime.into());
+    }
+
     // Initialize the payout schedule
     let payout_schedule = &mut ctx.accounts.payout_schedule;
     payout_schedule.treasury = ctx.accounts.treasury.key();
@@ -93,21 +142,63 @@ pub fn handler(
     payout_schedule.start_time = start_time;
     payout_schedule.interval_seconds = interval_seconds;
     payout_schedule.max_executions = max_executions;
+    payout_schedule.recurrence = recurrence;
     payout_schedule.executions = 0;
     payout_schedule.last_execution_time = 0;
-    payout_schedule.is_active = true;
+    payout_schedule.status = ScheduleStatus::Pending;
+    payout_schedule.is_paused = false;
+    payout_schedule.end_time = end_time;
+    payout_schedule.cancelled_by = None;
+    payout_schedule.cancelled_at = 0;
+    payout_schedule.cancel_reason = String::new();
+    payout_schedule.splits = Vec::new();
+    payout_schedule.oracle_pricing = oracle_pricing;
+    payout_schedule.priority = 0;
+    payout_schedule.claim_window_seconds = 0;
     payout_schedule.bump = ctx.bumps.payout_schedule;
     
-    // Verify authority is admin or treasurer
+    // The call's full arguments, which a passed vote must match exactly
+    let terms_hash = crate::utils::args_hash("create_payout_schedule", &(recipient_address, schedule_id, amount, token_mint, start_time, interval_seconds, max_executions, recurrence, end_time, oracle_pricing))?;
+    
+    // Verify authority holds the create schedule permission, unless token holders voted this exact call through
+    crate::utils::authorize_action(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.approval_request.as_deref(),
+        ApprovalAction::CreatePayoutSchedule { recipient: ctx.accounts.recipient.key(), schedule_id },
+        terms_hash,
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+
+    // The tier covers everything the schedule can pay out; unbounded schedules take the top tier
+    let commitment = ctx.accounts.payout_schedule.commitment();
+    crate::utils::enforce_commitment_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::CreatePayoutSchedule { recipient: ctx.accounts.recipient.key(), schedule_id },
+        terms_hash,
+        amount,
+        commitment,
+        token_mint,
+        Clock::get()?.unix_timestamp,
+    )?;
     let treasury = &ctx.accounts.treasury;
-    if ctx.accounts.authority.key() != treasury.admin && ctx.accounts.authority.key() != treasury.treasurer {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
-    }
     
-    // Verify recipient is active
-    if !ctx.accounts.recipient.is_active {
-        return Err(crate::error::ErrorCode::InactiveRecipient.into());
+    // Verify recipient is active and their whitelist entry has not expired
+    ctx.accounts.recipient.validate_payable(Clock::get()?.unix_timestamp)?;
+    
+    // No new schedules once the treasury is being decommissioned
+    if treasury.is_winding_down() {
+        return Err(crate::error::ErrorCode::TreasuryWindingDown.into());
     }
     
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_schedules = treasury.open_schedules.checked_add(1).unwrap();
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.active_schedules = recipient.active_schedules.checked_add(1).unwrap();
+    
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,146 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    action: ApprovalAction,
+    amount: u64,
+    token_mint: Option<Pubkey>,
+    terms_hash: [u8; 32],
+    treasury_seed_name: String,
+)]
+pub struct CreateSpendingProposal<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [
+            b"voter",
+            treasury.key().as_ref(),
+            proposer.key().as_ref(),
+        ],
+        bump = voter_record.bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    #[account(
+        init,
+        space=8 + ApprovalRequest::INIT_SPACE,
+        payer=proposer,
+        seeds = [
+            b"approval_request",
+            treasury.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub approval_request: Account<'info, ApprovalRequest>,
+
+    #[account(
+        init,
+        space=140,
+        payer=proposer,
+        seeds = [
+            b"proposal",
+            approval_request.key().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, SpendingProposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` governance: [GovernanceConfig] 
+/// 2. `[]` voter_record: [VoterRecord] Deposits of the proposer
+/// 3. `[writable]` approval_request: [ApprovalRequest] Describes the action voted on
+/// 4. `[writable]` proposal: [SpendingProposal] 
+/// 5. `[writable, signer]` proposer: [AccountInfo] Must have deposited governance tokens
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for the proposal's approval request
+/// - action: [ApprovalAction] The spending action to vote on
+/// - amount: [u64] Amount the action moves, as passed to it
+/// - token_mint: [Option<Pubkey>] Asset the action moves (null for SOL)
+/// - terms_hash: [[u8; 32]] `args_hash` of the instruction call performing the action, binding every argument
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<CreateSpendingProposal>,
+    request_id: u64,
+    action: ApprovalAction,
+    amount: u64,
+    token_mint: Option<Pubkey>,
+    terms_hash: [u8; 32],
+) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    governance.validate_enabled()?;
+    
+    // Any holder with deposited tokens may propose a spending action
+    if ctx.accounts.voter_record.deposited == 0 {
+        return Err(crate::error::ErrorCode::NoVotingWeight.into());
+    }
+    
+    if !action.is_votable() || amount == 0 {
+        return Err(crate::error::ErrorCode::InvalidProposal.into());
+    }
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    let voting_ends_at = current_time.checked_add(governance.voting_period_seconds as i64).unwrap();
+    
+    // The approval request carries the action; passing the vote marks it as authorized
+    let approval_request = &mut ctx.accounts.approval_request;
+    approval_request.treasury = ctx.accounts.treasury.key();
+    approval_request.request_id = request_id;
+    approval_request.proposer = ctx.accounts.proposer.key();
+    approval_request.action = action;
+    approval_request.amount = amount;
+    approval_request.token_mint = token_mint;
+    approval_request.approvers = Vec::new();
+    approval_request.admin_approved = false;
+    approval_request.created_at = current_time;
+    approval_request.last_approved_at = 0;
+    approval_request.is_executed = false;
+    approval_request.bump = ctx.bumps.approval_request;
+    approval_request.vote_passed = false;
+    approval_request.council_weight = 0;
+    approval_request.terms_hash = terms_hash;
+    approval_request.expires_at = voting_ends_at.checked_add(APPROVAL_REQUEST_LIFETIME_SECONDS).unwrap();
+    
+    // Quorum and threshold are fixed for the proposal's lifetime
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.treasury = ctx.accounts.treasury.key();
+    proposal.approval_request = ctx.accounts.approval_request.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.voting_ends_at = voting_ends_at;
+    proposal.quorum = governance.quorum;
+    proposal.threshold_bps = governance.threshold_bps;
+    proposal.yes_weight = 0;
+    proposal.no_weight = 0;
+    proposal.status = ProposalStatus::Voting;
+    proposal.bump = ctx.bumps.proposal;
+    
+    Ok(())
+}
@@ -16,6 +16,7 @@ use anchor_lang::prelude::*;
 )]
 pub struct CreateStreamingSchedule<'info> {
     #[account(
+        mut,
         seeds = [
             b"treasury",
             treasury_seed_name.as_bytes().as_ref(),
@@ -25,6 +26,7 @@ pub struct CreateStreamingSchedule<'info> {
     pub treasury: Account<'info, TreasuryConfig>,
 
     #[account(
+        mut,
         seeds = [
             b"recipient",
             treasury.key().as_ref(),
@@ -36,7 +38,7 @@ pub struct CreateStreamingSchedule<'info> {
 
     #[account(
         init,
-        space = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 33 + 8 + 1 + 1 + 32 + 1, // ~200 bytes
+        space = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 33 + 8 + 1 + 1 + 32 + 1 + 1 + 8, // ~210 bytes
         payer = authority,
         seeds = [
             b"stream",
@@ -52,6 +54,30 @@ pub struct CreateStreamingSchedule<'info> {
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(token_mint).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
 }
 
 pub fn handler(
@@ -65,16 +91,39 @@ pub fn handler(
     duration_seconds: u64,
     token_mint: Option<Pubkey>,
 ) -> Result<()> {
-    // Verify authority is admin or treasurer
+    // The call's full arguments, which a passed vote must match exactly
+    let terms_hash = crate::utils::args_hash("create_streaming_schedule", &(recipient_address, stream_id, total_amount, amount_per_second, start_time, cliff_time, duration_seconds, token_mint))?;
+
+    // Verify authority holds the create schedule permission, unless token holders voted this exact call through
+    crate::utils::authorize_action(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.approval_request.as_deref(),
+        ApprovalAction::CreateStreamingSchedule { recipient: ctx.accounts.recipient.key(), stream_id },
+        terms_hash,
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+
+    // Amounts above the lowest approval tier need an approved request
+    crate::utils::enforce_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::CreateStreamingSchedule { recipient: ctx.accounts.recipient.key(), stream_id },
+        terms_hash,
+        total_amount,
+        token_mint,
+        Clock::get()?.unix_timestamp,
+    )?;
     let treasury = &ctx.accounts.treasury;
-    if ctx.accounts.authority.key() != treasury.admin && 
-       ctx.accounts.authority.key() != treasury.treasurer {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
-    }
 
-    // Verify recipient is active
-    if !ctx.accounts.recipient.is_active {
-        return Err(crate::error::ErrorCode::InactiveRecipient.into());
+    // Verify recipient is active and their whitelist entry has not expired
+    ctx.accounts.recipient.validate_payable(Clock::get()?.unix_timestamp)?;
+
+    // No new streams once the treasury is being decommissioned
+    if treasury.is_winding_down() {
+        return Err(crate::error::ErrorCode::TreasuryWindingDown.into());
     }
 
     // Validate streaming parameters
@@ -112,10 +161,18 @@ pub fn handler(
         }
     }
 
+    let treasury_key = treasury.key();
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_streams = treasury.open_streams.checked_add(1).unwrap();
+
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.active_streams = recipient.active_streams.checked_add(1).unwrap();
+    let recipient_key = recipient.key();
+
     // Initialize the streaming schedule
     let streaming_schedule = &mut ctx.accounts.streaming_schedule;
-    streaming_schedule.treasury = treasury.key();
-    streaming_schedule.recipient = ctx.accounts.recipient.key();
+    streaming_schedule.treasury = treasury_key;
+    streaming_schedule.recipient = recipient_key;
     streaming_schedule.stream_id = stream_id;
     streaming_schedule.total_amount = total_amount;
     streaming_schedule.amount_per_second = amount_per_second;
@@ -128,6 +185,8 @@ pub fn handler(
     streaming_schedule.is_cancelable = true;
     streaming_schedule.created_by = ctx.accounts.authority.key();
     streaming_schedule.bump = ctx.bumps.streaming_schedule;
+    streaming_schedule.is_paused = false;
+    streaming_schedule.paused_at = 0;
 
     msg!(
         "Streaming schedule created: {} tokens over {} seconds to {}",
@@ -0,0 +1,100 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    delegate: Pubkey,
+    treasury_seed_name: String,
+)]
+pub struct DelegateVotes<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"voter",
+            treasury.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump = voter_record.bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    #[account(
+        init,
+        space=121,
+        payer=voter,
+        seeds = [
+            b"delegation",
+            treasury.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` governance: [GovernanceConfig] 
+/// 2. `[writable]` voter_record: [VoterRecord] 
+/// 3. `[writable]` delegation: [Delegation] 
+/// 4. `[writable, signer]` voter: [AccountInfo] Must have deposited governance tokens
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - delegate: [Pubkey] Council wallet receiving the voter's weight
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<DelegateVotes>,
+    delegate: Pubkey,
+) -> Result<()> {
+    ctx.accounts.governance.validate_enabled()?;
+    
+    // Weight backing a running vote cannot be counted again until the vote ends
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time < ctx.accounts.voter_record.locked_until {
+        return Err(crate::error::ErrorCode::VotingTokensLocked.into());
+    }
+    
+    let voter = ctx.accounts.voter.key();
+    let weight = ctx.accounts.voter_record.deposited;
+    if weight == 0 || delegate == voter {
+        return Err(crate::error::ErrorCode::InvalidDelegation.into());
+    }
+    
+    // Snapshot the weight and time, so the delegation only counts for requests created later
+    let delegation = &mut ctx.accounts.delegation;
+    delegation.treasury = ctx.accounts.treasury.key();
+    delegation.voter = voter;
+    delegation.delegate = delegate;
+    delegation.weight = weight;
+    delegation.delegated_at = current_time;
+    delegation.bump = ctx.bumps.delegation;
+    
+    let voter_record = &mut ctx.accounts.voter_record;
+    voter_record.delegate = Some(delegate);
+    
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::token::Token;
+
+#[derive(Accounts)]
+#[instruction(
+    amount: u64,
+    treasury_seed_name: String,
+)]
+pub struct DepositGovernanceTokens<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    #[account(
+        init_if_needed,
+        space=122,
+        payer=voter,
+        seeds = [
+            b"voter",
+            treasury.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = governance.vault_token_account @ crate::error::ErrorCode::InvalidGovernance,
+    )]
+    /// CHECK: Bound to the governance vault
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+    )]
+    pub authority: Signer<'info>,
+
+    pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositGovernanceTokens<'info> {
+    pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
+}
+
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` governance: [GovernanceConfig] 
+/// 2. `[writable]` voter_record: [VoterRecord] 
+/// 3. `[writable, signer]` voter: [AccountInfo] 
+/// 4. `[writable]` source: [AccountInfo] The source account.
+/// 5. `[writable]` destination: [AccountInfo] The governance vault.
+/// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - amount: [u64] Amount of governance tokens to deposit
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<DepositGovernanceTokens>,
+    amount: u64,
+) -> Result<()> {
+    ctx.accounts.governance.validate_enabled()?;
+    
+    // Transfer tokens from source to the governance vault
+    ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
+    
+    let governance = &mut ctx.accounts.governance;
+    governance.total_deposited = governance.total_deposited.checked_add(amount).unwrap();
+    
+    // Deposits add to the voter's weight in votes cast from now on; a standing
+    // delegation keeps the weight it was made with until it is made again
+    let voter_record = &mut ctx.accounts.voter_record;
+    voter_record.treasury = ctx.accounts.treasury.key();
+    voter_record.voter = ctx.accounts.voter.key();
+    voter_record.deposited = voter_record.deposited.checked_add(amount).unwrap();
+    voter_record.bump = ctx.bumps.voter_record;
+    
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    escrow_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct DisputeEscrow<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            treasury.key().as_ref(),
+            escrow_id.to_le_bytes().as_ref(),
+        ],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    pub disputer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            disputer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The vendor
+/// 2. `[writable]` escrow: [Escrow] 
+/// 3. `[signer]` disputer: [AccountInfo] The vendor's wallet address, or a holder of the execute payout permission for the treasury's side
+/// 4. `[]` authority_member: [Member] (optional) Membership of the disputer
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<DisputeEscrow>,
+) -> Result<()> {
+    if ctx.accounts.escrow.recipient != ctx.accounts.recipient.key() {
+        return Err(crate::error::ErrorCode::InvalidRecipient.into());
+    }
+    
+    // Either the vendor or the treasury's side may dispute
+    let disputer = ctx.accounts.disputer.key();
+    if disputer != ctx.accounts.recipient.recipient_address {
+        crate::utils::authorize(
+            &ctx.accounts.treasury,
+            &disputer,
+            ctx.accounts.authority_member.as_ref(),
+            PERMISSION_EXECUTE_PAYOUT,
+        )?;
+    }
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.escrow.validate_disputable(current_time)?;
+    
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.status = EscrowStatus::Disputed;
+    escrow.disputed_by = Some(disputer);
+    
+    msg!("Escrow {} disputed by {}; awaiting arbitrator {}", escrow.escrow_id, disputer, escrow.arbitrator);
+    
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    round_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct DistributeRound<'info> {
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"grants_round",
+            treasury.key().as_ref(),
+            round_id.to_le_bytes().as_ref(),
+        ],
+        bump = grants_round.bump
+    )]
+    pub grants_round: Account<'info, GrantsRound>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            fee_payer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` grants_round: [GrantsRound] 
+/// 3. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 4. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 5. `[writable]` remaining_accounts: for each of the round's next projects, in registration order, or project with a deferred match, its [RoundProject], its [Recipient] and the recipient's payout address
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeRound<'info>>,
+) -> Result<()> {
+    // Verify fee payer may crank the round under the treasury's keeper mode
+    crate::utils::authorize_keeper(
+        &ctx.accounts.treasury,
+        &ctx.accounts.fee_payer.key(),
+        ctx.accounts.authority_member.as_ref(),
+    )?;
+    
+    if ctx.accounts.grants_round.status != RoundStatus::Distributing {
+        return Err(crate::error::ErrorCode::InvalidRoundStatus.into());
+    }
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let grants_round_key = ctx.accounts.grants_round.key();
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    
+    // Pay each project its share of the pool straight from the round's
+    // reservation, which keeps the rest out of the booked balance until paid
+    for accounts in ctx.remaining_accounts.chunks(3) {
+        let grants_round = &ctx.accounts.grants_round;
+        let mut round_project = Account::<RoundProject>::try_from(&accounts[0])?;
+        let deferred = round_project.index < grants_round.distribute_cursor && !round_project.match_settled;
+        if accounts.len() != 3
+            || round_project.grants_round != grants_round_key
+            || !(deferred || round_project.index == grants_round.distribute_cursor)
+            || accounts[1].key() != round_project.recipient
+        {
+            return Err(crate::error::ErrorCode::InvalidRoundProject.into());
+        }
+        
+        let mut recipient = Account::<Recipient>::try_from(&accounts[1])?;
+        if accounts[2].key() != recipient.payout_address {
+            return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+        }
+        
+        // A project that can no longer be paid forfeits its match to the treasury;
+        // one held up by its recipient caps keeps it for a later crank
+        let mut matched_amount = grants_round.match_for(round_project.qf_score())?;
+        let mut settled = true;
+        if recipient.validate_payable(current_time).is_err() {
+            matched_amount = 0;
+        } else if recipient.validate_receivable(&ctx.accounts.treasury, matched_amount, current_time).is_err() {
+            settled = false;
+        }
+        
+        if matched_amount > 0 && settled {
+            // The reserved match counts toward the balance the payment leaves
+            let outflow = Outflow {
+                amount: matched_amount,
+                destinations: vec![accounts[2].key()],
+                balance: ctx.accounts.treasury.total_balance.saturating_add(matched_amount),
+                current_time,
+            };
+            crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+            
+            // Transfer SOL
+            **treasury_info.try_borrow_mut_lamports()? -= matched_amount;
+            **accounts[2].try_borrow_mut_lamports()? += matched_amount;
+            
+            recipient.record_payout(matched_amount, current_time);
+            recipient.exit(&crate::ID)?;
+        }
+        
+        round_project.matched_amount = matched_amount;
+        round_project.match_settled = settled;
+        round_project.exit(&crate::ID)?;
+        
+        let grants_round = &mut ctx.accounts.grants_round;
+        if settled {
+            grants_round.matched_total = grants_round.matched_total.checked_add(matched_amount).unwrap();
+        }
+        if !deferred {
+            grants_round.distribute_cursor += 1;
+            if !settled {
+                grants_round.deferred_count += 1;
+            }
+        } else if settled {
+            grants_round.deferred_count -= 1;
+        }
+    }
+    
+    // Once every project is paid or forfeited, the unpaid rest of the pool returns to the treasury
+    let grants_round = &mut ctx.accounts.grants_round;
+    let treasury = &mut ctx.accounts.treasury;
+    if grants_round.is_distributed() {
+        let unmatched = grants_round.matching_pool - grants_round.matched_total;
+        treasury.total_balance = treasury.total_balance.checked_add(unmatched).unwrap();
+        treasury.open_rounds = treasury.open_rounds.saturating_sub(1);
+        grants_round.status = RoundStatus::Finalized;
+    }
+    
+    msg!(
+        "Cranked {} of {} round projects, {} deferred, paying {} of {} lamports",
+        grants_round.distribute_cursor,
+        grants_round.project_count,
+        grants_round.deferred_count,
+        grants_round.matched_total,
+        grants_round.matching_pool
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,169 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+#[derive(Accounts)]
+#[instruction(
+    treasury_seed_name: String,
+)]
+pub struct DrainQueue<'info> {
+    #[account(
+        mut,
+        owner=Pubkey::from_str("11111111111111111111111111111111").unwrap(),
+    )]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"payout_queue",
+            treasury.key().as_ref(),
+        ],
+        bump = payout_queue.bump
+    )]
+    pub payout_queue: Account<'info, PendingPayoutQueue>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            fee_payer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless; receives the keeper tip
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` payout_queue: [PendingPayoutQueue] 
+/// 3. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 4. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 5. `[writable]` remaining_accounts: for each queued payout to drain, from the front, its [Recipient] and that recipient's payout address
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DrainQueue<'info>>,
+) -> Result<()> {
+    // Verify fee payer may execute payouts under the treasury's keeper mode
+    crate::utils::authorize_keeper(
+        &ctx.accounts.treasury,
+        &ctx.accounts.fee_payer.key(),
+        ctx.accounts.authority_member.as_ref(),
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    
+    // Pay queued payouts from the front until one no longer fits the
+    // spending limits; the ones behind it keep their place
+    let mut index = 0;
+    let mut drained = 0;
+    let mut dropped = 0;
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let entry = match ctx.accounts.payout_queue.entries.get(index) {
+            Some(entry) => *entry,
+            None => break,
+        };
+        
+        if accounts.len() != 2 || accounts[0].key() != entry.recipient {
+            return Err(crate::error::ErrorCode::InvalidQueuedPayout.into());
+        }
+        
+        let mut recipient = Account::<Recipient>::try_from(&accounts[0])?;
+        if recipient.treasury != ctx.accounts.treasury.key() {
+            return Err(crate::error::ErrorCode::InvalidQueuedPayout.into());
+        }
+        if accounts[1].key() != recipient.payout_address {
+            return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+        }
+        
+        // A recipient deactivated or expired since queueing can no longer be paid;
+        // drop its entry, returning its amount to the booked balance, so the
+        // payouts behind it move up
+        if recipient.validate_payable(current_time).is_err() {
+            ctx.accounts.payout_queue.entries.remove(index);
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.total_balance = treasury.total_balance.checked_add(entry.amount).unwrap();
+            dropped += 1;
+            continue;
+        }
+        
+        if ctx.accounts.treasury.remaining_spend(current_time) < entry.amount {
+            break;
+        }
+        
+        // A recipient at its caps for now, or a treasury account short of
+        // lamports, holds up only this entry: it keeps its place while the
+        // payouts behind it are paid
+        if recipient.validate_receivable(&ctx.accounts.treasury, entry.amount, current_time).is_err()
+            || crate::utils::validate_reserved_sol_outflow(&ctx.accounts.treasury, entry.amount).is_err()
+        {
+            index += 1;
+            continue;
+        }
+        
+        // The amount left the booked balance when it was queued, so it counts
+        // toward the balance the payout leaves
+        let outflow = Outflow {
+            amount: entry.amount,
+            destinations: vec![accounts[1].key()],
+            balance: ctx.accounts.treasury.total_balance.saturating_add(entry.amount),
+            current_time,
+        };
+        crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+        
+        // Transfer SOL
+        **treasury_info.try_borrow_mut_lamports()? -= entry.amount;
+        **accounts[1].try_borrow_mut_lamports()? += entry.amount;
+        
+        recipient.record_payout(entry.amount, current_time);
+        recipient.exit(&crate::ID)?;
+        
+        ctx.accounts.payout_queue.entries.remove(index);
+        drained += 1;
+    }
+    
+    if drained == 0 && dropped == 0 {
+        return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
+    }
+    
+    msg!(
+        "Drained {} queued payouts and dropped {} no longer payable, {} still queued",
+        drained,
+        dropped,
+        ctx.accounts.payout_queue.entries.len()
+    );
+    
+    // Tip the keeper once for the whole drain, if it paid anything
+    if drained > 0 {
+        let fee_payer = ctx.accounts.fee_payer.to_account_info();
+        crate::utils::pay_keeper_tip(&mut ctx.accounts.treasury, &fee_payer, current_time)?;
+    }
+    
+    Ok(())
+}
@@ -27,11 +27,38 @@ pub struct EmergencyWithdrawSol<'info> {
         mut,
     )]
     pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            admin.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
 }
 
 /// Accounts:
 /// 0. `[writable]` treasury: [TreasuryConfig] 
-/// 1. `[writable, signer]` admin: [AccountInfo] 
+/// 1. `[writable, signer]` admin: [AccountInfo] Must hold the emergency permission
+/// 2. `[]` authority_member: [Member] (optional) Membership of the admin
+/// 3. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for this asset, if set
+/// 4. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the amount's tier needs approvals or a delay, or to go below the reserve floor
 ///
 /// Data:
 /// - amount: [u64] Amount of SOL to withdraw (in lamports)
@@ -40,17 +67,47 @@ pub fn handler(
     ctx: Context<EmergencyWithdrawSol>,
     amount: u64,
 ) -> Result<()> {
-    // Verify the signer is the admin
-    if ctx.accounts.admin.key() != ctx.accounts.treasury.admin {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
+    // Verify admin holds the emergency permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_EMERGENCY,
+    )?;
+
+    // Going below the reserve floor needs a request approved by several approvers
+    let below_floor = ctx.accounts.treasury.total_balance.saturating_sub(amount) < ctx.accounts.treasury.reserve_floor;
+    if below_floor {
+        crate::utils::validate_floor_override(
+            &ctx.accounts.treasury.key(),
+            ctx.accounts.approval_request.as_deref(),
+            ApprovalAction::EmergencyWithdrawSol,
+            amount,
+            None,
+        )?;
     }
+
+    // Amounts above the lowest approval tier need an approved request
+    crate::utils::enforce_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::EmergencyWithdrawSol,
+        crate::utils::args_hash("emergency_withdraw_sol", &amount)?,
+        amount,
+        None,
+        Clock::get()?.unix_timestamp,
+    )?;
     
-    // Check if treasury has enough funds
+    // Check if treasury has enough funds, leaving those reserved for escrows, grants and bounties
     let treasury_info = ctx.accounts.treasury.to_account_info();
-    if **treasury_info.lamports.borrow() < amount {
+    if **treasury_info.lamports.borrow() < amount || ctx.accounts.treasury.total_balance < amount {
         return Err(crate::error::ErrorCode::InsufficientFunds.into());
     }
     
+    // The treasury account always stays rent-exempt
+    crate::utils::validate_sol_reserve(&ctx.accounts.treasury, amount, below_floor)?;
+    
     // Transfer SOL
     **treasury_info.try_borrow_mut_lamports()? -= amount;
     **ctx.accounts.admin.to_account_info().try_borrow_mut_lamports()? += amount;
@@ -59,5 +116,10 @@ pub fn handler(
     let treasury = &mut ctx.accounts.treasury;
     treasury.total_balance = treasury.total_balance.checked_sub(amount).unwrap();
     
+    // Spend the request that allowed going below the floor
+    if let Some(approval_request) = ctx.accounts.approval_request.as_mut().filter(|_| below_floor) {
+        approval_request.is_executed = true;
+    }
+    
     Ok(())
 }
\ No newline at end of file
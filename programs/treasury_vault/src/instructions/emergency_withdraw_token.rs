@@ -55,6 +55,30 @@ pub struct EmergencyWithdrawToken<'info> {
     pub authority: Signer<'info>,
 
     pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            admin.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(Some(token_mint.key())).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
 }
 
 impl<'info> EmergencyWithdrawToken<'info> {
@@ -77,11 +101,14 @@ impl<'info> EmergencyWithdrawToken<'info> {
 /// 0. `[]` treasury: [TreasuryConfig] 
 /// 1. `[writable]` token_vault: [TokenVault] 
 /// 2. `[]` token_mint: [Mint] 
-/// 3. `[signer]` admin: [AccountInfo] 
+/// 3. `[signer]` admin: [AccountInfo] Must hold the emergency permission
 /// 4. `[writable]` source: [AccountInfo] The source account.
 /// 5. `[writable]` destination: [AccountInfo] The destination account.
 /// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
 /// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 8. `[]` authority_member: [Member] (optional) Membership of the admin
+/// 9. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for this asset, if set
+/// 10. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the amount's tier needs approvals or a delay, or to go below the reserve floor
 ///
 /// Data:
 /// - amount: [u64] Amount of tokens to withdraw
@@ -90,10 +117,37 @@ pub fn handler(
     ctx: Context<EmergencyWithdrawToken>,
     amount: u64,
 ) -> Result<()> {
-    // Verify the signer is the admin
-    if ctx.accounts.admin.key() != ctx.accounts.treasury.admin {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
+    // Verify admin holds the emergency permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_EMERGENCY,
+    )?;
+
+    // Going below the reserve floor needs a request approved by several approvers
+    let below_floor = ctx.accounts.token_vault.balance.saturating_sub(amount) < ctx.accounts.token_vault.reserve_floor;
+    if below_floor {
+        crate::utils::validate_floor_override(
+            &ctx.accounts.treasury.key(),
+            ctx.accounts.approval_request.as_deref(),
+            ApprovalAction::EmergencyWithdrawToken,
+            amount,
+            Some(ctx.accounts.token_mint.key()),
+        )?;
     }
+
+    // Amounts above the lowest approval tier need an approved request
+    crate::utils::enforce_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::EmergencyWithdrawToken,
+        crate::utils::args_hash("emergency_withdraw_token", &amount)?,
+        amount,
+        Some(ctx.accounts.token_mint.key()),
+        Clock::get()?.unix_timestamp,
+    )?;
     
     // Check if token vault has enough funds
     if ctx.accounts.token_vault.balance < amount {
@@ -107,5 +161,10 @@ pub fn handler(
     let token_vault = &mut ctx.accounts.token_vault;
     token_vault.balance = token_vault.balance.checked_sub(amount).unwrap();
     
+    // Spend the request that allowed going below the floor
+    if let Some(approval_request) = ctx.accounts.approval_request.as_mut().filter(|_| below_floor) {
+        approval_request.is_executed = true;
+    }
+    
     Ok(())
 }
\ No newline at end of file
@@ -52,122 +52,237 @@ pub struct ExecuteSolPayout<'info> {
     )]
     pub payout_schedule: Account<'info, PayoutSchedule>,
 
+    /// CHECK: Only used to derive the recipient account
+    pub recipient_address: UncheckedAccount<'info>,
+
     #[account(
         mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
     )]
-    /// CHECK: implement manual checks if needed
-    pub recipient_address: UncheckedAccount<'info>,
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            fee_payer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    /// CHECK: Matched against the schedule's price feed by `period_payout_amount`
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"payout_queue",
+            treasury.key().as_ref(),
+        ],
+        bump = payout_queue.bump
+    )]
+    pub payout_queue: Option<Account<'info, PendingPayoutQueue>>,
 }
 
 /// Accounts:
-/// 0. `[writable, signer]` fee_payer: [AccountInfo] Auto-generated, default fee payer
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless; receives the keeper tip
 /// 1. `[writable]` treasury: [TreasuryConfig] 
 /// 2. `[writable]` recipient: [Recipient] 
 /// 3. `[writable]` payout_schedule: [PayoutSchedule] 
-/// 4. `[writable]` recipient_address: [AccountInfo] 
+/// 4. `[]` recipient_address: [AccountInfo] The recipient's wallet address
+/// 5. `[writable]` payout_address: [AccountInfo] The recipient's bound payout address
+/// 6. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 7. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 8. `[]` price_feed: [AccountInfo] (optional) The schedule's price feed, required for oracle-priced schedules
+/// 9. `[writable]` payout_queue: [PendingPayoutQueue] (optional) Queues the payout instead of failing when it exceeds the spending limits
+/// 10. `[writable]` remaining_accounts: for each split in order, its [Recipient] and that recipient's payout address
 ///
 /// Data:
 /// - schedule_id: [u64] Unique identifier for this schedule
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
-pub fn handler(
-    ctx: Context<ExecuteSolPayout>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteSolPayout<'info>>,
     schedule_id: u64,
 ) -> Result<()> {
+    // Verify fee payer may execute payouts under the treasury's keeper mode
+    crate::utils::authorize_keeper(
+        &ctx.accounts.treasury,
+        &ctx.accounts.fee_payer.key(),
+        ctx.accounts.authority_member.as_ref(),
+    )?;
+    
     // Get current time
     let current_time = Clock::get()?.unix_timestamp;
     
-    // Verify payout schedule is active
-    if !ctx.accounts.payout_schedule.is_active {
+    // Verify payout schedule is neither paused nor finished
+    if !ctx.accounts.payout_schedule.is_payable() {
         return Err(crate::error::ErrorCode::InvalidSchedule.into());
-        
     }
     
-    // Verify payout is due
-    if current_time < ctx.accounts.payout_schedule.start_time {
-        return Err(crate::error::ErrorCode::PayoutNotDue.into());
+    // Claim mode schedules are paid when their recipient claims them
+    if ctx.accounts.payout_schedule.is_claim_mode() {
+        return Err(crate::error::ErrorCode::PayoutModeMismatch.into());
     }
     
+    // Verify recipient is active and their whitelist entry has not expired
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    
     // Check if max executions reached
     if ctx.accounts.payout_schedule.max_executions > 0 && 
        ctx.accounts.payout_schedule.executions >= ctx.accounts.payout_schedule.max_executions {
         return Err(crate::error::ErrorCode::MaxExecutionsReached.into());
     }
     
-    // For recurring payments, check if next payment is due
-    if ctx.accounts.payout_schedule.interval_seconds > 0 && 
-       ctx.accounts.payout_schedule.last_execution_time > 0 {
-        let next_execution = ctx.accounts.payout_schedule.last_execution_time + 
-                            ctx.accounts.payout_schedule.interval_seconds as i64;
-        if current_time < next_execution {
-            return Err(crate::error::ErrorCode::PayoutNotDue.into());
-        }
+    // Verify a payout is due, counted from the start time so late
+    // executions do not push later payouts back, and not after the end time
+    let due_periods = ctx.accounts.payout_schedule.due_periods(current_time);
+    if due_periods == 0 {
+        return Err(crate::error::ErrorCode::PayoutNotDue.into());
     }
     
-    // Check if treasury has enough funds
-    let amount = ctx.accounts.payout_schedule.amount;
+    // Pay every missed period at once, within the outflow policy and the
+    // recipient's caps, unless the treasury drops missed periods and pays
+    // only the latest one
+    let schedule_amount = crate::utils::period_payout_amount(
+        &ctx.accounts.payout_schedule,
+        ctx.accounts.price_feed.as_ref(),
+        SOL_DECIMALS,
+        current_time,
+    )?;
+    let paid_periods = if ctx.accounts.treasury.skip_missed_payouts || schedule_amount == 0 {
+        1
+    } else {
+        let allowance = crate::utils::outflow_allowance(
+            &ctx.accounts.treasury,
+            &ctx.accounts.policy,
+            ctx.accounts.treasury.total_balance,
+            current_time,
+        )?
+            .min(ctx.accounts.recipient.remaining_allowance(&ctx.accounts.treasury, current_time));
+        let affordable = allowance / schedule_amount;
+        due_periods.min(affordable).max(1)
+    };
+    let skipped_periods = if ctx.accounts.treasury.skip_missed_payouts { due_periods - 1 } else { 0 };
+    
+    // Check if treasury has enough funds, leaving those reserved for escrows, grants and bounties
+    let amount = schedule_amount.checked_mul(paid_periods).unwrap();
     let treasury_info = ctx.accounts.treasury.to_account_info();
-    if **treasury_info.lamports.borrow() < amount {
+    if **treasury_info.lamports.borrow() < amount || ctx.accounts.treasury.total_balance < amount {
         return Err(crate::error::ErrorCode::InsufficientFunds.into());
     }
     
-    // Check spending limits
-    let treasury = &mut ctx.accounts.treasury;
-    
-    // Reset daily limit if needed
-    if current_time - treasury.last_day_reset >= 86400 { // 24 hours in seconds
-        treasury.daily_total = 0;
-        treasury.last_day_reset = current_time;
-    }
-    
-    // Reset weekly limit if needed
-    if current_time - treasury.last_week_reset >= 604800 { // 7 days in seconds
-        treasury.weekly_total = 0;
-        treasury.last_week_reset = current_time;
-    }
+    // Normal payouts keep the reserve floor and the account's rent-exempt minimum
+    crate::utils::validate_sol_reserve(&ctx.accounts.treasury, amount, false)?;
     
-    // Reset monthly limit if needed
-    if current_time - treasury.last_month_reset >= 2592000 { // 30 days in seconds
-        treasury.monthly_total = 0;
-        treasury.last_month_reset = current_time;
-    }
+    // Queue the payout when only the spending limits stand in its way,
+    // for drain_queue to pay once the limit windows free up
+    let queue_payout = ctx.accounts.payout_queue.is_some()
+        && ctx.accounts.payout_schedule.splits.is_empty()
+        && ctx.accounts.treasury.remaining_spend(current_time) < amount;
     
-    // Check if this payment would exceed limits
-    if treasury.daily_total.checked_add(amount).unwrap() > treasury.daily_limit {
-        return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
-    }
-    
-    if treasury.weekly_total.checked_add(amount).unwrap() > treasury.weekly_limit {
-        return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
-    }
-    
-    if treasury.monthly_total.checked_add(amount).unwrap() > treasury.monthly_limit {
-        return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
+    if queue_payout {
+        // Only queue what the recipient can receive and the limits can ever
+        // let through, so the entry can be drained later
+        if amount > ctx.accounts.treasury.smallest_spending_limit() {
+            return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
+        }
+        ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, amount, current_time)?;
+        
+        let recipient_key = ctx.accounts.recipient.key();
+        let payout_schedule_key = ctx.accounts.payout_schedule.key();
+        let priority = ctx.accounts.payout_schedule.priority;
+        let payout_queue = ctx.accounts.payout_queue.as_mut().unwrap();
+        payout_queue.enqueue(recipient_key, payout_schedule_key, amount, priority, current_time)?;
+        
+        // The queued amount leaves the booked balance until it is drained or dropped
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_balance = treasury.total_balance.checked_sub(amount).unwrap();
+        
+        msg!("Payout of {} lamports queued behind the spending limits", amount);
+    } else {
+        // Check the payout against the spending limits and the outflow policy
+        let outflow = Outflow {
+            amount,
+            destinations: std::iter::once(ctx.accounts.payout_address.key())
+                .chain(ctx.remaining_accounts.iter().skip(1).step_by(2).map(|account| account.key()))
+                .collect(),
+            balance: ctx.accounts.treasury.total_balance,
+            current_time,
+        };
+        crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+        let treasury = &mut ctx.accounts.treasury;
+        
+        // Pay each split's share to its recipient, then the rest to this schedule's recipient
+        let (split_amounts, recipient_amount) = ctx.accounts.payout_schedule.split_amount(amount);
+        ctx.accounts.recipient.validate_receivable(treasury, recipient_amount, current_time)?;
+        if ctx.remaining_accounts.len() != split_amounts.len() * 2 {
+            return Err(crate::error::ErrorCode::InvalidPayoutSplit.into());
+        }
+        
+        for ((split, share), accounts) in ctx.accounts.payout_schedule.splits.iter()
+            .zip(split_amounts)
+            .zip(ctx.remaining_accounts.chunks(2))
+        {
+            let mut split_recipient = crate::utils::load_split_recipient(treasury, split, &accounts[0], current_time)?;
+            split_recipient.validate_receivable(treasury, share, current_time)?;
+            if accounts[1].key() != split_recipient.payout_address {
+                return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+            }
+            
+            **treasury_info.try_borrow_mut_lamports()? -= share;
+            **accounts[1].try_borrow_mut_lamports()? += share;
+            
+            split_recipient.record_payout(share, current_time);
+            split_recipient.exit(&crate::ID)?;
+        }
+        
+        // Transfer SOL
+        **treasury_info.try_borrow_mut_lamports()? -= recipient_amount;
+        **ctx.accounts.payout_address.to_account_info().try_borrow_mut_lamports()? += recipient_amount;
+        
+        // Update treasury totals
+        treasury.total_balance = treasury.total_balance.checked_sub(amount).unwrap();
+        
+        // Update recipient
+        let recipient = &mut ctx.accounts.recipient;
+        recipient.record_payout(recipient_amount, current_time);
     }
     
-    // Transfer SOL
-    **treasury_info.try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.recipient_address.to_account_info().try_borrow_mut_lamports()? += amount;
-    
-    // Update treasury totals
-    treasury.total_balance = treasury.total_balance.checked_sub(amount).unwrap();
-    treasury.daily_total = treasury.daily_total.checked_add(amount).unwrap();
-    treasury.weekly_total = treasury.weekly_total.checked_add(amount).unwrap();
-    treasury.monthly_total = treasury.monthly_total.checked_add(amount).unwrap();
-    
-    // Update recipient
-    let recipient = &mut ctx.accounts.recipient;
-    recipient.total_received = recipient.total_received.checked_add(amount).unwrap();
-    recipient.last_payout_time = current_time;
-    
     // Update payout schedule
     let payout_schedule = &mut ctx.accounts.payout_schedule;
-    payout_schedule.executions = payout_schedule.executions.checked_add(1).unwrap();
+    payout_schedule.executions = payout_schedule.executions.checked_add(paid_periods + skipped_periods).unwrap();
     payout_schedule.last_execution_time = current_time;
     
-    // If this was the last execution and max_executions is set, mark as inactive
-    if payout_schedule.max_executions > 0 && 
-       payout_schedule.executions >= payout_schedule.max_executions {
-        payout_schedule.is_active = false;
+    // Complete the schedule once max_executions or the end time is reached
+    payout_schedule.status = ScheduleStatus::Active;
+    if payout_schedule.is_finished() {
+        payout_schedule.status = ScheduleStatus::Completed;
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.open_schedules = treasury.open_schedules.saturating_sub(1);
+        
+        let recipient = &mut ctx.accounts.recipient;
+        recipient.active_schedules = recipient.active_schedules.saturating_sub(1);
+    }
+    
+    // Tip the keeper for a payout actually made; drain_queue tips for queued ones
+    if !queue_payout {
+        let fee_payer = ctx.accounts.fee_payer.to_account_info();
+        crate::utils::pay_keeper_tip(&mut ctx.accounts.treasury, &fee_payer, current_time)?;
     }
     
     Ok(())
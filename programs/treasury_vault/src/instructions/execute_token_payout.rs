@@ -84,6 +84,42 @@ pub struct ExecuteTokenPayout<'info> {
     pub authority: Signer<'info>,
 
     pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            fee_payer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"payout_destination",
+            recipient.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `expected_payout_token_account`
+    pub payout_destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(Some(token_mint.key())).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    /// CHECK: Matched against the schedule's price feed by `period_payout_amount`
+    pub price_feed: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> ExecuteTokenPayout<'info> {
@@ -99,20 +135,38 @@ impl<'info> ExecuteTokenPayout<'info> {
             amount, 
         )
     }
+
+    pub fn cpi_csl_spl_token_transfer_to(&self, destination: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: destination.clone(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
 }
 
 
 /// Accounts:
-/// 0. `[writable, signer]` fee_payer: [AccountInfo] Auto-generated, default fee payer
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless; receives the keeper tip
 /// 1. `[writable]` treasury: [TreasuryConfig] 
 /// 2. `[writable]` recipient: [Recipient] 
 /// 3. `[writable]` payout_schedule: [PayoutSchedule] 
 /// 4. `[writable]` token_vault: [TokenVault] 
 /// 5. `[]` token_mint: [Mint] 
 /// 6. `[writable]` source: [AccountInfo] The source account.
-/// 7. `[writable]` destination: [AccountInfo] The destination account.
+/// 7. `[writable]` destination: [AccountInfo] The recipient's bound payout token account.
 /// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
 /// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 11. `[]` payout_destination: [PayoutDestination] The recipient's token account override for this mint, if registered
+/// 12. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+/// 13. `[]` price_feed: [AccountInfo] (optional) The schedule's price feed, required for oracle-priced schedules
+/// 14. `[writable]` remaining_accounts: for each split in order, its [Recipient], that recipient's [PayoutDestination] for this mint and its payout token account
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
@@ -120,38 +174,45 @@ impl<'info> ExecuteTokenPayout<'info> {
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
 
 
-pub fn handler(
-    ctx: Context<ExecuteTokenPayout>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteTokenPayout<'info>>,
     _recipient_address: Pubkey, // Add underscore to fix unused variable warning
     _schedule_id: u64, // Add underscore to fix unused variable warning
 ) -> Result<()> {
+    // Verify fee payer may execute payouts under the treasury's keeper mode
+    crate::utils::authorize_keeper(
+        &ctx.accounts.treasury,
+        &ctx.accounts.fee_payer.key(),
+        ctx.accounts.authority_member.as_ref(),
+    )?;
+    
     // Get current time
     let current_time = Clock::get()?.unix_timestamp;
     
-    // Verify payout schedule is active
-    if !ctx.accounts.payout_schedule.is_active {
+    // Verify payout schedule is neither paused nor finished
+    if !ctx.accounts.payout_schedule.is_payable() {
         return Err(crate::error::ErrorCode::InvalidSchedule.into());
     }
     
-    // Verify payout is due
-    if current_time < ctx.accounts.payout_schedule.start_time {
-        return Err(crate::error::ErrorCode::PayoutNotDue.into());
+    // Claim mode schedules are paid when their recipient claims them
+    if ctx.accounts.payout_schedule.is_claim_mode() {
+        return Err(crate::error::ErrorCode::PayoutModeMismatch.into());
     }
     
+    // Verify recipient is active and their whitelist entry has not expired
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    
     // Check if max executions reached
     if ctx.accounts.payout_schedule.max_executions > 0 && 
        ctx.accounts.payout_schedule.executions >= ctx.accounts.payout_schedule.max_executions {
         return Err(crate::error::ErrorCode::MaxExecutionsReached.into());
     }
     
-    // For recurring payments, check if next payment is due
-    if ctx.accounts.payout_schedule.interval_seconds > 0 && 
-       ctx.accounts.payout_schedule.last_execution_time > 0 {
-        let next_execution = ctx.accounts.payout_schedule.last_execution_time + 
-                            ctx.accounts.payout_schedule.interval_seconds as i64;
-        if current_time < next_execution {
-            return Err(crate::error::ErrorCode::PayoutNotDue.into());
-        }
+    // Verify a payout is due, counted from the start time so late
+    // executions do not push later payouts back, and not after the end time
+    let due_periods = ctx.accounts.payout_schedule.due_periods(current_time);
+    if due_periods == 0 {
+        return Err(crate::error::ErrorCode::PayoutNotDue.into());
     }
     
     // Verify token mint matches the schedule
@@ -160,48 +221,95 @@ pub fn handler(
         return Err(crate::error::ErrorCode::InvalidTokenVault.into());
     }
     
+    // Verify tokens go only to the recipient's bound payout token account
+    let expected_destination = crate::utils::expected_payout_token_account(
+        &ctx.accounts.recipient,
+        &ctx.accounts.payout_destination,
+        &ctx.accounts.token_mint.key(),
+    )?;
+    if ctx.accounts.destination.key() != expected_destination {
+        return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+    }
+    
+    // Pay every missed period at once, within the outflow policy and the
+    // recipient's caps, unless the treasury drops missed periods and pays
+    // only the latest one
+    let schedule_amount = crate::utils::period_payout_amount(
+        &ctx.accounts.payout_schedule,
+        ctx.accounts.price_feed.as_ref(),
+        ctx.accounts.token_mint.decimals,
+        current_time,
+    )?;
+    let paid_periods = if ctx.accounts.treasury.skip_missed_payouts || schedule_amount == 0 {
+        1
+    } else {
+        let allowance = crate::utils::outflow_allowance(
+            &ctx.accounts.treasury,
+            &ctx.accounts.policy,
+            ctx.accounts.token_vault.balance,
+            current_time,
+        )?
+            .min(ctx.accounts.recipient.remaining_allowance(&ctx.accounts.treasury, current_time));
+        let affordable = allowance / schedule_amount;
+        due_periods.min(affordable).max(1)
+    };
+    let skipped_periods = if ctx.accounts.treasury.skip_missed_payouts { due_periods - 1 } else { 0 };
+    
     // Check if token vault has enough funds
-    let amount = ctx.accounts.payout_schedule.amount;
+    let amount = schedule_amount.checked_mul(paid_periods).unwrap();
     if ctx.accounts.token_vault.balance < amount {
         return Err(crate::error::ErrorCode::InsufficientFunds.into());
     }
     
-    // Do spending limit checks first (before taking mutable references)
+    // Normal payouts keep the vault's reserve floor
+    crate::utils::validate_token_reserve(&ctx.accounts.token_vault, amount, false)?;
+    
+    // Check the payout against the spending limits and the outflow policy
+    let outflow = Outflow {
+        amount,
+        destinations: std::iter::once(ctx.accounts.destination.key())
+            .chain(ctx.remaining_accounts.iter().skip(2).step_by(3).map(|account| account.key()))
+            .collect(),
+        balance: ctx.accounts.token_vault.balance,
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Pay each split's share to its recipient's payout token account
+    let (split_amounts, recipient_amount) = ctx.accounts.payout_schedule.split_amount(amount);
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, recipient_amount, current_time)?;
+    if ctx.remaining_accounts.len() != split_amounts.len() * 3 {
+        return Err(crate::error::ErrorCode::InvalidPayoutSplit.into());
+    }
+    
+    let token_mint_key = ctx.accounts.token_mint.key();
+    for ((split, share), accounts) in ctx.accounts.payout_schedule.splits.iter()
+        .zip(split_amounts)
+        .zip(ctx.remaining_accounts.chunks(3))
     {
-        let treasury = &ctx.accounts.treasury;
-        let mut daily_total = treasury.daily_total;
-        let mut weekly_total = treasury.weekly_total;
-        let mut monthly_total = treasury.monthly_total;
-        
-        // Reset totals if time periods have passed
-        if current_time - treasury.last_day_reset >= 86400 {
-            daily_total = 0;
-        }
+        let mut split_recipient = crate::utils::load_split_recipient(&ctx.accounts.treasury, split, &accounts[0], current_time)?;
+        split_recipient.validate_receivable(&ctx.accounts.treasury, share, current_time)?;
         
-        if current_time - treasury.last_week_reset >= 604800 {
-            weekly_total = 0;
+        let (payout_destination_key, _) = Pubkey::find_program_address(
+            &[b"payout_destination", split_recipient.key().as_ref(), token_mint_key.as_ref()],
+            &crate::ID,
+        );
+        if accounts[1].key() != payout_destination_key {
+            return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
         }
-        
-        if current_time - treasury.last_month_reset >= 2592000 {
-            monthly_total = 0;
-        }
-        
-        // Check limits
-        if daily_total.checked_add(amount).unwrap() > treasury.daily_limit {
-            return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
+        let expected_destination = crate::utils::expected_payout_token_account(&split_recipient, &accounts[1], &token_mint_key)?;
+        if accounts[2].key() != expected_destination {
+            return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
         }
         
-        if weekly_total.checked_add(amount).unwrap() > treasury.weekly_limit {
-            return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
-        }
+        ctx.accounts.cpi_csl_spl_token_transfer_to(&accounts[2], share)?;
         
-        if monthly_total.checked_add(amount).unwrap() > treasury.monthly_limit {
-            return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
-        }
+        split_recipient.record_payout(share, current_time);
+        split_recipient.exit(&crate::ID)?;
     }
     
     // Transfer tokens (this needs immutable borrow of ctx.accounts)
-    ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
+    ctx.accounts.cpi_csl_spl_token_transfer(recipient_amount)?;
     
     // Now update all state (mutable borrows)
     {
@@ -209,48 +317,32 @@ pub fn handler(
         token_vault.balance = token_vault.balance.checked_sub(amount).unwrap();
     }
     
-    {
-        let treasury = &mut ctx.accounts.treasury;
-        
-        // Reset and update time periods
-        if current_time - treasury.last_day_reset >= 86400 {
-            treasury.daily_total = 0;
-            treasury.last_day_reset = current_time;
-        }
-        
-        if current_time - treasury.last_week_reset >= 604800 {
-            treasury.weekly_total = 0;
-            treasury.last_week_reset = current_time;
-        }
-        
-        if current_time - treasury.last_month_reset >= 2592000 {
-            treasury.monthly_total = 0;
-            treasury.last_month_reset = current_time;
-        }
-        
-        // Update totals
-        treasury.daily_total = treasury.daily_total.checked_add(amount).unwrap();
-        treasury.weekly_total = treasury.weekly_total.checked_add(amount).unwrap();
-        treasury.monthly_total = treasury.monthly_total.checked_add(amount).unwrap();
-    }
-    
     {
         let recipient = &mut ctx.accounts.recipient;
-        recipient.total_received = recipient.total_received.checked_add(amount).unwrap();
-        recipient.last_payout_time = current_time;
+        recipient.record_payout(recipient_amount, current_time);
     }
     
     {
         let payout_schedule = &mut ctx.accounts.payout_schedule;
-        payout_schedule.executions = payout_schedule.executions.checked_add(1).unwrap();
+        payout_schedule.executions = payout_schedule.executions.checked_add(paid_periods + skipped_periods).unwrap();
         payout_schedule.last_execution_time = current_time;
         
-        // If max executions reached, mark inactive
-        if payout_schedule.max_executions > 0 && 
-           payout_schedule.executions >= payout_schedule.max_executions {
-            payout_schedule.is_active = false;
+        // Complete the schedule once max_executions or the end time is reached
+        payout_schedule.status = ScheduleStatus::Active;
+        if payout_schedule.is_finished() {
+            payout_schedule.status = ScheduleStatus::Completed;
+            
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.open_schedules = treasury.open_schedules.saturating_sub(1);
+            
+            let recipient = &mut ctx.accounts.recipient;
+            recipient.active_schedules = recipient.active_schedules.saturating_sub(1);
         }
     }
     
+    // Tip the keeper for executing
+    let fee_payer = ctx.accounts.fee_payer.to_account_info();
+    crate::utils::pay_keeper_tip(&mut ctx.accounts.treasury, &fee_payer, current_time)?;
+    
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,81 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    bounty_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct ExpireBounty<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bounty",
+            treasury.key().as_ref(),
+            bounty_id.to_le_bytes().as_ref(),
+        ],
+        bump = bounty.bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_vault.token_mint.as_ref(),
+        ],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Option<Account<'info, TokenVault>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[writable]` token_vault: [TokenVault] (optional) The vault of the reward's mint, required for token bounties
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ExpireBounty>,
+) -> Result<()> {
+    // Anyone may expire a bounty past its deadline, since it only frees the reserved reward
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.bounty.validate_expirable(current_time)?;
+    
+    let reward = ctx.accounts.bounty.reward;
+    match ctx.accounts.bounty.token_mint {
+        None => {
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.total_balance = treasury.total_balance.checked_add(reward).unwrap();
+        },
+        Some(mint) => {
+            let token_vault = ctx.accounts.token_vault.as_mut()
+                .filter(|token_vault| token_vault.token_mint == mint)
+                .ok_or(crate::error::ErrorCode::InvalidTokenVault)?;
+            token_vault.balance = token_vault.balance.checked_add(reward).unwrap();
+        },
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_bounties = treasury.open_bounties.saturating_sub(1);
+    
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.status = BountyStatus::Expired;
+    
+    msg!("Bounty {} expired with {} claims; reward of {} released", bounty.bounty_id, bounty.claim_count, reward);
+    
+    Ok(())
+}
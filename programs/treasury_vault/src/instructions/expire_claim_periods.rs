@@ -0,0 +1,94 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    recipient_address: Pubkey,
+    schedule_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct ExpireClaimPeriods<'info> {
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_address.as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"schedule",
+            treasury.key().as_ref(),
+            recipient.key().as_ref(),
+            schedule_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub payout_schedule: Account<'info, PayoutSchedule>,
+}
+
+/// Accounts:
+/// 0. `[signer]` fee_payer: [AccountInfo] Anyone may expire lapsed periods
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] 
+/// 3. `[writable]` payout_schedule: [PayoutSchedule] 
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ExpireClaimPeriods>,
+    _recipient_address: Pubkey,
+    _schedule_id: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Verify payout schedule is neither paused nor finished
+    if !ctx.accounts.payout_schedule.is_payable() {
+        return Err(crate::error::ErrorCode::InvalidSchedule.into());
+    }
+    
+    // Only claim mode schedules have claim windows to lapse
+    if !ctx.accounts.payout_schedule.is_claim_mode() {
+        return Err(crate::error::ErrorCode::PayoutModeMismatch.into());
+    }
+    
+    // Commit the periods left unclaimed past their window, their amounts
+    // staying in the treasury, so a recipient who never claims again does
+    // not keep the schedule open
+    let expired_periods = ctx.accounts.payout_schedule.expired_claim_periods(current_time);
+    if expired_periods == 0 {
+        return Err(crate::error::ErrorCode::PayoutNotDue.into());
+    }
+    
+    let payout_schedule = &mut ctx.accounts.payout_schedule;
+    payout_schedule.executions = payout_schedule.executions.checked_add(expired_periods).unwrap();
+    
+    crate::utils::complete_claim_schedule(
+        &mut ctx.accounts.payout_schedule,
+        &mut ctx.accounts.treasury,
+        &mut ctx.accounts.recipient,
+    );
+    
+    msg!("Expired {} unclaimed periods", expired_periods);
+    
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct FinalizeProposal<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"approval_request",
+            treasury.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = approval_request.bump
+    )]
+    pub approval_request: Account<'info, ApprovalRequest>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            approval_request.key().as_ref(),
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, SpendingProposal>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` approval_request: [ApprovalRequest] 
+/// 2. `[writable]` proposal: [SpendingProposal] 
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for the proposal's approval request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<FinalizeProposal>,
+    _request_id: u64,
+) -> Result<()> {
+    // Anyone may settle a proposal once its voting period is over
+    let current_time = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.status != ProposalStatus::Voting {
+        return Err(crate::error::ErrorCode::VotingClosed.into());
+    }
+    if current_time < proposal.voting_ends_at {
+        return Err(crate::error::ErrorCode::VotingNotEnded.into());
+    }
+    
+    // A passed vote authorizes the request's action for whoever performs it
+    if proposal.has_passed() {
+        proposal.status = ProposalStatus::Passed;
+        ctx.accounts.approval_request.vote_passed = true;
+    } else {
+        proposal.status = ProposalStatus::Defeated;
+    }
+    
+    msg!(
+        "Proposal for request {} {:?} with {} yes and {} no",
+        ctx.accounts.approval_request.request_id,
+        proposal.status,
+        proposal.yes_weight,
+        proposal.no_weight
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    treasury_seed_name: String,
+)]
+pub struct InitializePayoutQueue<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        init,
+        space=1477,
+        payer=authority,
+        seeds = [
+            b"payout_queue",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    pub payout_queue: Account<'info, PendingPayoutQueue>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` payout_queue: [PendingPayoutQueue] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<InitializePayoutQueue>,
+) -> Result<()> {
+    // Verify authority may manage the treasury configuration
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    
+    let payout_queue = &mut ctx.accounts.payout_queue;
+    payout_queue.treasury = ctx.accounts.treasury.key();
+    payout_queue.entries = Vec::new();
+    payout_queue.next_sequence = 0;
+    payout_queue.bump = ctx.bumps.payout_queue;
+    
+    Ok(())
+}
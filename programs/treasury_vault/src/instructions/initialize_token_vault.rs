@@ -13,6 +13,7 @@ use anchor_spl::{
 )]
 pub struct InitializeTokenVault<'info> {
     #[account(
+        mut,
         seeds = [
             b"treasury",
             treasury_seed_name.as_bytes().as_ref(),
@@ -23,7 +24,7 @@ pub struct InitializeTokenVault<'info> {
 
     #[account(
         init,
-        space=113,
+        space=121,
         payer=authority,
         seeds = [
             b"token_vault",
@@ -63,12 +64,22 @@ pub struct InitializeTokenVault<'info> {
     pub token_program: Program<'info, Token>,
 
     pub associated_token_program: Program<'info, AssociatedToken>, // ✅ required name
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
 }
 /// Accounts:
-/// 0. `[]` treasury: [TreasuryConfig] 
+/// 0. `[writable]` treasury: [TreasuryConfig] 
 /// 1. `[writable]` token_vault: [TokenVault] 
 /// 2. `[]` token_mint: [Mint] 
-/// 3. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 3. `[signer]` authority: [AccountInfo] Must hold the manage vaults permission
 /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 /// 5. `[writable, signer]` funding: [AccountInfo] Funding account (must be a system account)
 /// 6. `[writable]` assoc_token_account: [AccountInfo] Associated token account address to be created
@@ -76,6 +87,7 @@ pub struct InitializeTokenVault<'info> {
 /// 8. `[]` mint: [Mint] The token mint for the new associated token account
 /// 9. `[]` token_program: [AccountInfo] SPL Token program
 /// 10. `[]` csl_spl_assoc_token_v0_0_0: [AccountInfo] Auto-generated, CslSplAssocTokenProgram v0.0.0
+/// 11. `[]` authority_member: [Member] (optional) Membership of the authority
 ///
 /// Data:
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
@@ -89,12 +101,19 @@ pub fn handler(
     token_vault.token_account = ctx.accounts.assoc_token_account.key();
     token_vault.balance = 0;
     token_vault.bump = ctx.bumps.token_vault;
+    token_vault.reserve_floor = 0;
+    
+    // Verify authority holds the manage vaults permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_MANAGE_VAULTS,
+    )?;
     
-    // Verify authority is admin or treasurer
-    let treasury = &ctx.accounts.treasury;
-    if ctx.accounts.authority.key() != treasury.admin && ctx.accounts.authority.key() != treasury.treasurer {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
-    }
+    // Vaults must be swept before the treasury can be closed
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.token_vault_count = treasury.token_vault_count.checked_add(1).unwrap();
     
     Ok(())
 }
\ No newline at end of file
@@ -25,7 +25,7 @@ pub struct InitializeTreasury<'info> {
 
     #[account(
         init,
-        space=411,
+        space=574,
         payer=admin,
         seeds = [
             b"treasury",
@@ -85,6 +85,28 @@ pub fn handler(
     treasury.last_week_reset = Clock::get()?.unix_timestamp;
     treasury.last_month_reset = Clock::get()?.unix_timestamp;
     treasury.bump = ctx.bumps.treasury;
+    treasury.status = TreasuryStatus::Active;
+    treasury.successor = None;
+    treasury.open_schedules = 0;
+    treasury.open_streams = 0;
+    treasury.token_vault_count = 0;
+    treasury.deactivation_policy = DeactivationPolicy::PauseOnly;
+    treasury.attester = None;
+    treasury.require_payout_cosign = false;
+    treasury.skip_missed_payouts = false;
+    treasury.cooling_period_seconds = 0;
+    treasury.cooling_payout_cap = 0;
+    treasury.reserve_floor = 0;
+    treasury.keeper_mode = KeeperMode::Allowlisted;
+    treasury.keeper_tip = 0;
+    treasury.keeper_tip_period_seconds = 0;
+    treasury.keeper_tip_period_cap = 0;
+    treasury.keeper_tips_paid = 0;
+    treasury.keeper_tip_period_start = 0;
+    treasury.open_escrows = 0;
+    treasury.open_rounds = 0;
+    treasury.open_bounties = 0;
+    treasury.open_grants = 0;
     
     Ok(())
 }
\ No newline at end of file
@@ -8,6 +8,7 @@ pub mod add_recipient;
 pub mod update_recipient;
 pub mod create_payout_schedule;
 pub mod update_payout_schedule;
+pub mod cancel_payout_schedule;
 pub mod execute_sol_payout;
 pub mod execute_token_payout;
 pub mod emergency_withdraw_sol;
@@ -15,6 +16,65 @@ pub mod emergency_withdraw_token;
 pub mod create_streaming_schedule;
 pub mod withdraw_from_stream; 
 pub mod cancel_stream;
+pub mod begin_decommission;
+pub mod sweep_token_vault;
+pub mod close_treasury;
+pub mod deactivate_recipient;
+pub mod reactivate_recipient;
+pub mod renew_recipient;
+pub mod add_member;
+pub mod update_member;
+pub mod remove_member;
+pub mod set_payout_address;
+pub mod set_payout_token_account;
+pub mod set_approval_policy;
+pub mod create_approval_request;
+pub mod approve_request;
+pub mod set_policy;
+pub mod set_reserve_floor;
+pub mod initialize_payout_queue;
+pub mod drain_queue;
+pub mod set_keeper_config;
+pub mod check_payout_due;
+pub mod claim_payout;
+pub mod claim_token_payout;
+pub mod expire_claim_periods;
+pub mod submit_payment_request;
+pub mod approve_sol_payment_request;
+pub mod approve_token_payment_request;
+pub mod reject_payment_request;
+pub mod create_milestone_grant;
+pub mod submit_milestone_evidence;
+pub mod approve_milestone;
+pub mod release_sol_milestone;
+pub mod release_token_milestone;
+pub mod reclaim_milestone;
+pub mod create_escrow;
+pub mod dispute_escrow;
+pub mod release_sol_escrow;
+pub mod release_token_escrow;
+pub mod resolve_sol_escrow;
+pub mod resolve_token_escrow;
+pub mod create_grants_round;
+pub mod register_round_project;
+pub mod contribute_to_round;
+pub mod tally_round;
+pub mod distribute_round;
+pub mod post_bounty;
+pub mod submit_bounty_claim;
+pub mod accept_sol_bounty_claim;
+pub mod accept_token_bounty_claim;
+pub mod expire_bounty;
+pub mod set_governance;
+pub mod deposit_governance_tokens;
+pub mod withdraw_governance_tokens;
+pub mod create_spending_proposal;
+pub mod cast_vote;
+pub mod finalize_proposal;
+pub mod delegate_votes;
+pub mod revoke_delegation;
+pub mod cosign_request;
+pub mod prune_queued_payout;
 
 pub use initialize_treasury::*;
 pub use update_treasury_config::*;
@@ -25,6 +85,7 @@ pub use add_recipient::*;
 pub use update_recipient::*;
 pub use create_payout_schedule::*;
 pub use update_payout_schedule::*;
+pub use cancel_payout_schedule::*;
 pub use execute_sol_payout::*;
 pub use execute_token_payout::*;
 pub use emergency_withdraw_sol::*;
@@ -32,3 +93,63 @@ pub use emergency_withdraw_token::*;
 pub use create_streaming_schedule::*;
 pub use withdraw_from_stream::*;
 pub use cancel_stream::*;
+pub use begin_decommission::*;
+pub use sweep_token_vault::*;
+pub use close_treasury::*;
+pub use deactivate_recipient::*;
+pub use reactivate_recipient::*;
+pub use renew_recipient::*;
+pub use add_member::*;
+pub use update_member::*;
+pub use remove_member::*;
+pub use set_payout_address::*;
+pub use set_payout_token_account::*;
+pub use set_approval_policy::*;
+pub use create_approval_request::*;
+pub use approve_request::*;
+pub use set_policy::*;
+pub use set_reserve_floor::*;
+pub use initialize_payout_queue::*;
+pub use drain_queue::*;
+pub use set_keeper_config::*;
+pub use check_payout_due::*;
+pub use claim_payout::*;
+pub use claim_token_payout::*;
+pub use expire_claim_periods::*;
+pub use submit_payment_request::*;
+pub use approve_sol_payment_request::*;
+pub use approve_token_payment_request::*;
+pub use reject_payment_request::*;
+pub use create_milestone_grant::*;
+pub use submit_milestone_evidence::*;
+pub use approve_milestone::*;
+pub use release_sol_milestone::*;
+pub use release_token_milestone::*;
+pub use reclaim_milestone::*;
+pub use create_escrow::*;
+pub use dispute_escrow::*;
+pub use release_sol_escrow::*;
+pub use release_token_escrow::*;
+pub use resolve_sol_escrow::*;
+pub use resolve_token_escrow::*;
+pub use create_grants_round::*;
+pub use register_round_project::*;
+pub use contribute_to_round::*;
+pub use tally_round::*;
+pub use distribute_round::*;
+pub use post_bounty::*;
+pub use submit_bounty_claim::*;
+pub use accept_sol_bounty_claim::*;
+pub use accept_token_bounty_claim::*;
+pub use expire_bounty::*;
+pub use set_governance::*;
+pub use deposit_governance_tokens::*;
+pub use withdraw_governance_tokens::*;
+pub use create_spending_proposal::*;
+pub use cast_vote::*;
+pub use finalize_proposal::*;
+pub use delegate_votes::*;
+pub use revoke_delegation::*;
+pub use cosign_request::*;
+pub use prune_queued_payout::*;
+
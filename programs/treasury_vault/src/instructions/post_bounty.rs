@@ -0,0 +1,180 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    bounty_id: u64,
+    token_mint: Option<Pubkey>,
+    reward: u64,
+    deadline: i64,
+    description_hash: [u8; 32],
+    treasury_seed_name: String,
+)]
+pub struct PostBounty<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        init,
+        space=168,
+        payer=authority,
+        seeds = [
+            b"bounty",
+            treasury.key().as_ref(),
+            bounty_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_vault.token_mint.as_ref(),
+        ],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Option<Account<'info, TokenVault>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(token_mint).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[writable]` token_vault: [TokenVault] (optional) The vault of the reward's mint, required for token bounties
+/// 3. `[writable, signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 5. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for the reward's asset, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the reward's tier needs approvals or a delay, or passed by token-holder vote
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - token_mint: [Option<Pubkey>] Mint the reward is paid in (null for SOL)
+/// - reward: [u64] Amount paid for the accepted claim
+/// - deadline: [i64] Last time claims are taken
+/// - description_hash: [[u8; 32]] Hash of the bounty's description
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<PostBounty>,
+    bounty_id: u64,
+    token_mint: Option<Pubkey>,
+    reward: u64,
+    deadline: i64,
+    description_hash: [u8; 32],
+) -> Result<()> {
+    // The call's full arguments, which a passed vote must match exactly
+    let terms_hash = crate::utils::args_hash("post_bounty", &(bounty_id, token_mint, reward, deadline, description_hash))?;
+    
+    // Verify authority holds the create schedule permission, unless token holders voted this exact call through
+    crate::utils::authorize_action(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.approval_request.as_deref(),
+        ApprovalAction::PostBounty { bounty_id },
+        terms_hash,
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Bounties are new obligations, like schedules and streams
+    if ctx.accounts.treasury.is_winding_down() {
+        return Err(crate::error::ErrorCode::TreasuryWindingDown.into());
+    }
+    
+    if reward == 0 || deadline <= current_time {
+        return Err(crate::error::ErrorCode::InvalidBounty.into());
+    }
+    
+    // Amounts above the lowest approval tier need an approved request
+    crate::utils::enforce_approval_policy(
+        &ctx.accounts.treasury,
+        &ctx.accounts.approval_policy,
+        ctx.accounts.approval_request.as_mut(),
+        ApprovalAction::PostBounty { bounty_id },
+        terms_hash,
+        reward,
+        token_mint,
+        current_time,
+    )?;
+    
+    // Reserve the reward: it stays in the treasury's custody but leaves the available balance
+    match token_mint {
+        None => {
+            if ctx.accounts.treasury.total_balance < reward {
+                return Err(crate::error::ErrorCode::InsufficientFunds.into());
+            }
+            crate::utils::validate_sol_reserve(&ctx.accounts.treasury, reward, false)?;
+            
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.total_balance -= reward;
+        },
+        Some(mint) => {
+            let token_vault = ctx.accounts.token_vault.as_mut()
+                .filter(|token_vault| token_vault.token_mint == mint)
+                .ok_or(crate::error::ErrorCode::InvalidTokenVault)?;
+            if token_vault.balance < reward {
+                return Err(crate::error::ErrorCode::InsufficientFunds.into());
+            }
+            crate::utils::validate_token_reserve(token_vault, reward, false)?;
+            
+            token_vault.balance -= reward;
+        },
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_bounties = treasury.open_bounties.checked_add(1).unwrap();
+    
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.treasury = treasury.key();
+    bounty.bounty_id = bounty_id;
+    bounty.token_mint = token_mint;
+    bounty.reward = reward;
+    bounty.deadline = deadline;
+    bounty.description_hash = description_hash;
+    bounty.status = BountyStatus::Open;
+    bounty.claim_count = 0;
+    bounty.winner = None;
+    bounty.bump = ctx.bumps.bounty;
+    
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    sequence: u64,
+    treasury_seed_name: String,
+)]
+pub struct PruneQueuedPayout<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"payout_queue",
+            treasury.key().as_ref(),
+        ],
+        bump = payout_queue.bump
+    )]
+    pub payout_queue: Account<'info, PendingPayoutQueue>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` payout_queue: [PendingPayoutQueue] 
+/// 2. `[signer]` authority: [AccountInfo] Must hold the config permission, as needed to set up the queue
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+///
+/// Data:
+/// - sequence: [u64] Sequence number of the queued payout to drop
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<PruneQueuedPayout>,
+    sequence: u64,
+) -> Result<()> {
+    // Verify authority may manage the treasury configuration
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    
+    // The payout was never paid, so its amount returns to the booked balance
+    let entry = ctx.accounts.payout_queue.remove(sequence)?;
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_balance = treasury.total_balance.checked_add(entry.amount).unwrap();
+    
+    msg!(
+        "Dropped queued payout {} of {} lamports to {}",
+        entry.sequence,
+        entry.amount,
+        entry.recipient
+    );
+    
+    Ok(())
+}
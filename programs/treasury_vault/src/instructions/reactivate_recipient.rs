@@ -0,0 +1,128 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+#[instruction(
+    recipient_address: Pubkey,
+    treasury_seed_name: String,
+)]
+pub struct ReactivateRecipient<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_address.as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[writable]` remaining_accounts: every paused [PayoutSchedule] and [StreamingSchedule] of the recipient
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReactivateRecipient<'info>>,
+    _recipient_address: Pubkey,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Verify authority holds the manage recipients permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_MANAGE_RECIPIENTS,
+    )?;
+    let treasury = &ctx.accounts.treasury;
+
+    if ctx.accounts.recipient.is_active {
+        return Err(crate::error::ErrorCode::InvalidRecipient.into());
+    }
+
+    let treasury_key = treasury.key();
+    let recipient_key = ctx.accounts.recipient.key();
+
+    let mut schedules_resumed: u32 = 0;
+    let mut streams_resumed: u32 = 0;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let is_schedule = account_info.try_borrow_data()?.starts_with(PayoutSchedule::DISCRIMINATOR);
+
+        if is_schedule {
+            let mut payout_schedule = Account::<PayoutSchedule>::try_from(account_info)?;
+            if payout_schedule.treasury != treasury_key || payout_schedule.recipient != recipient_key {
+                return Err(crate::error::ErrorCode::InvalidSchedule.into());
+            }
+
+            if !payout_schedule.status.is_open() || !payout_schedule.is_paused {
+                continue;
+            }
+
+            payout_schedule.is_paused = false;
+            payout_schedule.exit(&crate::ID)?;
+            schedules_resumed += 1;
+        } else {
+            let mut streaming_schedule = Account::<StreamingSchedule>::try_from(account_info)?;
+            if streaming_schedule.treasury != treasury_key || streaming_schedule.recipient != recipient_key {
+                return Err(crate::error::ErrorCode::InvalidStreamingSchedule.into());
+            }
+
+            if !streaming_schedule.is_active || !streaming_schedule.is_paused {
+                continue;
+            }
+
+            streaming_schedule.resume(current_time);
+            streaming_schedule.exit(&crate::ID)?;
+            streams_resumed += 1;
+        }
+    }
+
+    // Nothing may stay paused behind an active recipient
+    let recipient = &mut ctx.accounts.recipient;
+    if schedules_resumed != recipient.active_schedules || streams_resumed != recipient.active_streams {
+        return Err(crate::error::ErrorCode::IncompleteRecipientSchedules.into());
+    }
+
+    recipient.is_active = true;
+
+    emit!(RecipientReactivated {
+        treasury: treasury_key,
+        recipient: recipient_key,
+        schedules_resumed,
+        streams_resumed,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    grant_id: u64,
+    milestone_index: u8,
+    treasury_seed_name: String,
+)]
+pub struct ReclaimMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"milestone_grant",
+            treasury.key().as_ref(),
+            grant_id.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_grant.bump
+    )]
+    pub milestone_grant: Account<'info, MilestoneGrant>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_vault.token_mint.as_ref(),
+        ],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Option<Account<'info, TokenVault>>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 2. `[writable]` token_vault: [TokenVault] (optional) The vault of the grant's mint, required for token grants
+/// 3. `[signer]` authority: [AccountInfo] Must hold the create schedule permission
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ReclaimMilestone>,
+    milestone_index: u8,
+) -> Result<()> {
+    // Verify authority holds the create schedule permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let token_mint = ctx.accounts.milestone_grant.token_mint;
+    
+    // Only milestones whose deadline passed without sign-off can be reclaimed
+    let milestone = ctx.accounts.milestone_grant.reclaimable_milestone(milestone_index, current_time)?;
+    milestone.status = MilestoneStatus::Reclaimed;
+    let amount = milestone.amount;
+    
+    // Return the escrowed funds to the treasury's booked balance
+    match token_mint {
+        None => {
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.total_balance = treasury.total_balance.checked_add(amount).unwrap();
+        },
+        Some(mint) => {
+            let token_vault = ctx.accounts.token_vault.as_mut()
+                .filter(|token_vault| token_vault.token_mint == mint)
+                .ok_or(crate::error::ErrorCode::InvalidTokenVault)?;
+            token_vault.balance = token_vault.balance.checked_add(amount).unwrap();
+        },
+    }
+    
+    // The grant stays open until its last milestone is released or reclaimed
+    if ctx.accounts.milestone_grant.is_settled() {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.open_grants = treasury.open_grants.saturating_sub(1);
+    }
+    
+    msg!("Reclaimed {} from milestone {} of grant {}", amount, milestone_index, ctx.accounts.milestone_grant.grant_id);
+    
+    Ok(())
+}
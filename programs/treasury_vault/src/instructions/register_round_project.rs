@@ -0,0 +1,115 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    round_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct RegisterRoundProject<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"grants_round",
+            treasury.key().as_ref(),
+            round_id.to_le_bytes().as_ref(),
+        ],
+        bump = grants_round.bump
+    )]
+    pub grants_round: Account<'info, GrantsRound>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        init,
+        space=106,
+        payer=authority,
+        seeds = [
+            b"round_project",
+            grants_round.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump
+    )]
+    pub round_project: Account<'info, RoundProject>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` grants_round: [GrantsRound] 
+/// 2. `[]` recipient: [Recipient] The project's recipient entry
+/// 3. `[writable]` round_project: [RoundProject] 
+/// 4. `[writable, signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 5. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<RegisterRoundProject>,
+) -> Result<()> {
+    // Verify authority holds the manage recipients permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_MANAGE_RECIPIENTS,
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Projects join until contributions close
+    if ctx.accounts.grants_round.status != RoundStatus::Open || current_time >= ctx.accounts.grants_round.end_time {
+        return Err(crate::error::ErrorCode::RoundNotOpen.into());
+    }
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    
+    let grants_round = &mut ctx.accounts.grants_round;
+    let round_project = &mut ctx.accounts.round_project;
+    round_project.grants_round = grants_round.key();
+    round_project.recipient = ctx.accounts.recipient.key();
+    round_project.index = grants_round.project_count;
+    round_project.contributed = 0;
+    round_project.sqrt_sum = 0;
+    round_project.contributor_count = 0;
+    round_project.matched_amount = 0;
+    round_project.match_settled = false;
+    round_project.bump = ctx.bumps.round_project;
+    
+    grants_round.project_count = grants_round.project_count.checked_add(1).unwrap();
+    
+    Ok(())
+}
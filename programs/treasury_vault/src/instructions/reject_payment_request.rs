@@ -0,0 +1,87 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct RejectPaymentRequest<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"payment_request",
+            recipient.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = payment_request.bump
+    )]
+    pub payment_request: Account<'info, PaymentRequest>,
+
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            reviewer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The recipient that submitted the request
+/// 2. `[writable]` payment_request: [PaymentRequest] 
+/// 3. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission
+/// 4. `[]` authority_member: [Member] (optional) Membership of the reviewer
+///
+/// Data:
+/// - request_id: [u64] Identifier of the request, unique for the recipient
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<RejectPaymentRequest>,
+) -> Result<()> {
+    // Verify reviewer holds the execute payout permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.reviewer.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_EXECUTE_PAYOUT,
+    )?;
+    
+    let payment_request = &mut ctx.accounts.payment_request;
+    payment_request.validate_reviewable()?;
+    
+    // Whatever has not been paid yet is rejected
+    let rejected = payment_request.remaining();
+    payment_request.record(
+        PaymentRequestStatus::Rejected,
+        ctx.accounts.reviewer.key(),
+        rejected,
+        Clock::get()?.unix_timestamp,
+    );
+    
+    Ok(())
+}
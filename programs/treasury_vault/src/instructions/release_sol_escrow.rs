@@ -0,0 +1,151 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+#[derive(Accounts)]
+#[instruction(
+    escrow_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct ReleaseSolEscrow<'info> {
+    #[account(
+        mut,
+        owner=Pubkey::from_str("11111111111111111111111111111111").unwrap(),
+    )]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            treasury.key().as_ref(),
+            escrow_id.to_le_bytes().as_ref(),
+        ],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            fee_payer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission, unless the release time has passed and it is the vendor or the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] The vendor
+/// 3. `[writable]` escrow: [Escrow] 
+/// 4. `[writable]` payout_address: [AccountInfo] The vendor's bound payout address
+/// 5. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 6. `[]` authority_member: [Member] (optional) Membership of the fee payer
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ReleaseSolEscrow>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Before the release time only the treasury's side can release early;
+    // afterwards an undisputed escrow releases like any due payout, and the
+    // vendor can always release it to themselves
+    if current_time < ctx.accounts.escrow.release_time {
+        crate::utils::authorize(
+            &ctx.accounts.treasury,
+            &ctx.accounts.fee_payer.key(),
+            ctx.accounts.authority_member.as_ref(),
+            PERMISSION_EXECUTE_PAYOUT,
+        )?;
+    } else if ctx.accounts.fee_payer.key() != ctx.accounts.recipient.recipient_address {
+        crate::utils::authorize_keeper(
+            &ctx.accounts.treasury,
+            &ctx.accounts.fee_payer.key(),
+            ctx.accounts.authority_member.as_ref(),
+        )?;
+    }
+    
+    // Verify the escrow is a held SOL escrow of this recipient
+    let escrow = &ctx.accounts.escrow;
+    if escrow.recipient != ctx.accounts.recipient.key() || escrow.token_mint.is_some() {
+        return Err(crate::error::ErrorCode::InvalidEscrow.into());
+    }
+    escrow.validate_releasable()?;
+    let amount = escrow.amount;
+    
+    // The release goes through the same checks as any other payout
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, amount, current_time)?;
+    
+    // Escrowed funds left the booked balance when the escrow was created,
+    // so they count toward the balance the release leaves
+    let outflow = Outflow {
+        amount,
+        destinations: vec![ctx.accounts.payout_address.key()],
+        balance: ctx.accounts.treasury.total_balance.saturating_add(amount),
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Transfer SOL
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.payout_address.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_escrows = treasury.open_escrows.saturating_sub(1);
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(amount, current_time);
+    
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.status = EscrowStatus::Released;
+    escrow.vendor_amount = amount;
+    
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+#[derive(Accounts)]
+#[instruction(
+    grant_id: u64,
+    milestone_index: u8,
+    treasury_seed_name: String,
+)]
+pub struct ReleaseSolMilestone<'info> {
+    #[account(
+        mut,
+        owner=Pubkey::from_str("11111111111111111111111111111111").unwrap(),
+    )]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"milestone_grant",
+            treasury.key().as_ref(),
+            grant_id.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_grant.bump
+    )]
+    pub milestone_grant: Account<'info, MilestoneGrant>,
+
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(None).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            fee_payer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] The grantee
+/// 3. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 4. `[writable]` payout_address: [AccountInfo] The grantee's bound payout address
+/// 5. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 6. `[]` authority_member: [Member] (optional) Membership of the fee payer
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ReleaseSolMilestone>,
+    milestone_index: u8,
+) -> Result<()> {
+    // Verify fee payer may execute payouts under the treasury's keeper mode
+    crate::utils::authorize_keeper(
+        &ctx.accounts.treasury,
+        &ctx.accounts.fee_payer.key(),
+        ctx.accounts.authority_member.as_ref(),
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Verify the grant is a SOL grant of this recipient
+    let milestone_grant = &ctx.accounts.milestone_grant;
+    if milestone_grant.recipient != ctx.accounts.recipient.key() || milestone_grant.token_mint.is_some() {
+        return Err(crate::error::ErrorCode::InvalidMilestones.into());
+    }
+    
+    let milestone = ctx.accounts.milestone_grant.current_milestone(milestone_index)?;
+    if milestone.status != MilestoneStatus::Approved {
+        return Err(crate::error::ErrorCode::InvalidMilestoneStatus.into());
+    }
+    let amount = milestone.amount;
+    
+    // The release goes through the same checks as any other payout
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, amount, current_time)?;
+    
+    // Escrowed funds left the booked balance when the grant was created,
+    // so they count toward the balance the release leaves
+    let outflow = Outflow {
+        amount,
+        destinations: vec![ctx.accounts.payout_address.key()],
+        balance: ctx.accounts.treasury.total_balance.saturating_add(amount),
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    crate::utils::validate_reserved_sol_outflow(&ctx.accounts.treasury, amount)?;
+    
+    // Transfer SOL
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.payout_address.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(amount, current_time);
+    
+    let milestone = ctx.accounts.milestone_grant.current_milestone(milestone_index)?;
+    milestone.status = MilestoneStatus::Released;
+    
+    // The grant stays open until its last milestone is released or reclaimed
+    if ctx.accounts.milestone_grant.is_settled() {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.open_grants = treasury.open_grants.saturating_sub(1);
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(
+    grant_id: u64,
+    milestone_index: u8,
+    treasury_seed_name: String,
+)]
+pub struct ReleaseTokenMilestone<'info> {
+    #[account(
+        mut,
+        owner=Pubkey::from_str("11111111111111111111111111111111").unwrap(),
+    )]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"milestone_grant",
+            treasury.key().as_ref(),
+            grant_id.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_grant.bump
+    )]
+    pub milestone_grant: Account<'info, MilestoneGrant>,
+
+    #[account(
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+    )]
+    pub authority: Signer<'info>,
+
+    pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            fee_payer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"payout_destination",
+            recipient.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `expected_payout_token_account`
+    pub payout_destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(Some(token_mint.key())).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
+}
+
+impl<'info> ReleaseTokenMilestone<'info> {
+    pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
+}
+
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] The grantee
+/// 3. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 4. `[]` token_vault: [TokenVault] 
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` source: [AccountInfo] The source account.
+/// 7. `[writable]` destination: [AccountInfo] The grantee's bound payout token account.
+/// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 11. `[]` payout_destination: [PayoutDestination] The grantee's token account override for this mint, if registered
+/// 12. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ReleaseTokenMilestone>,
+    milestone_index: u8,
+) -> Result<()> {
+    // Verify fee payer may execute payouts under the treasury's keeper mode
+    crate::utils::authorize_keeper(
+        &ctx.accounts.treasury,
+        &ctx.accounts.fee_payer.key(),
+        ctx.accounts.authority_member.as_ref(),
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let token_mint = ctx.accounts.token_mint.key();
+    
+    // Verify the grant is paid in this mint to this recipient
+    let milestone_grant = &ctx.accounts.milestone_grant;
+    if milestone_grant.recipient != ctx.accounts.recipient.key() || milestone_grant.token_mint != Some(token_mint) {
+        return Err(crate::error::ErrorCode::InvalidMilestones.into());
+    }
+    
+    let milestone = ctx.accounts.milestone_grant.current_milestone(milestone_index)?;
+    if milestone.status != MilestoneStatus::Approved {
+        return Err(crate::error::ErrorCode::InvalidMilestoneStatus.into());
+    }
+    let amount = milestone.amount;
+    
+    // Verify tokens go only to the recipient's bound payout token account
+    let expected_destination = crate::utils::expected_payout_token_account(
+        &ctx.accounts.recipient,
+        &ctx.accounts.payout_destination,
+        &token_mint,
+    )?;
+    if ctx.accounts.destination.key() != expected_destination {
+        return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+    }
+    
+    // The release goes through the same checks as any other payout
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, amount, current_time)?;
+    
+    // Escrowed tokens left the vault's booked balance when the grant was
+    // created, so they count toward the balance the release leaves
+    let outflow = Outflow {
+        amount,
+        destinations: vec![ctx.accounts.destination.key()],
+        balance: ctx.accounts.token_vault.balance.saturating_add(amount),
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
+    
+    // Transfer tokens
+    ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.record_payout(amount, current_time);
+    
+    let milestone = ctx.accounts.milestone_grant.current_milestone(milestone_index)?;
+    milestone.status = MilestoneStatus::Released;
+    
+    // The grant stays open until its last milestone is released or reclaimed
+    if ctx.accounts.milestone_grant.is_settled() {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.open_grants = treasury.open_grants.saturating_sub(1);
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    member_address: Pubkey,
+    treasury_seed_name: String,
+)]
+pub struct RemoveMember<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            member_address.as_ref(),
+        ],
+        bump = member.bump,
+        close = authority // Close account and return rent to authority
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `load_governance`
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` member: [Member] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission, plus any privileged permission it changes unless it is the admin
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 5. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - member_address: [Pubkey] The member's wallet address
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<RemoveMember>,
+    member_address: Pubkey,
+) -> Result<()> {
+    // Verify authority may manage the treasury configuration
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    
+    // While governance requires it, the council co-signs this exact change
+    let config_hash = crate::utils::args_hash("remove_member", &member_address)?;
+    crate::utils::enforce_council_cosign(
+        &ctx.accounts.treasury.key(),
+        crate::utils::load_governance(&ctx.accounts.governance)?.as_ref(),
+        ctx.accounts.approval_request.as_mut(),
+        config_hash,
+    )?;
+    
+    // Removing a member revokes its privileged permissions too
+    crate::utils::authorize_grant(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.member.permissions,
+    )?;
+    
+    msg!("Member removed: {}", member_address);
+    
+    Ok(())
+}
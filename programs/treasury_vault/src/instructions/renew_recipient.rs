@@ -0,0 +1,102 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    recipient_address: Pubkey,
+    valid_until: i64,
+    attestation_ref: [u8; 32],
+    treasury_seed_name: String,
+)]
+pub struct RenewRecipient<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_address.as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Only required if treasury.attester is set; address checked against the instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 3. `[]` instructions_sysvar: [AccountInfo] (optional) Required when the treasury has an attester
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - valid_until: [i64] New whitelist expiry
+/// - attestation_ref: [[u8; 32]] Reference to the new off-chain attestation
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<RenewRecipient>,
+    recipient_address: Pubkey,
+    valid_until: i64,
+    attestation_ref: [u8; 32],
+) -> Result<()> {
+    // Verify authority holds the manage recipients permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_MANAGE_RECIPIENTS,
+    )?;
+    let treasury = &ctx.accounts.treasury;
+    
+    // A renewal must actually extend the whitelist entry into the future
+    let current_time = Clock::get()?.unix_timestamp;
+    if valid_until <= current_time {
+        return Err(crate::error::ErrorCode::InvalidExpiry.into());
+    }
+    
+    // Verify the attester approved the new expiry
+    crate::utils::validate_attestation(
+        treasury,
+        ctx.accounts.instructions_sysvar.as_ref().map(|a| a.as_ref()),
+        &recipient_address,
+        valid_until,
+    )?;
+    
+    let recipient = &mut ctx.accounts.recipient;
+    recipient.valid_until = valid_until;
+    recipient.attestation_ref = attestation_ref;
+    recipient.attested_at = current_time;
+    
+    msg!(
+        "Recipient renewed: {} valid until {}",
+        recipient_address,
+        valid_until
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    escrow_id: u64,
+    vendor_amount: u64,
+    treasury_seed_name: String,
+)]
+pub struct ResolveSolEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            treasury.key().as_ref(),
+            escrow_id.to_le_bytes().as_ref(),
+        ],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    pub arbitrator: Signer<'info>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] The vendor
+/// 2. `[writable]` escrow: [Escrow] 
+/// 3. `[writable]` payout_address: [AccountInfo] The vendor's bound payout address
+/// 4. `[signer]` arbitrator: [AccountInfo] The escrow's arbitrator
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - vendor_amount: [u64] Share of the escrowed amount paid to the vendor; the rest returns to the treasury
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ResolveSolEscrow>,
+    vendor_amount: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Verify the escrow is a disputed SOL escrow of this recipient, settled by its arbitrator
+    let escrow = &ctx.accounts.escrow;
+    if escrow.recipient != ctx.accounts.recipient.key() || escrow.token_mint.is_some() {
+        return Err(crate::error::ErrorCode::InvalidEscrow.into());
+    }
+    escrow.validate_resolution(&ctx.accounts.arbitrator.key(), vendor_amount)?;
+    let treasury_amount = escrow.amount - vendor_amount;
+    
+    // The vendor's share was reserved when the escrow was created, so the
+    // arbitrator's ruling is not held to the spending limits, outflow policy
+    // or recipient caps, any of which could leave the dispute unsettled
+    if vendor_amount > 0 {
+        ctx.accounts.recipient.validate_payable(current_time)?;
+        
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? -= vendor_amount;
+        **ctx.accounts.payout_address.to_account_info().try_borrow_mut_lamports()? += vendor_amount;
+        
+        let recipient = &mut ctx.accounts.recipient;
+        recipient.record_payout(vendor_amount, current_time);
+    }
+    
+    // The treasury's share is booked back into its balance
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_balance = treasury.total_balance.checked_add(treasury_amount).unwrap();
+    treasury.open_escrows = treasury.open_escrows.saturating_sub(1);
+    
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.status = EscrowStatus::Resolved;
+    escrow.vendor_amount = vendor_amount;
+    
+    msg!("Escrow {} resolved: {} to the vendor, {} to the treasury", escrow.escrow_id, vendor_amount, treasury_amount);
+    
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(
+    escrow_id: u64,
+    vendor_amount: u64,
+    treasury_seed_name: String,
+)]
+pub struct ResolveTokenEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient.recipient_address.as_ref(),
+        ],
+        bump = recipient.bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            treasury.key().as_ref(),
+            escrow_id.to_le_bytes().as_ref(),
+        ],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+    )]
+    pub authority: Signer<'info>,
+
+    pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    pub arbitrator: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"payout_destination",
+            recipient.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `expected_payout_token_account`
+    pub payout_destination: UncheckedAccount<'info>,
+}
+
+impl<'info> ResolveTokenEscrow<'info> {
+    pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
+}
+
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] The vendor
+/// 2. `[writable]` escrow: [Escrow] 
+/// 3. `[writable]` token_vault: [TokenVault] 
+/// 4. `[]` token_mint: [Mint] 
+/// 5. `[writable]` source: [AccountInfo] The source account.
+/// 6. `[writable]` destination: [AccountInfo] The vendor's bound payout token account.
+/// 7. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[signer]` arbitrator: [AccountInfo] The escrow's arbitrator
+/// 10. `[]` payout_destination: [PayoutDestination] The vendor's token account override for this mint, if registered
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - vendor_amount: [u64] Share of the escrowed amount paid to the vendor; the rest returns to the vault
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<ResolveTokenEscrow>,
+    vendor_amount: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let token_mint = ctx.accounts.token_mint.key();
+    
+    // Verify the escrow is a disputed escrow of this mint for this recipient, settled by its arbitrator
+    let escrow = &ctx.accounts.escrow;
+    if escrow.recipient != ctx.accounts.recipient.key() || escrow.token_mint != Some(token_mint) {
+        return Err(crate::error::ErrorCode::InvalidEscrow.into());
+    }
+    escrow.validate_resolution(&ctx.accounts.arbitrator.key(), vendor_amount)?;
+    let treasury_amount = escrow.amount - vendor_amount;
+    
+    // The vendor's share was reserved when the escrow was created, so the
+    // arbitrator's ruling is not held to the spending limits, outflow policy
+    // or recipient caps, any of which could leave the dispute unsettled
+    if vendor_amount > 0 {
+        let expected_destination = crate::utils::expected_payout_token_account(
+            &ctx.accounts.recipient,
+            &ctx.accounts.payout_destination,
+            &token_mint,
+        )?;
+        if ctx.accounts.destination.key() != expected_destination {
+            return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+        }
+        
+        ctx.accounts.recipient.validate_payable(current_time)?;
+        
+        ctx.accounts.cpi_csl_spl_token_transfer(vendor_amount)?;
+        
+        let recipient = &mut ctx.accounts.recipient;
+        recipient.record_payout(vendor_amount, current_time);
+    }
+    
+    // The treasury's share is booked back into the vault's balance
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.balance = token_vault.balance.checked_add(treasury_amount).unwrap();
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.open_escrows = treasury.open_escrows.saturating_sub(1);
+    
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.status = EscrowStatus::Resolved;
+    escrow.vendor_amount = vendor_amount;
+    
+    msg!("Escrow {} resolved: {} to the vendor, {} to the vault", escrow.escrow_id, vendor_amount, treasury_amount);
+    
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    treasury_seed_name: String,
+)]
+pub struct RevokeDelegation<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"voter",
+            treasury.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump = voter_record.bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [
+            b"delegation",
+            treasury.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump = delegation.bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` voter_record: [VoterRecord] 
+/// 2. `[writable]` delegation: [Delegation] Closed to the voter
+/// 3. `[writable, signer]` voter: [AccountInfo] 
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<RevokeDelegation>,
+) -> Result<()> {
+    // Revoking works at any time, even with governance disabled; co-signs already
+    // given keep the weight they counted
+    let voter_record = &mut ctx.accounts.voter_record;
+    voter_record.delegate = None;
+    
+    msg!("Delegation of {} to {} revoked", ctx.accounts.delegation.weight, ctx.accounts.delegation.delegate);
+    
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    token_mint: Option<Pubkey>,
+    tiers: Vec<ApprovalTier>,
+    treasury_seed_name: String,
+)]
+pub struct SetApprovalPolicy<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        init_if_needed,
+        space=168,
+        payer=authority,
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(token_mint).as_ref(),
+        ],
+        bump
+    )]
+    pub approval_policy: Account<'info, ApprovalPolicy>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `load_governance`
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` approval_policy: [ApprovalPolicy] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - token_mint: [Option<Pubkey>] Asset the tiers apply to (null for SOL)
+/// - tiers: [Vec<ApprovalTier>] Amount tiers in ascending order (empty lifts the policy)
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SetApprovalPolicy>,
+    token_mint: Option<Pubkey>,
+    tiers: Vec<ApprovalTier>,
+) -> Result<()> {
+    // Verify authority may manage the treasury configuration
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    
+    // While governance requires it, the council co-signs this exact change
+    let config_hash = crate::utils::args_hash("set_approval_policy", &(token_mint, &tiers))?;
+    crate::utils::enforce_council_cosign(
+        &ctx.accounts.treasury.key(),
+        crate::utils::load_governance(&ctx.accounts.governance)?.as_ref(),
+        ctx.accounts.approval_request.as_mut(),
+        config_hash,
+    )?;
+    
+    ApprovalPolicy::validate_tiers(&tiers)?;
+    
+    let approval_policy = &mut ctx.accounts.approval_policy;
+    approval_policy.treasury = ctx.accounts.treasury.key();
+    approval_policy.token_mint = token_mint;
+    approval_policy.tiers = tiers;
+    approval_policy.bump = ctx.bumps.approval_policy;
+    
+    Ok(())
+}
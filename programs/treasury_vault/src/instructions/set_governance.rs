@@ -0,0 +1,144 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+#[instruction(
+    governance_mint: Option<Pubkey>,
+    quorum: u64,
+    threshold_bps: u16,
+    voting_period_seconds: u64,
+    is_enabled: bool,
+    delegate_threshold: u64,
+    require_council_config: bool,
+    treasury_seed_name: String,
+)]
+pub struct SetGovernance<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        init_if_needed,
+        space=141,
+        payer=authority,
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` governance: [GovernanceConfig] 
+/// 2. `[]` vault_token_account: [TokenAccount] Token account of the governance mint that holds deposited voting tokens
+/// 3. `[writable, signer]` authority: [AccountInfo] Must hold the config permission
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - governance_mint: [Option<Pubkey>] Mint whose deposited tokens carry voting weight (null for the token gate mint)
+/// - quorum: [u64] Least total weight a proposal's votes must reach
+/// - threshold_bps: [u16] Share of the weight cast that must vote yes, in basis points
+/// - voting_period_seconds: [u64] How long proposals take votes
+/// - is_enabled: [bool] Whether deposits and new proposals are taken
+/// - delegate_threshold: [u64] Least delegated weight a delegate needs to co-sign requests (0 turns co-signing off)
+/// - require_council_config: [bool] Whether config changes need a request co-signed by a delegate
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SetGovernance>,
+    governance_mint: Option<Pubkey>,
+    quorum: u64,
+    threshold_bps: u16,
+    voting_period_seconds: u64,
+    is_enabled: bool,
+    delegate_threshold: u64,
+    require_council_config: bool,
+) -> Result<()> {
+    // Verify authority may manage the treasury configuration
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    
+    GovernanceConfig::validate_parameters(quorum, threshold_bps, voting_period_seconds)?;
+    
+    // Requiring co-signs nobody can give would lock the configuration
+    if require_council_config && delegate_threshold == 0 {
+        return Err(crate::error::ErrorCode::InvalidGovernance.into());
+    }
+    
+    // Voting defaults to the token gate mint, and the vault must hold that mint
+    let governance_mint = governance_mint
+        .or(ctx.accounts.treasury.token_gate_mint)
+        .ok_or(crate::error::ErrorCode::InvalidGovernance)?;
+    let vault_token_account = ctx.accounts.vault_token_account.key();
+    if ctx.accounts.vault_token_account.mint != governance_mint {
+        return Err(crate::error::ErrorCode::InvalidGovernance.into());
+    }
+    
+    // While required, the council co-signs changes to governance itself
+    let config_hash = crate::utils::args_hash(
+        "set_governance",
+        &(governance_mint, vault_token_account, quorum, threshold_bps, voting_period_seconds, is_enabled, delegate_threshold, require_council_config),
+    )?;
+    crate::utils::enforce_council_cosign(
+        &ctx.accounts.treasury.key(),
+        Some(&ctx.accounts.governance),
+        ctx.accounts.approval_request.as_mut(),
+        config_hash,
+    )?;
+    
+    // Deposited tokens pin the mint and vault until they are withdrawn
+    let governance = &mut ctx.accounts.governance;
+    if governance.total_deposited > 0
+        && (governance.governance_mint != governance_mint || governance.vault_token_account != vault_token_account)
+    {
+        return Err(crate::error::ErrorCode::InvalidGovernance.into());
+    }
+    
+    governance.treasury = ctx.accounts.treasury.key();
+    governance.governance_mint = governance_mint;
+    governance.vault_token_account = vault_token_account;
+    governance.quorum = quorum;
+    governance.threshold_bps = threshold_bps;
+    governance.voting_period_seconds = voting_period_seconds;
+    governance.is_enabled = is_enabled;
+    governance.bump = ctx.bumps.governance;
+    governance.delegate_threshold = delegate_threshold;
+    governance.require_council_config = require_council_config;
+    
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    keeper_mode: KeeperMode,
+    keeper_tip: u64,
+    keeper_tip_period_seconds: u64,
+    keeper_tip_period_cap: u64,
+    treasury_seed_name: String,
+)]
+pub struct SetKeeperConfig<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `load_governance`
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[signer]` authority: [AccountInfo] Must hold the config permission
+/// 2. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 3. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 4. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - keeper_mode: [KeeperMode] Who may execute due payouts
+/// - keeper_tip: [u64] Lamports paid to the fee payer of each payout execution (0 for no tip)
+/// - keeper_tip_period_seconds: [u64] Length of the window keeper tips are capped over
+/// - keeper_tip_period_cap: [u64] Most keeper tips paid per window
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SetKeeperConfig>,
+    keeper_mode: KeeperMode,
+    keeper_tip: u64,
+    keeper_tip_period_seconds: u64,
+    keeper_tip_period_cap: u64,
+) -> Result<()> {
+    // Verify authority may manage the treasury configuration
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    
+    // While governance requires it, the council co-signs this exact change
+    let config_hash = crate::utils::args_hash("set_keeper_config", &(keeper_mode, keeper_tip, keeper_tip_period_seconds, keeper_tip_period_cap))?;
+    crate::utils::enforce_council_cosign(
+        &ctx.accounts.treasury.key(),
+        crate::utils::load_governance(&ctx.accounts.governance)?.as_ref(),
+        ctx.accounts.approval_request.as_mut(),
+        config_hash,
+    )?;
+    
+    if keeper_tip > 0 && (keeper_tip_period_seconds == 0 || keeper_tip_period_seconds > i64::MAX as u64) {
+        return Err(crate::error::ErrorCode::InvalidKeeperTip.into());
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.keeper_mode = keeper_mode;
+    treasury.keeper_tip = keeper_tip;
+    treasury.keeper_tip_period_seconds = keeper_tip_period_seconds;
+    treasury.keeper_tip_period_cap = keeper_tip_period_cap;
+    
+    Ok(())
+}
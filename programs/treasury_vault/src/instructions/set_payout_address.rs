@@ -0,0 +1,97 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    recipient_address: Pubkey,
+    payout_address: Pubkey,
+    treasury_seed_name: String,
+)]
+pub struct SetPayoutAddress<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_address.as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    pub recipient_signer: Signer<'info>,
+
+    pub cosigner: Option<Signer<'info>>,
+
+    // Bound to the treasury and cosigner by `authorize`
+    pub cosigner_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `load_governance`
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig]
+/// 1. `[writable]` recipient: [Recipient]
+/// 2. `[signer]` recipient_signer: [AccountInfo] The recipient's wallet
+/// 3. `[signer]` cosigner: [AccountInfo] (optional) Must hold the manage recipients permission when the treasury requires a co-sign
+/// 4. `[]` cosigner_member: [Member] (optional) Membership of the cosigner
+/// 5. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - payout_address: [Pubkey] Wallet that receives the recipient's SOL payouts from now on
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SetPayoutAddress>,
+    recipient_address: Pubkey,
+    payout_address: Pubkey,
+) -> Result<()> {
+    // Verify the recipient signed, plus the co-signer if the treasury requires one
+    crate::utils::authorize_payout_change(
+        &ctx.accounts.treasury,
+        &ctx.accounts.recipient,
+        &ctx.accounts.recipient_signer.key(),
+        ctx.accounts.cosigner.as_ref(),
+        ctx.accounts.cosigner_member.as_ref(),
+    )?;
+
+    // While governance requires it, the council co-signs this exact change
+    let config_hash = crate::utils::args_hash("set_payout_address", &(recipient_address, payout_address))?;
+    crate::utils::enforce_council_cosign(
+        &ctx.accounts.treasury.key(),
+        crate::utils::load_governance(&ctx.accounts.governance)?.as_ref(),
+        ctx.accounts.approval_request.as_mut(),
+        config_hash,
+    )?;
+
+    if payout_address == Pubkey::default() {
+        return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+    }
+
+    ctx.accounts.recipient.payout_address = payout_address;
+
+    msg!("Payout address of recipient {} set to {}", recipient_address, payout_address);
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(
+    recipient_address: Pubkey,
+    treasury_seed_name: String,
+)]
+pub struct SetPayoutTokenAccount<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_address.as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        init_if_needed,
+        space=113,
+        payer=recipient_signer,
+        seeds = [
+            b"payout_destination",
+            recipient.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub payout_destination: Account<'info, PayoutDestination>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        token::mint = token_mint,
+    )]
+    pub payout_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub recipient_signer: Signer<'info>,
+
+    pub cosigner: Option<Signer<'info>>,
+
+    // Bound to the treasury and cosigner by `authorize`
+    pub cosigner_member: Option<Account<'info, Member>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `load_governance`
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig]
+/// 1. `[]` recipient: [Recipient]
+/// 2. `[writable]` payout_destination: [PayoutDestination]
+/// 3. `[]` token_mint: [Mint]
+/// 4. `[]` payout_token_account: [TokenAccount] Receives the recipient's payouts of this mint
+/// 5. `[writable, signer]` recipient_signer: [AccountInfo] The recipient's wallet
+/// 6. `[signer]` cosigner: [AccountInfo] (optional) Must hold the manage recipients permission when the treasury requires a co-sign
+/// 7. `[]` cosigner_member: [Member] (optional) Membership of the cosigner
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 9. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 10. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SetPayoutTokenAccount>,
+    recipient_address: Pubkey,
+) -> Result<()> {
+    // Verify the recipient signed, plus the co-signer if the treasury requires one
+    crate::utils::authorize_payout_change(
+        &ctx.accounts.treasury,
+        &ctx.accounts.recipient,
+        &ctx.accounts.recipient_signer.key(),
+        ctx.accounts.cosigner.as_ref(),
+        ctx.accounts.cosigner_member.as_ref(),
+    )?;
+
+    // While governance requires it, the council co-signs this exact change
+    let config_hash = crate::utils::args_hash("set_payout_token_account", &(recipient_address, ctx.accounts.token_mint.key(), ctx.accounts.payout_token_account.key()))?;
+    crate::utils::enforce_council_cosign(
+        &ctx.accounts.treasury.key(),
+        crate::utils::load_governance(&ctx.accounts.governance)?.as_ref(),
+        ctx.accounts.approval_request.as_mut(),
+        config_hash,
+    )?;
+
+    let payout_destination = &mut ctx.accounts.payout_destination;
+    payout_destination.recipient = ctx.accounts.recipient.key();
+    payout_destination.token_mint = ctx.accounts.token_mint.key();
+    payout_destination.token_account = ctx.accounts.payout_token_account.key();
+    payout_destination.updated_at = Clock::get()?.unix_timestamp;
+    payout_destination.bump = ctx.bumps.payout_destination;
+
+    msg!(
+        "Payout token account of recipient {} for mint {} set to {}",
+        recipient_address,
+        payout_destination.token_mint,
+        payout_destination.token_account
+    );
+
+    Ok(())
+}
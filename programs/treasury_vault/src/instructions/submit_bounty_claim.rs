@@ -0,0 +1,93 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    bounty_id: u64,
+    evidence_hash: [u8; 32],
+    treasury_seed_name: String,
+)]
+pub struct SubmitBountyClaim<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bounty",
+            treasury.key().as_ref(),
+            bounty_id.to_le_bytes().as_ref(),
+        ],
+        bump = bounty.bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_signer.key().as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        init,
+        space=113,
+        payer=recipient_signer,
+        seeds = [
+            b"bounty_claim",
+            bounty.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump
+    )]
+    pub bounty_claim: Account<'info, BountyClaim>,
+
+    #[account(mut)]
+    pub recipient_signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[]` recipient: [Recipient] The hunter's recipient entry
+/// 3. `[writable]` bounty_claim: [BountyClaim] 
+/// 4. `[writable, signer]` recipient_signer: [AccountInfo] The hunter's wallet address
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - evidence_hash: [[u8; 32]] Hash of the hunter's submission
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SubmitBountyClaim>,
+    evidence_hash: [u8; 32],
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Only whitelisted recipients can hunt, so rewards keep to bound payout destinations
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    ctx.accounts.bounty.validate_claimable(current_time)?;
+    
+    let bounty_claim = &mut ctx.accounts.bounty_claim;
+    bounty_claim.bounty = ctx.accounts.bounty.key();
+    bounty_claim.recipient = ctx.accounts.recipient.key();
+    bounty_claim.evidence_hash = evidence_hash;
+    bounty_claim.submitted_at = current_time;
+    bounty_claim.bump = ctx.bumps.bounty_claim;
+    
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.claim_count = bounty.claim_count.checked_add(1).unwrap();
+    
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    grant_id: u64,
+    milestone_index: u8,
+    evidence_hash: [u8; 32],
+    treasury_seed_name: String,
+)]
+pub struct SubmitMilestoneEvidence<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_signer.key().as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"milestone_grant",
+            treasury.key().as_ref(),
+            grant_id.to_le_bytes().as_ref(),
+        ],
+        bump = milestone_grant.bump
+    )]
+    pub milestone_grant: Account<'info, MilestoneGrant>,
+
+    pub recipient_signer: Signer<'info>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The grantee
+/// 2. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 3. `[signer]` recipient_signer: [AccountInfo] The grantee's wallet address
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - evidence_hash: [[u8; 32]] Hash of the evidence the milestone is complete
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SubmitMilestoneEvidence>,
+    milestone_index: u8,
+    evidence_hash: [u8; 32],
+) -> Result<()> {
+    // Only the grantee submits evidence
+    if ctx.accounts.milestone_grant.recipient != ctx.accounts.recipient.key() {
+        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
+    }
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let milestone = ctx.accounts.milestone_grant.current_milestone(milestone_index)?;
+    milestone.validate_open(current_time)?;
+    
+    // New evidence replaces the old, so earlier sign-offs no longer count
+    if !matches!(milestone.status, MilestoneStatus::Pending | MilestoneStatus::Submitted) {
+        return Err(crate::error::ErrorCode::InvalidMilestoneStatus.into());
+    }
+    milestone.evidence_hash = evidence_hash;
+    milestone.approvals = 0;
+    milestone.status = MilestoneStatus::Submitted;
+    
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    request_id: u64,
+    amount: u64,
+    token_mint: Option<Pubkey>,
+    due_date: i64,
+    invoice_hash: [u8; 32],
+    invoice_uri: String,
+    treasury_seed_name: String,
+)]
+pub struct SubmitPaymentRequest<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        seeds = [
+            b"recipient",
+            treasury.key().as_ref(),
+            recipient_signer.key().as_ref(),
+        ],
+        bump
+    )]
+    pub recipient: Account<'info, Recipient>,
+
+    #[account(
+        init,
+        space=771,
+        payer=recipient_signer,
+        seeds = [
+            b"payment_request",
+            recipient.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub payment_request: Account<'info, PaymentRequest>,
+
+    #[account(mut)]
+    pub recipient_signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] 
+/// 2. `[writable]` payment_request: [PaymentRequest] 
+/// 3. `[writable, signer]` recipient_signer: [AccountInfo] The recipient's wallet address
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - request_id: [u64] Identifier of the request, unique for the recipient
+/// - amount: [u64] Amount invoiced, in lamports or raw token units
+/// - token_mint: [Option<Pubkey>] Mint to be paid in (null for SOL)
+/// - due_date: [i64] When the invoice is due
+/// - invoice_hash: [[u8; 32]] Hash of the invoice document
+/// - invoice_uri: [String] Where the invoice can be found
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SubmitPaymentRequest>,
+    request_id: u64,
+    amount: u64,
+    token_mint: Option<Pubkey>,
+    due_date: i64,
+    invoice_hash: [u8; 32],
+    invoice_uri: String,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Only whitelisted recipients can invoice the treasury
+    ctx.accounts.recipient.validate_payable(current_time)?;
+    
+    if amount == 0 || invoice_uri.len() > MAX_INVOICE_URI_LEN {
+        return Err(crate::error::ErrorCode::InvalidPaymentRequest.into());
+    }
+    
+    let payment_request = &mut ctx.accounts.payment_request;
+    payment_request.treasury = ctx.accounts.treasury.key();
+    payment_request.recipient = ctx.accounts.recipient.key();
+    payment_request.request_id = request_id;
+    payment_request.amount = amount;
+    payment_request.token_mint = token_mint;
+    payment_request.due_date = due_date;
+    payment_request.invoice_hash = invoice_hash;
+    payment_request.invoice_uri = invoice_uri;
+    payment_request.paid_amount = 0;
+    payment_request.history = Vec::new();
+    payment_request.bump = ctx.bumps.payment_request;
+    payment_request.record(
+        PaymentRequestStatus::Pending,
+        ctx.accounts.recipient_signer.key(),
+        amount,
+        current_time,
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(
+    treasury_seed_name: String,
+)]
+pub struct SweepTokenVault<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_vault",
+            treasury.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        close = successor
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        address = token_vault.token_account @ crate::error::ErrorCode::InvalidTokenVault,
+    )]
+    pub source: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination.owner == successor.key() @ crate::error::ErrorCode::InvalidSuccessor,
+        constraint = destination.mint == token_mint.key() @ crate::error::ErrorCode::InvalidTokenVault,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = Some(successor.key()) == treasury.successor @ crate::error::ErrorCode::InvalidSuccessor,
+    )]
+    /// CHECK: validated against treasury.successor
+    pub successor: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            admin.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+impl<'info> SweepTokenVault<'info> {
+    pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
+}
+
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` token_vault: [TokenVault] 
+/// 2. `[]` token_mint: [Mint] 
+/// 3. `[signer]` admin: [AccountInfo] Must hold the manage vaults permission
+/// 4. `[writable]` source: [TokenAccount] The vault's token account.
+/// 5. `[writable]` destination: [TokenAccount] The successor's token account.
+/// 6. `[writable]` successor: [AccountInfo] Receives the vault account rent
+/// 7. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[]` authority_member: [Member] (optional) Membership of the admin
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<SweepTokenVault>,
+) -> Result<()> {
+    // Verify admin holds the manage vaults permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_MANAGE_VAULTS,
+    )?;
+    
+    if !ctx.accounts.treasury.is_winding_down() {
+        return Err(crate::error::ErrorCode::TreasuryNotWindingDown.into());
+    }
+    
+    // Streams and schedules may still pay out of the vault
+    if ctx.accounts.treasury.has_open_obligations() {
+        return Err(crate::error::ErrorCode::OpenObligationsRemaining.into());
+    }
+    
+    // Transfer everything held by the vault token account to the successor
+    let amount = ctx.accounts.source.amount;
+    if amount > 0 {
+        ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
+    }
+    
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.balance = 0;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.token_vault_count = treasury.token_vault_count.saturating_sub(1);
+    
+    msg!(
+        "Token vault swept: {} tokens of {} sent to successor",
+        amount,
+        ctx.accounts.token_mint.key()
+    );
+    
+    // Vault account closed due to close constraint
+    Ok(())
+}
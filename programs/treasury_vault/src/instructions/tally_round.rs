@@ -0,0 +1,100 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(
+    round_id: u64,
+    treasury_seed_name: String,
+)]
+pub struct TallyRound<'info> {
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"grants_round",
+            treasury.key().as_ref(),
+            round_id.to_le_bytes().as_ref(),
+        ],
+        bump = grants_round.bump
+    )]
+    pub grants_round: Account<'info, GrantsRound>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            fee_payer.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+}
+
+/// Accounts:
+/// 0. `[signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless
+/// 1. `[]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` grants_round: [GrantsRound] 
+/// 3. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 4. `[]` remaining_accounts: the round's next [RoundProject] accounts to tally, in registration order
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TallyRound<'info>>,
+) -> Result<()> {
+    // Verify fee payer may crank the round under the treasury's keeper mode
+    crate::utils::authorize_keeper(
+        &ctx.accounts.treasury,
+        &ctx.accounts.fee_payer.key(),
+        ctx.accounts.authority_member.as_ref(),
+    )?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let grants_round_key = ctx.accounts.grants_round.key();
+    let grants_round = &mut ctx.accounts.grants_round;
+    
+    // The first page closes the round to contributions
+    if grants_round.status == RoundStatus::Open {
+        if current_time < grants_round.end_time {
+            return Err(crate::error::ErrorCode::InvalidRoundStatus.into());
+        }
+        grants_round.status = RoundStatus::Tallying;
+    }
+    if grants_round.status != RoundStatus::Tallying {
+        return Err(crate::error::ErrorCode::InvalidRoundStatus.into());
+    }
+    
+    // Sum the scores of the next projects in registration order
+    for account_info in ctx.remaining_accounts {
+        let round_project = Account::<RoundProject>::try_from(account_info)?;
+        if round_project.grants_round != grants_round_key || round_project.index != grants_round.tally_cursor {
+            return Err(crate::error::ErrorCode::InvalidRoundProject.into());
+        }
+        
+        grants_round.qf_total = grants_round.qf_total.checked_add(round_project.qf_score()).unwrap();
+        grants_round.tally_cursor += 1;
+    }
+    
+    if grants_round.tally_cursor == grants_round.project_count {
+        grants_round.status = RoundStatus::Distributing;
+    }
+    
+    msg!(
+        "Tallied {} of {} round projects",
+        grants_round.tally_cursor,
+        grants_round.project_count
+    );
+    
+    Ok(())
+}
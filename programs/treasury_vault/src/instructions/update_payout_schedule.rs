@@ -16,10 +16,17 @@ use anchor_spl::{
     interval_seconds: Option<u64>,
     max_executions: Option<u64>,
     is_active: Option<bool>,
+    recurrence: Option<Recurrence>,
+    end_time: Option<i64>,
+    splits: Option<Vec<PayoutSplit>>,
+    oracle_pricing: Option<Option<OraclePricing>>,
+    priority: Option<u8>,
+    claim_window_seconds: Option<u64>,
     treasury_seed_name: String,
 )]
 pub struct UpdatePayoutSchedule<'info> {
     #[account(
+        mut,
         seeds = [
             b"treasury",
             treasury_seed_name.as_bytes().as_ref(),
@@ -29,6 +36,7 @@ pub struct UpdatePayoutSchedule<'info> {
     pub treasury: Account<'info, TreasuryConfig>,
 
     #[account(
+        mut,
         seeds = [
             b"recipient",
             treasury.key().as_ref(),
@@ -51,13 +59,40 @@ pub struct UpdatePayoutSchedule<'info> {
     pub payout_schedule: Account<'info, PayoutSchedule>,
 
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"approval_policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(payout_schedule.token_mint).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_commitment_approval_policy`
+    pub approval_policy: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
 }
 
 /// Accounts:
-/// 0. `[]` treasury: [TreasuryConfig] 
-/// 1. `[]` recipient: [Recipient] 
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
 /// 2. `[writable]` payout_schedule: [PayoutSchedule] 
-/// 3. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 3. `[signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 5. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for the schedule's asset, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the update raises the schedule's total payout into a tier that needs approvals or a delay, or passed by token-holder vote
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
@@ -66,27 +101,55 @@ pub struct UpdatePayoutSchedule<'info> {
 /// - start_time: [Option<i64>] Optional new start time
 /// - interval_seconds: [Option<u64>] Optional new interval between payouts
 /// - max_executions: [Option<u64>] Optional new maximum number of executions
-/// - is_active: [Option<bool>] Optional update to active status
+/// - is_active: [Option<bool>] Optional pause (false) or resume (true) of the schedule
+/// - recurrence: [Option<Recurrence>] Optional new recurrence rule
+/// - end_time: [Option<i64>] Optional new end time (0 for no end)
+/// - splits: [Option<Vec<PayoutSplit>>] Optional new shares for other recipients, in basis points (empty pays only this recipient)
+/// - oracle_pricing: [Option<Option<OraclePricing>>] Optional new price feed for a USD amount (Some(null) pays a raw amount); not allowed while the asset has approval tiers
+/// - priority: [Option<u8>] Optional new queue priority, higher drains first
+/// - claim_window_seconds: [Option<u64>] Optional new claim window; when set the recipient claims each payout within this long of it falling due (0 returns to executed payouts)
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
 pub fn handler(
     ctx: Context<UpdatePayoutSchedule>,
-    _recipient_address: Pubkey,
-    _schedule_id: u64,
+    recipient_address: Pubkey,
+    schedule_id: u64,
     amount: Option<u64>,
     start_time: Option<i64>,
     interval_seconds: Option<u64>,
     max_executions: Option<u64>,
     is_active: Option<bool>,
+    recurrence: Option<Recurrence>,
+    end_time: Option<i64>,
+    splits: Option<Vec<PayoutSplit>>,
+    oracle_pricing: Option<Option<OraclePricing>>,
+    priority: Option<u8>,
+    claim_window_seconds: Option<u64>,
 ) -> Result<()> {
-    // Verify authority is admin or treasurer
-    let treasury = &ctx.accounts.treasury;
-    if ctx.accounts.authority.key() != treasury.admin && ctx.accounts.authority.key() != treasury.treasurer {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
-    }
+    // The call's full arguments, which a passed vote must match exactly
+    let terms_hash = crate::utils::args_hash(
+        "update_payout_schedule",
+        &(recipient_address, schedule_id, amount, start_time, interval_seconds, max_executions, is_active, recurrence, end_time, &splits, oracle_pricing, priority, claim_window_seconds),
+    )?;
     
-    // Update payout schedule with new values if provided
+    // Verify authority holds the create schedule permission, unless token holders voted this exact call through
+    crate::utils::authorize_action(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.approval_request.as_deref(),
+        ApprovalAction::CreatePayoutSchedule { recipient: ctx.accounts.recipient.key(), schedule_id },
+        terms_hash,
+        PERMISSION_CREATE_SCHEDULE,
+    )?;
+    
+    // Completed and cancelled schedules are final
     let payout_schedule = &mut ctx.accounts.payout_schedule;
+    if !payout_schedule.status.is_open() {
+        return Err(crate::error::ErrorCode::ScheduleClosed.into());
+    }
+    let previous_commitment = payout_schedule.commitment();
     
+    // Update payout schedule with new values if provided
     if let Some(new_amount) = amount {
         payout_schedule.amount = new_amount;
     }
@@ -103,8 +166,97 @@ pub fn handler(
         payout_schedule.max_executions = new_max;
     }
     
+    if let Some(new_recurrence) = recurrence {
+        new_recurrence.validate()?;
+        payout_schedule.recurrence = new_recurrence;
+    }
+    
+    if let Some(new_end_time) = end_time {
+        payout_schedule.end_time = new_end_time;
+    }
+    
+    if payout_schedule.end_time != 0 && payout_schedule.end_time < payout_schedule.start_time {
+        return Err(crate::error::ErrorCode::InvalidEndTime.into());
+    }
+    
+    if let Some(new_splits) = splits {
+        payout_schedule.validate_splits(&new_splits)?;
+        payout_schedule.splits = new_splits;
+    }
+    
+    if let Some(new_pricing) = oracle_pricing {
+        if let Some(pricing) = new_pricing {
+            pricing.validate()?;
+            crate::utils::validate_oracle_pricing_allowed(&ctx.accounts.approval_policy)?;
+        }
+        payout_schedule.oracle_pricing = new_pricing;
+    }
+    
+    if let Some(new_priority) = priority {
+        payout_schedule.priority = new_priority;
+    }
+    
+    if let Some(new_window) = claim_window_seconds {
+        payout_schedule.claim_window_seconds = new_window;
+    }
+    
+    // Checked last as the claim window, interval, recurrence and splits all bear on it
+    payout_schedule.validate_claim_mode()?;
+    
+    // Raising the schedule's total payout needs the same approval tier as
+    // creating it that large, and a passed vote is spent either way
+    let vote_passed = ctx.accounts.approval_request.as_ref().is_some_and(|request| request.vote_passed);
+    if vote_passed || payout_schedule.raises_commitment(previous_commitment) {
+        if payout_schedule.oracle_pricing.is_some() {
+            crate::utils::validate_oracle_pricing_allowed(&ctx.accounts.approval_policy)?;
+        }
+        let commitment = payout_schedule.commitment();
+        let amount = payout_schedule.amount;
+        let token_mint = payout_schedule.token_mint;
+        crate::utils::enforce_commitment_approval_policy(
+            &ctx.accounts.treasury,
+            &ctx.accounts.approval_policy,
+            ctx.accounts.approval_request.as_mut(),
+            ApprovalAction::CreatePayoutSchedule { recipient: ctx.accounts.recipient.key(), schedule_id },
+            terms_hash,
+            amount,
+            commitment,
+            token_mint,
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+    let payout_schedule = &mut ctx.accounts.payout_schedule;
+    
+    // Paused schedules stay open, so pausing and resuming leave the open counts alone
     if let Some(active) = is_active {
-        payout_schedule.is_active = active;
+        match (active, payout_schedule.status) {
+            (false, ScheduleStatus::Pending | ScheduleStatus::Active) => {
+                payout_schedule.status = ScheduleStatus::Paused;
+            },
+            (true, ScheduleStatus::Paused) => {
+                if !ctx.accounts.recipient.is_active {
+                    return Err(crate::error::ErrorCode::InactiveRecipient.into());
+                }
+                payout_schedule.status = if payout_schedule.executions == 0 {
+                    ScheduleStatus::Pending
+                } else {
+                    ScheduleStatus::Active
+                };
+            },
+            _ => {},
+        }
+    }
+    
+    // Lowering max_executions or the end time can leave no payout to come, completing the schedule
+    let payout_schedule = &mut ctx.accounts.payout_schedule;
+    if payout_schedule.is_finished() {
+        payout_schedule.status = ScheduleStatus::Completed;
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.open_schedules = treasury.open_schedules.saturating_sub(1);
+        
+        let recipient = &mut ctx.accounts.recipient;
+        recipient.active_schedules = recipient.active_schedules.saturating_sub(1);
     }
     
     Ok(())
@@ -13,6 +13,7 @@ use anchor_spl::{
     name: Option<String>,
     role: Option<u8>,
     is_active: Option<bool>,
+    caps: Option<RecipientCaps>,
     treasury_seed_name: String,
 )]
 pub struct UpdateRecipient<'info> {
@@ -37,18 +38,30 @@ pub struct UpdateRecipient<'info> {
     pub recipient: Account<'info, Recipient>,
 
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
 }
 
 /// Accounts:
 /// 0. `[]` treasury: [TreasuryConfig] 
 /// 1. `[writable]` recipient: [Recipient] 
-/// 2. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 2. `[signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
 /// - name: [Option<String>] Optional new name for the recipient
 /// - role: [Option<u8>] Optional new role for the recipient
 /// - is_active: [Option<bool>] Optional update to active status
+/// - caps: [Option<RecipientCaps>] Optional new caps on what the recipient can receive
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
 pub fn handler(
     ctx: Context<UpdateRecipient>,
@@ -56,12 +69,15 @@ pub fn handler(
     name: Option<String>,
     role: Option<u8>,
     is_active: Option<bool>,
+    caps: Option<RecipientCaps>,
 ) -> Result<()> {
-    // Verify authority is admin or treasurer
-    let treasury = &ctx.accounts.treasury;
-    if ctx.accounts.authority.key() != treasury.admin && ctx.accounts.authority.key() != treasury.treasurer {
-        return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
-    }
+    // Verify authority holds the manage recipients permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_MANAGE_RECIPIENTS,
+    )?;
     
     // Update recipient with new values if provided
     let recipient = &mut ctx.accounts.recipient;
@@ -75,8 +91,17 @@ pub fn handler(
     }
     
     if let Some(active) = is_active {
+        // Schedules and streams must be paused or resumed together with the recipient
+        if active != recipient.is_active && recipient.has_open_schedules() {
+            return Err(crate::error::ErrorCode::RecipientHasOpenSchedules.into());
+        }
         recipient.is_active = active;
     }
     
+    if let Some(new_caps) = caps {
+        new_caps.validate()?;
+        recipient.caps = new_caps;
+    }
+    
     Ok(())
 }
\ No newline at end of file
@@ -18,6 +18,12 @@ use anchor_spl::{
     require_token_gate: Option<bool>,
     token_gate_mint: Option<Pubkey>,
     token_gate_amount: Option<u64>,
+    deactivation_policy: Option<DeactivationPolicy>,
+    attester: Option<Pubkey>,
+    require_payout_cosign: Option<bool>,
+    skip_missed_payouts: Option<bool>,
+    cooling_period_seconds: Option<u64>,
+    cooling_payout_cap: Option<u64>,
     treasury_seed_name: String,
 )]
 pub struct UpdateTreasuryConfig<'info> {
@@ -32,11 +38,37 @@ pub struct UpdateTreasuryConfig<'info> {
     pub treasury: Account<'info, TreasuryConfig>,
 
     pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"member",
+            treasury.key().as_ref(),
+            admin.key().as_ref(),
+        ],
+        bump = authority_member.bump
+    )]
+    pub authority_member: Option<Account<'info, Member>>,
+
+    #[account(
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `load_governance`
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub approval_request: Option<Account<'info, ApprovalRequest>>,
 }
 
 /// Accounts:
 /// 0. `[writable]` treasury: [TreasuryConfig] 
-/// 1. `[signer]` admin: [AccountInfo] 
+/// 1. `[signer]` admin: [AccountInfo] Must hold the config permission
+/// 2. `[]` authority_member: [Member] (optional) Membership of the admin
+/// 3. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 4. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
 ///
 /// Data:
 /// - new_admin: [Option<Pubkey>] Optional new admin authority
@@ -48,6 +80,12 @@ pub struct UpdateTreasuryConfig<'info> {
 /// - require_token_gate: [Option<bool>] Optional update to token gating requirement
 /// - token_gate_mint: [Option<Pubkey>] Optional new token gate mint
 /// - token_gate_amount: [Option<u64>] Optional new token gate amount
+/// - deactivation_policy: [Option<DeactivationPolicy>] Optional new effect of deactivating a recipient
+/// - attester: [Option<Pubkey>] Optional new attester for recipient admissions (the default pubkey removes it)
+/// - require_payout_cosign: [Option<bool>] Optional update to whether payout destination changes need a co-signer
+/// - skip_missed_payouts: [Option<bool>] Optional update to whether late executions drop missed periods
+/// - cooling_period_seconds: [Option<u64>] Optional new cooling period for newly added recipients (0 for none)
+/// - cooling_payout_cap: [Option<u64>] Optional new cap on what a recipient receives while cooling (0 holds every payout)
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
 pub fn handler(
     ctx: Context<UpdateTreasuryConfig>,
@@ -60,13 +98,43 @@ pub fn handler(
     require_token_gate: Option<bool>,
     token_gate_mint: Option<Pubkey>,
     token_gate_amount: Option<u64>,
+    deactivation_policy: Option<DeactivationPolicy>,
+    attester: Option<Pubkey>,
+    require_payout_cosign: Option<bool>,
+    skip_missed_payouts: Option<bool>,
+    cooling_period_seconds: Option<u64>,
+    cooling_payout_cap: Option<u64>,
 ) -> Result<()> {
-    // Verify the signer is the admin
-    let treasury = &mut ctx.accounts.treasury;
-    if ctx.accounts.admin.key() != treasury.admin {
+    // Verify admin holds the config permission
+    crate::utils::authorize(
+        &ctx.accounts.treasury,
+        &ctx.accounts.admin.key(),
+        ctx.accounts.authority_member.as_ref(),
+        PERMISSION_CONFIG,
+    )?;
+    
+    // Only the admin can hand over the admin role
+    if new_admin.is_some() && ctx.accounts.admin.key() != ctx.accounts.treasury.admin {
         return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
     }
     
+    // While governance requires it, the council co-signs this exact change
+    let config_hash = crate::utils::args_hash(
+        "update_treasury_config",
+        &(
+            (new_admin, new_treasurer, &description, daily_limit, weekly_limit, monthly_limit, require_token_gate, token_gate_mint),
+            (token_gate_amount, deactivation_policy, attester, require_payout_cosign, skip_missed_payouts, cooling_period_seconds, cooling_payout_cap),
+        ),
+    )?;
+    crate::utils::enforce_council_cosign(
+        &ctx.accounts.treasury.key(),
+        crate::utils::load_governance(&ctx.accounts.governance)?.as_ref(),
+        ctx.accounts.approval_request.as_mut(),
+        config_hash,
+    )?;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    
     // Update treasury config with new values if provided
     if let Some(admin) = new_admin {
         treasury.admin = admin;
@@ -104,5 +172,29 @@ pub fn handler(
         treasury.token_gate_amount = amount;
     }
     
+    if let Some(policy) = deactivation_policy {
+        treasury.deactivation_policy = policy;
+    }
+    
+    if let Some(attester) = attester {
+        treasury.attester = if attester == Pubkey::default() { None } else { Some(attester) };
+    }
+    
+    if let Some(require) = require_payout_cosign {
+        treasury.require_payout_cosign = require;
+    }
+    
+    if let Some(skip) = skip_missed_payouts {
+        treasury.skip_missed_payouts = skip;
+    }
+    
+    if let Some(seconds) = cooling_period_seconds {
+        treasury.cooling_period_seconds = seconds;
+    }
+    
+    if let Some(cap) = cooling_payout_cap {
+        treasury.cooling_payout_cap = cap;
+    }
+    
     Ok(())
 }
\ No newline at end of file
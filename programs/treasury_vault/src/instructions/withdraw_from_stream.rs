@@ -43,6 +43,25 @@ pub struct WithdrawFromStream<'info> {
 
     #[account(mut)]
     pub recipient_signer: Signer<'info>,
+
+    #[account(
+        mut,
+        address = recipient.payout_address @ crate::error::ErrorCode::InvalidPayoutDestination,
+    )]
+    /// CHECK: Bound to the recipient's payout address
+    pub payout_address: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"policy",
+            treasury.key().as_ref(),
+            crate::utils::policy_asset(streaming_schedule.token_mint).as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: May be uninitialized; read by `enforce_outflow_policy`
+    pub policy: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -50,6 +69,10 @@ pub fn handler(
     _stream_id: u64,
 ) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
+
+    // Verify recipient is active and their whitelist entry has not expired
+    ctx.accounts.recipient.validate_payable(current_time)?;
+
     let streaming_schedule = &mut ctx.accounts.streaming_schedule;
 
     // Verify stream is active
@@ -63,50 +86,33 @@ pub fn handler(
         return Err(crate::error::ErrorCode::PayoutNotDue.into());
     }
 
-    // Do spending limit checks first (before taking mutable references)
-    {
-        let treasury = &ctx.accounts.treasury;
-        let mut daily_total = treasury.daily_total;
-        let mut weekly_total = treasury.weekly_total;
-        let mut monthly_total = treasury.monthly_total;
-        
-        // Reset totals if time periods have passed
-        if current_time - treasury.last_day_reset >= 86400 {
-            daily_total = 0;
-        }
-        
-        if current_time - treasury.last_week_reset >= 604800 {
-            weekly_total = 0;
-        }
-        
-        if current_time - treasury.last_month_reset >= 2592000 {
-            monthly_total = 0;
-        }
-        
-        // Check limits
-        if daily_total.checked_add(withdrawable).unwrap() > treasury.daily_limit {
-            return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
-        }
-        
-        if weekly_total.checked_add(withdrawable).unwrap() > treasury.weekly_limit {
-            return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
-        }
-        
-        if monthly_total.checked_add(withdrawable).unwrap() > treasury.monthly_limit {
-            return Err(crate::error::ErrorCode::SpendingLimitExceeded.into());
-        }
+    // Withdraw only what the recipient's caps allow; the rest stays vested in the stream
+    let allowance = ctx.accounts.recipient.remaining_allowance(&ctx.accounts.treasury, current_time);
+    if allowance == 0 {
+        return ctx.accounts.recipient.validate_receivable(&ctx.accounts.treasury, withdrawable, current_time);
     }
+    let withdrawable = withdrawable.min(allowance);
+
+    // Check the withdrawal against the spending limits and the outflow policy
+    let outflow = Outflow {
+        amount: withdrawable,
+        destinations: vec![ctx.accounts.payout_address.key()],
+        balance: ctx.accounts.treasury.total_balance,
+        current_time,
+    };
+    crate::utils::enforce_outflow_policy(&mut ctx.accounts.treasury, &ctx.accounts.policy, &outflow)?;
 
     // Transfer funds based on token type (using immutable borrow)
     match streaming_schedule.token_mint {
         None => {
             // SOL transfer
             let treasury_info = ctx.accounts.treasury.to_account_info();
-            let recipient_info = ctx.accounts.recipient_signer.to_account_info();
+            let recipient_info = ctx.accounts.payout_address.to_account_info();
 
-            if **treasury_info.lamports.borrow() < withdrawable {
+            if **treasury_info.lamports.borrow() < withdrawable || ctx.accounts.treasury.total_balance < withdrawable {
                 return Err(crate::error::ErrorCode::InsufficientFunds.into());
             }
+            crate::utils::validate_sol_reserve(&ctx.accounts.treasury, withdrawable, false)?;
 
             **treasury_info.try_borrow_mut_lamports()? -= withdrawable;
             **recipient_info.try_borrow_mut_lamports()? += withdrawable;
@@ -122,29 +128,8 @@ pub fn handler(
     {
         let treasury = &mut ctx.accounts.treasury;
         
-        // Reset and update time periods
-        if current_time - treasury.last_day_reset >= 86400 {
-            treasury.daily_total = 0;
-            treasury.last_day_reset = current_time;
-        }
-        
-        if current_time - treasury.last_week_reset >= 604800 {
-            treasury.weekly_total = 0;
-            treasury.last_week_reset = current_time;
-        }
-        
-        if current_time - treasury.last_month_reset >= 2592000 {
-            treasury.monthly_total = 0;
-            treasury.last_month_reset = current_time;
-        }
-        
-        // Update totals
-        treasury.daily_total = treasury.daily_total.checked_add(withdrawable).unwrap();
-        treasury.weekly_total = treasury.weekly_total.checked_add(withdrawable).unwrap();
-        treasury.monthly_total = treasury.monthly_total.checked_add(withdrawable).unwrap();
-        
         // Update treasury balance
-        treasury.total_balance = treasury.total_balance.saturating_sub(withdrawable);
+        treasury.total_balance = treasury.total_balance.checked_sub(withdrawable).unwrap();
     }
 
     // Update stream state
@@ -154,12 +139,17 @@ pub fn handler(
     // Check if stream is fully withdrawn
     if streaming_schedule.withdrawn_amount >= streaming_schedule.total_amount {
         streaming_schedule.is_active = false;
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.open_streams = treasury.open_streams.saturating_sub(1);
+
+        let recipient = &mut ctx.accounts.recipient;
+        recipient.active_streams = recipient.active_streams.saturating_sub(1);
     }
 
     // Update recipient stats
     let recipient = &mut ctx.accounts.recipient;
-    recipient.total_received = recipient.total_received.checked_add(withdrawable).unwrap();
-    recipient.last_payout_time = current_time;
+    recipient.record_payout(withdrawable, current_time);
 
     msg!(
         "Stream withdrawal: {} tokens withdrawn, {} total withdrawn",
@@ -0,0 +1,122 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::token::Token;
+
+#[derive(Accounts)]
+#[instruction(
+    amount: u64,
+    treasury_seed_name: String,
+)]
+pub struct WithdrawGovernanceTokens<'info> {
+    #[account(
+        seeds = [
+            b"treasury",
+            treasury_seed_name.as_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"governance",
+            treasury.key().as_ref(),
+        ],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"voter",
+            treasury.key().as_ref(),
+            voter.key().as_ref(),
+        ],
+        bump = voter_record.bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        address = governance.vault_token_account @ crate::error::ErrorCode::InvalidGovernance,
+    )]
+    /// CHECK: Bound to the governance vault
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: implement manual checks if needed
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+    )]
+    pub authority: Signer<'info>,
+
+    pub csl_spl_token_v0_0_0: Program<'info, Token>,
+}
+
+impl<'info> WithdrawGovernanceTokens<'info> {
+    pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+                anchor_spl::token::Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.authority.to_account_info()
+                }
+            ),
+            amount, 
+        )
+    }
+}
+
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` governance: [GovernanceConfig] 
+/// 2. `[writable]` voter_record: [VoterRecord] 
+/// 3. `[signer]` voter: [AccountInfo] 
+/// 4. `[writable]` source: [AccountInfo] The governance vault.
+/// 5. `[writable]` destination: [AccountInfo] The destination account.
+/// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///
+/// Data:
+/// - amount: [u64] Amount of governance tokens to withdraw
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+pub fn handler(
+    ctx: Context<WithdrawGovernanceTokens>,
+    amount: u64,
+) -> Result<()> {
+    // Deposits back votes until the latest one the voter cast has ended
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time < ctx.accounts.voter_record.locked_until {
+        return Err(crate::error::ErrorCode::VotingTokensLocked.into());
+    }
+    
+    // The delegation was made with these deposits, so it must be revoked first
+    ctx.accounts.voter_record.validate_undelegated()?;
+    
+    if ctx.accounts.voter_record.deposited < amount {
+        return Err(crate::error::ErrorCode::InsufficientFunds.into());
+    }
+    
+    // Transfer tokens from the governance vault to destination
+    ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
+    
+    let voter_record = &mut ctx.accounts.voter_record;
+    voter_record.deposited = voter_record.deposited.checked_sub(amount).unwrap();
+    
+    let governance = &mut ctx.accounts.governance;
+    governance.total_deposited = governance.total_deposited.checked_sub(amount).unwrap();
+    
+    Ok(())
+}
@@ -1,6 +1,7 @@
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -9,6 +10,7 @@ use anchor_lang::prelude::*;
 use std::str::FromStr;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -39,7 +41,10 @@ pub mod treasury_vault {
 
 /// Accounts:
 /// 0. `[writable]` treasury: [TreasuryConfig] 
-/// 1. `[signer]` admin: [AccountInfo] 
+/// 1. `[signer]` admin: [AccountInfo] Must hold the config permission
+/// 2. `[]` authority_member: [Member] (optional) Membership of the admin
+/// 3. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 4. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
 ///
 /// Data:
 /// - new_admin: [Option<Pubkey>] Optional new admin authority
@@ -51,9 +56,15 @@ pub mod treasury_vault {
 /// - require_token_gate: [Option<bool>] Optional update to token gating requirement
 /// - token_gate_mint: [Option<Pubkey>] Optional new token gate mint
 /// - token_gate_amount: [Option<u64>] Optional new token gate amount
+/// - deactivation_policy: [Option<DeactivationPolicy>] Optional new effect of deactivating a recipient
+/// - attester: [Option<Pubkey>] Optional new attester for recipient admissions (the default pubkey removes it)
+/// - require_payout_cosign: [Option<bool>] Optional update to whether payout destination changes need a co-signer
+/// - skip_missed_payouts: [Option<bool>] Optional update to whether late executions drop missed periods
+/// - cooling_period_seconds: [Option<u64>] Optional new cooling period for newly added recipients (0 for none)
+/// - cooling_payout_cap: [Option<u64>] Optional new cap on what a recipient receives while cooling (0 holds every payout)
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
-	pub fn update_treasury_config(ctx: Context<UpdateTreasuryConfig>, new_admin: Option<Pubkey>, new_treasurer: Option<Pubkey>, description: Option<String>, daily_limit: Option<u64>, weekly_limit: Option<u64>, monthly_limit: Option<u64>, require_token_gate: Option<bool>, token_gate_mint: Option<Pubkey>, token_gate_amount: Option<u64>, _treasury_seed_name: String) -> Result<()> {
-		update_treasury_config::handler(ctx, new_admin, new_treasurer, description, daily_limit, weekly_limit, monthly_limit, require_token_gate, token_gate_mint, token_gate_amount)
+	pub fn update_treasury_config(ctx: Context<UpdateTreasuryConfig>, new_admin: Option<Pubkey>, new_treasurer: Option<Pubkey>, description: Option<String>, daily_limit: Option<u64>, weekly_limit: Option<u64>, monthly_limit: Option<u64>, require_token_gate: Option<bool>, token_gate_mint: Option<Pubkey>, token_gate_amount: Option<u64>, deactivation_policy: Option<DeactivationPolicy>, attester: Option<Pubkey>, require_payout_cosign: Option<bool>, skip_missed_payouts: Option<bool>, cooling_period_seconds: Option<u64>, cooling_payout_cap: Option<u64>, _treasury_seed_name: String) -> Result<()> {
+		update_treasury_config::handler(ctx, new_admin, new_treasurer, description, daily_limit, weekly_limit, monthly_limit, require_token_gate, token_gate_mint, token_gate_amount, deactivation_policy, attester, require_payout_cosign, skip_missed_payouts, cooling_period_seconds, cooling_payout_cap)
 	}
 
 /// Accounts:
@@ -68,10 +79,10 @@ pub mod treasury_vault {
 	}
 
 /// Accounts:
-/// 0. `[]` treasury: [TreasuryConfig] 
+/// 0. `[writable]` treasury: [TreasuryConfig] 
 /// 1. `[writable]` token_vault: [TokenVault] 
 /// 2. `[]` token_mint: [Mint] 
-/// 3. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 3. `[signer]` authority: [AccountInfo] Must hold the manage vaults permission
 /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 /// 5. `[writable, signer]` funding: [AccountInfo] Funding account (must be a system account)
 /// 6. `[writable]` assoc_token_account: [AccountInfo] Associated token account address to be created
@@ -79,6 +90,7 @@ pub mod treasury_vault {
 /// 8. `[]` mint: [Mint] The token mint for the new associated token account
 /// 9. `[]` token_program: [AccountInfo] SPL Token program
 /// 10. `[]` csl_spl_assoc_token_v0_0_0: [AccountInfo] Auto-generated, CslSplAssocTokenProgram v0.0.0
+/// 11. `[]` authority_member: [Member] (optional) Membership of the authority
 ///
 /// Data:
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
@@ -106,39 +118,51 @@ pub mod treasury_vault {
 /// Accounts:
 /// 0. `[]` treasury: [TreasuryConfig] 
 /// 1. `[writable]` recipient: [Recipient] 
-/// 2. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 2. `[signer]` authority: [AccountInfo] Must hold the manage recipients permission
 /// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 4. `[]` token_gate_mint: [Mint] (optional) Required when token gating is enabled
+/// 5. `[]` recipient_token_account: [TokenAccount] (optional) Required when token gating is enabled
+/// 6. `[]` token_program: [AccountInfo] (optional) SPL Token program
+/// 7. `[]` instructions_sysvar: [AccountInfo] (optional) Required when the treasury has an attester
+/// 8. `[]` authority_member: [Member] (optional) Membership of the authority
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
 /// - name: [String] Name of the recipient
 /// - role: [u8] Role of the recipient (0=Regular, 1=Privileged)
+/// - valid_until: [i64] When the whitelist entry expires (0 for no expiry)
+/// - attestation_ref: [[u8; 32]] Reference to the off-chain attestation, e.g. a document hash
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
-	pub fn add_recipient(ctx: Context<AddRecipient>, recipient_address: Pubkey, name: String, role: u8, _treasury_seed_name: String) -> Result<()> {
-		add_recipient::handler(ctx, recipient_address, name, role)
+	pub fn add_recipient(ctx: Context<AddRecipient>, recipient_address: Pubkey, name: String, role: u8, valid_until: i64, attestation_ref: [u8; 32], _treasury_seed_name: String) -> Result<()> {
+		add_recipient::handler(ctx, recipient_address, name, role, valid_until, attestation_ref)
 	}
 
 /// Accounts:
 /// 0. `[]` treasury: [TreasuryConfig] 
 /// 1. `[writable]` recipient: [Recipient] 
-/// 2. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 2. `[signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
 /// - name: [Option<String>] Optional new name for the recipient
 /// - role: [Option<u8>] Optional new role for the recipient
 /// - is_active: [Option<bool>] Optional update to active status
+/// - caps: [Option<RecipientCaps>] Optional new caps on what the recipient can receive
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
-	pub fn update_recipient(ctx: Context<UpdateRecipient>, recipient_address: Pubkey, name: Option<String>, role: Option<u8>, is_active: Option<bool>, _treasury_seed_name: String) -> Result<()> {
-		update_recipient::handler(ctx, recipient_address, name, role, is_active)
+	pub fn update_recipient(ctx: Context<UpdateRecipient>, recipient_address: Pubkey, name: Option<String>, role: Option<u8>, is_active: Option<bool>, caps: Option<RecipientCaps>, _treasury_seed_name: String) -> Result<()> {
+		update_recipient::handler(ctx, recipient_address, name, role, is_active, caps)
 	}
 
 /// Accounts:
-/// 0. `[]` treasury: [TreasuryConfig] 
-/// 1. `[]` recipient: [Recipient] 
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
 /// 2. `[writable]` payout_schedule: [PayoutSchedule] 
-/// 3. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 3. `[signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
 /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 6. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for this asset, if set
+/// 7. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the amount's tier needs approvals or a delay, or passed by token-holder vote
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
@@ -148,16 +172,22 @@ pub mod treasury_vault {
 /// - start_time: [i64] When this schedule starts
 /// - interval_seconds: [u64] Interval between payouts in seconds (0 for one-time)
 /// - max_executions: [u64] Maximum number of executions (0 for unlimited)
+/// - recurrence: [Recurrence] When payouts fall due, counted from start_time
+/// - end_time: [i64] No payouts fall due after this time (0 for no end)
+/// - oracle_pricing: [Option<OraclePricing>] Price feed converting a USD amount at execution (null for a raw amount); not allowed while the asset has approval tiers
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
-	pub fn create_payout_schedule(ctx: Context<CreatePayoutSchedule>, recipient_address: Pubkey, schedule_id: u64, amount: u64, token_mint: Option<Pubkey>, start_time: i64, interval_seconds: u64, max_executions: u64, _treasury_seed_name: String) -> Result<()> {
-		create_payout_schedule::handler(ctx, recipient_address, schedule_id, amount, token_mint, start_time, interval_seconds, max_executions)
+	pub fn create_payout_schedule(ctx: Context<CreatePayoutSchedule>, recipient_address: Pubkey, schedule_id: u64, amount: u64, token_mint: Option<Pubkey>, start_time: i64, interval_seconds: u64, max_executions: u64, recurrence: Recurrence, end_time: i64, oracle_pricing: Option<OraclePricing>, _treasury_seed_name: String) -> Result<()> {
+		create_payout_schedule::handler(ctx, recipient_address, schedule_id, amount, token_mint, start_time, interval_seconds, max_executions, recurrence, end_time, oracle_pricing)
 	}
 
 /// Accounts:
-/// 0. `[]` treasury: [TreasuryConfig] 
-/// 1. `[]` recipient: [Recipient] 
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
 /// 2. `[writable]` payout_schedule: [PayoutSchedule] 
-/// 3. `[signer]` authority: [AccountInfo] Must be admin or treasurer
+/// 3. `[signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 5. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for the schedule's asset, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the update raises the schedule's total payout into a tier that needs approvals or a delay, or passed by token-holder vote
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
@@ -166,49 +196,85 @@ pub mod treasury_vault {
 /// - start_time: [Option<i64>] Optional new start time
 /// - interval_seconds: [Option<u64>] Optional new interval between payouts
 /// - max_executions: [Option<u64>] Optional new maximum number of executions
-/// - is_active: [Option<bool>] Optional update to active status
+/// - is_active: [Option<bool>] Optional pause (false) or resume (true) of the schedule
+/// - recurrence: [Option<Recurrence>] Optional new recurrence rule
+/// - end_time: [Option<i64>] Optional new end time (0 for no end)
+/// - splits: [Option<Vec<PayoutSplit>>] Optional new shares for other recipients, in basis points (empty pays only this recipient)
+/// - oracle_pricing: [Option<Option<OraclePricing>>] Optional new price feed for a USD amount (Some(null) pays a raw amount); not allowed while the asset has approval tiers
+/// - priority: [Option<u8>] Optional new queue priority, higher drains first
+/// - claim_window_seconds: [Option<u64>] Optional new claim window; when set the recipient claims each payout within this long of it falling due (0 returns to executed payouts)
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
-	pub fn update_payout_schedule(ctx: Context<UpdatePayoutSchedule>, recipient_address: Pubkey, schedule_id: u64, amount: Option<u64>, start_time: Option<i64>, interval_seconds: Option<u64>, max_executions: Option<u64>, is_active: Option<bool>, _treasury_seed_name: String) -> Result<()> {
-		update_payout_schedule::handler(ctx, recipient_address, schedule_id, amount, start_time, interval_seconds, max_executions, is_active)
+	pub fn update_payout_schedule(ctx: Context<UpdatePayoutSchedule>, recipient_address: Pubkey, schedule_id: u64, amount: Option<u64>, start_time: Option<i64>, interval_seconds: Option<u64>, max_executions: Option<u64>, is_active: Option<bool>, recurrence: Option<Recurrence>, end_time: Option<i64>, splits: Option<Vec<PayoutSplit>>, oracle_pricing: Option<Option<OraclePricing>>, priority: Option<u8>, claim_window_seconds: Option<u64>, _treasury_seed_name: String) -> Result<()> {
+		update_payout_schedule::handler(ctx, recipient_address, schedule_id, amount, start_time, interval_seconds, max_executions, is_active, recurrence, end_time, splits, oracle_pricing, priority, claim_window_seconds)
 	}
 
 /// Accounts:
-/// 0. `[writable, signer]` fee_payer: [AccountInfo] Auto-generated, default fee payer
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[writable]` payout_schedule: [PayoutSchedule] 
+/// 3. `[signer]` authority: [AccountInfo] Must hold the create schedule permission
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - reason: [String] Why the schedule is cancelled, up to MAX_CANCEL_REASON_LEN bytes
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn cancel_payout_schedule(ctx: Context<CancelPayoutSchedule>, recipient_address: Pubkey, schedule_id: u64, reason: String, _treasury_seed_name: String) -> Result<()> {
+		cancel_payout_schedule::handler(ctx, recipient_address, schedule_id, reason)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless; receives the keeper tip
 /// 1. `[writable]` treasury: [TreasuryConfig] 
 /// 2. `[writable]` recipient: [Recipient] 
 /// 3. `[writable]` payout_schedule: [PayoutSchedule] 
-/// 4. `[writable]` recipient_address: [AccountInfo] 
+/// 4. `[]` recipient_address: [AccountInfo] The recipient's wallet address
+/// 5. `[writable]` payout_address: [AccountInfo] The recipient's bound payout address
+/// 6. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 7. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 8. `[]` price_feed: [AccountInfo] (optional) The schedule's price feed, required for oracle-priced schedules
+/// 9. `[writable]` payout_queue: [PendingPayoutQueue] (optional) Queues the payout instead of failing when it exceeds the spending limits
+/// 10. `[writable]` remaining_accounts: for each split in order, its [Recipient] and that recipient's payout address
 ///
 /// Data:
 /// - schedule_id: [u64] Unique identifier for this schedule
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
-	pub fn execute_sol_payout(ctx: Context<ExecuteSolPayout>, schedule_id: u64, _treasury_seed_name: String) -> Result<()> {
+	pub fn execute_sol_payout<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteSolPayout<'info>>, schedule_id: u64, _treasury_seed_name: String) -> Result<()> {
 		execute_sol_payout::handler(ctx, schedule_id)
 	}
 
 /// Accounts:
-/// 0. `[writable, signer]` fee_payer: [AccountInfo] Auto-generated, default fee payer
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless; receives the keeper tip
 /// 1. `[writable]` treasury: [TreasuryConfig] 
 /// 2. `[writable]` recipient: [Recipient] 
 /// 3. `[writable]` payout_schedule: [PayoutSchedule] 
 /// 4. `[writable]` token_vault: [TokenVault] 
 /// 5. `[]` token_mint: [Mint] 
 /// 6. `[writable]` source: [AccountInfo] The source account.
-/// 7. `[writable]` destination: [AccountInfo] The destination account.
+/// 7. `[writable]` destination: [AccountInfo] The recipient's bound payout token account.
 /// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
 /// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 11. `[]` payout_destination: [PayoutDestination] The recipient's token account override for this mint, if registered
+/// 12. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+/// 13. `[]` price_feed: [AccountInfo] (optional) The schedule's price feed, required for oracle-priced schedules
+/// 14. `[writable]` remaining_accounts: for each split in order, its [Recipient], that recipient's [PayoutDestination] for this mint and its payout token account
 ///
 /// Data:
 /// - recipient_address: [Pubkey] The recipient's wallet address
 /// - schedule_id: [u64] Unique identifier for this schedule
 /// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
-	pub fn execute_token_payout(ctx: Context<ExecuteTokenPayout>, recipient_address: Pubkey, schedule_id: u64, _treasury_seed_name: String) -> Result<()> {
+	pub fn execute_token_payout<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteTokenPayout<'info>>, recipient_address: Pubkey, schedule_id: u64, _treasury_seed_name: String) -> Result<()> {
 		execute_token_payout::handler(ctx, recipient_address, schedule_id)
 	}
 
 /// Accounts:
 /// 0. `[writable]` treasury: [TreasuryConfig] 
-/// 1. `[writable, signer]` admin: [AccountInfo] 
+/// 1. `[writable, signer]` admin: [AccountInfo] Must hold the emergency permission
+/// 2. `[]` authority_member: [Member] (optional) Membership of the admin
+/// 3. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for this asset, if set
+/// 4. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the amount's tier needs approvals or a delay, or to go below the reserve floor
 ///
 /// Data:
 /// - amount: [u64] Amount of SOL to withdraw (in lamports)
@@ -221,11 +287,14 @@ pub mod treasury_vault {
 /// 0. `[]` treasury: [TreasuryConfig] 
 /// 1. `[writable]` token_vault: [TokenVault] 
 /// 2. `[]` token_mint: [Mint] 
-/// 3. `[signer]` admin: [AccountInfo] 
+/// 3. `[signer]` admin: [AccountInfo] Must hold the emergency permission
 /// 4. `[writable]` source: [AccountInfo] The source account.
 /// 5. `[writable]` destination: [AccountInfo] The destination account.
 /// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
 /// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 8. `[]` authority_member: [Member] (optional) Membership of the admin
+/// 9. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for this asset, if set
+/// 10. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the amount's tier needs approvals or a delay, or to go below the reserve floor
 ///
 /// Data:
 /// - amount: [u64] Amount of tokens to withdraw
@@ -263,7 +332,7 @@ pub fn withdraw_from_stream(
     withdraw_from_stream::handler(ctx, stream_id)
 }
 
-/// Cancel an active stream
+/// Cancel an active stream, paying out what has vested unless the recipient can no longer receive it
 pub fn cancel_stream(
     ctx: Context<CancelStream>,
     recipient_address: Pubkey,
@@ -273,6 +342,985 @@ pub fn cancel_stream(
     cancel_stream::handler(ctx, recipient_address, stream_id)
 }
 
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[signer]` admin: [AccountInfo] Must hold the config permission
+/// 2. `[]` authority_member: [Member] (optional) Membership of the admin
+///
+/// Data:
+/// - successor: [Pubkey] Wallet that receives the remaining SOL and tokens
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn begin_decommission(ctx: Context<BeginDecommission>, successor: Pubkey, _treasury_seed_name: String) -> Result<()> {
+		begin_decommission::handler(ctx, successor)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` token_vault: [TokenVault] 
+/// 2. `[]` token_mint: [Mint] 
+/// 3. `[signer]` admin: [AccountInfo] Must hold the manage vaults permission
+/// 4. `[writable]` source: [TokenAccount] The vault's token account.
+/// 5. `[writable]` destination: [TokenAccount] The successor's token account.
+/// 6. `[writable]` successor: [AccountInfo] Receives the vault account rent
+/// 7. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[]` authority_member: [Member] (optional) Membership of the admin
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn sweep_token_vault(ctx: Context<SweepTokenVault>, _treasury_seed_name: String) -> Result<()> {
+		sweep_token_vault::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[signer]` admin: [AccountInfo] Must hold the config permission
+/// 2. `[writable]` successor: [AccountInfo] Receives all remaining lamports
+/// 3. `[]` authority_member: [Member] (optional) Membership of the admin
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn close_treasury(ctx: Context<CloseTreasury>, _treasury_seed_name: String) -> Result<()> {
+		close_treasury::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[writable]` remaining_accounts: every active [PayoutSchedule] and [StreamingSchedule] of the recipient
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn deactivate_recipient<'info>(ctx: Context<'_, '_, 'info, 'info, DeactivateRecipient<'info>>, recipient_address: Pubkey, _treasury_seed_name: String) -> Result<()> {
+		deactivate_recipient::handler(ctx, recipient_address)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[writable]` remaining_accounts: every paused [PayoutSchedule] and [StreamingSchedule] of the recipient
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn reactivate_recipient<'info>(ctx: Context<'_, '_, 'info, 'info, ReactivateRecipient<'info>>, recipient_address: Pubkey, _treasury_seed_name: String) -> Result<()> {
+		reactivate_recipient::handler(ctx, recipient_address)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 3. `[]` instructions_sysvar: [AccountInfo] (optional) Required when the treasury has an attester
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - valid_until: [i64] New whitelist expiry
+/// - attestation_ref: [[u8; 32]] Reference to the new off-chain attestation
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn renew_recipient(ctx: Context<RenewRecipient>, recipient_address: Pubkey, valid_until: i64, attestation_ref: [u8; 32], _treasury_seed_name: String) -> Result<()> {
+		renew_recipient::handler(ctx, recipient_address, valid_until, attestation_ref)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` member: [Member] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission, plus any privileged permission it changes unless it is the admin
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - member_address: [Pubkey] The member's wallet address
+/// - permissions: [u16] Bitmask of the PERMISSION_* constants
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn add_member(ctx: Context<AddMember>, member_address: Pubkey, permissions: u16, _treasury_seed_name: String) -> Result<()> {
+		add_member::handler(ctx, member_address, permissions)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` member: [Member] 
+/// 2. `[signer]` authority: [AccountInfo] Must hold the config permission, plus any privileged permission it changes unless it is the admin
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 5. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - member_address: [Pubkey] The member's wallet address
+/// - permissions: [u16] New bitmask of the PERMISSION_* constants
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn update_member(ctx: Context<UpdateMember>, member_address: Pubkey, permissions: u16, _treasury_seed_name: String) -> Result<()> {
+		update_member::handler(ctx, member_address, permissions)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` member: [Member] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission, plus any privileged permission it changes unless it is the admin
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 5. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - member_address: [Pubkey] The member's wallet address
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn remove_member(ctx: Context<RemoveMember>, member_address: Pubkey, _treasury_seed_name: String) -> Result<()> {
+		remove_member::handler(ctx, member_address)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig]
+/// 1. `[writable]` recipient: [Recipient]
+/// 2. `[signer]` recipient_signer: [AccountInfo] The recipient's wallet
+/// 3. `[signer]` cosigner: [AccountInfo] (optional) Must hold the manage recipients permission when the treasury requires a co-sign
+/// 4. `[]` cosigner_member: [Member] (optional) Membership of the cosigner
+/// 5. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - payout_address: [Pubkey] Wallet that receives the recipient's SOL payouts from now on
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn set_payout_address(ctx: Context<SetPayoutAddress>, recipient_address: Pubkey, payout_address: Pubkey, _treasury_seed_name: String) -> Result<()> {
+		set_payout_address::handler(ctx, recipient_address, payout_address)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig]
+/// 1. `[]` recipient: [Recipient]
+/// 2. `[writable]` payout_destination: [PayoutDestination]
+/// 3. `[]` token_mint: [Mint]
+/// 4. `[]` payout_token_account: [TokenAccount] Receives the recipient's payouts of this mint
+/// 5. `[writable, signer]` recipient_signer: [AccountInfo] The recipient's wallet
+/// 6. `[signer]` cosigner: [AccountInfo] (optional) Must hold the manage recipients permission when the treasury requires a co-sign
+/// 7. `[]` cosigner_member: [Member] (optional) Membership of the cosigner
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 9. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 10. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn set_payout_token_account(ctx: Context<SetPayoutTokenAccount>, recipient_address: Pubkey, _treasury_seed_name: String) -> Result<()> {
+		set_payout_token_account::handler(ctx, recipient_address)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` approval_policy: [ApprovalPolicy] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - token_mint: [Option<Pubkey>] Asset the tiers apply to (null for SOL)
+/// - tiers: [Vec<ApprovalTier>] Amount tiers in ascending order (empty lifts the policy)
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn set_approval_policy(ctx: Context<SetApprovalPolicy>, token_mint: Option<Pubkey>, tiers: Vec<ApprovalTier>, _treasury_seed_name: String) -> Result<()> {
+		set_approval_policy::handler(ctx, token_mint, tiers)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` approval_request: [ApprovalRequest] 
+/// 2. `[writable, signer]` proposer: [AccountInfo] Must hold the permission the action needs
+/// 3. `[]` authority_member: [Member] (optional) Membership of the proposer
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for this request
+/// - action: [ApprovalAction] The action to be approved
+/// - amount: [u64] Amount the action moves, as passed to it
+/// - token_mint: [Option<Pubkey>] Asset the action moves (null for SOL)
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn create_approval_request(ctx: Context<CreateApprovalRequest>, request_id: u64, action: ApprovalAction, amount: u64, token_mint: Option<Pubkey>, _treasury_seed_name: String) -> Result<()> {
+		create_approval_request::handler(ctx, request_id, action, amount, token_mint)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` approval_request: [ApprovalRequest] 
+/// 2. `[signer]` approver: [AccountInfo] Must hold the approve permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the approver
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for this request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn approve_request(ctx: Context<ApproveRequest>, request_id: u64, _treasury_seed_name: String) -> Result<()> {
+		approve_request::handler(ctx, request_id)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` policy: [Policy] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 5. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - token_mint: [Option<Pubkey>] Asset the rules apply to (null for SOL)
+/// - rules: [Vec<PolicyRule>] Rules every outflow is checked against, in order (empty lifts the policy)
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn set_policy(ctx: Context<SetPolicy>, token_mint: Option<Pubkey>, rules: Vec<PolicyRule>, _treasury_seed_name: String) -> Result<()> {
+		set_policy::handler(ctx, token_mint, rules)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` token_vault: [TokenVault] (optional) The vault whose floor to set; the treasury's SOL floor when omitted
+/// 2. `[signer]` authority: [AccountInfo] Must hold the config permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 5. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - reserve_floor: [u64] Balance normal outflows must leave in place (0 for no floor)
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn set_reserve_floor(ctx: Context<SetReserveFloor>, reserve_floor: u64, _treasury_seed_name: String) -> Result<()> {
+		set_reserve_floor::handler(ctx, reserve_floor)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` payout_queue: [PendingPayoutQueue] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the config permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn initialize_payout_queue(ctx: Context<InitializePayoutQueue>, _treasury_seed_name: String) -> Result<()> {
+		initialize_payout_queue::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless; receives the keeper tip
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` payout_queue: [PendingPayoutQueue] 
+/// 3. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 4. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 5. `[writable]` remaining_accounts: for each queued payout to drain, from the front, its [Recipient] and that recipient's payout address
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn drain_queue<'info>(ctx: Context<'_, '_, 'info, 'info, DrainQueue<'info>>, _treasury_seed_name: String) -> Result<()> {
+		drain_queue::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` payout_queue: [PendingPayoutQueue] 
+/// 2. `[signer]` authority: [AccountInfo] Must hold the config permission, as needed to set up the queue
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+///
+/// Data:
+/// - sequence: [u64] Sequence number of the queued payout to drop
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn prune_queued_payout(ctx: Context<PruneQueuedPayout>, sequence: u64, _treasury_seed_name: String) -> Result<()> {
+		prune_queued_payout::handler(ctx, sequence)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[signer]` authority: [AccountInfo] Must hold the config permission
+/// 2. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 3. `[]` governance: [GovernanceConfig] The treasury's governance config, if set
+/// 4. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - keeper_mode: [KeeperMode] Who may execute due payouts
+/// - keeper_tip: [u64] Lamports paid to the fee payer of each payout execution (0 for no tip)
+/// - keeper_tip_period_seconds: [u64] Length of the window keeper tips are capped over
+/// - keeper_tip_period_cap: [u64] Most keeper tips paid per window
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn set_keeper_config(ctx: Context<SetKeeperConfig>, keeper_mode: KeeperMode, keeper_tip: u64, keeper_tip_period_seconds: u64, keeper_tip_period_cap: u64, _treasury_seed_name: String) -> Result<()> {
+		set_keeper_config::handler(ctx, keeper_mode, keeper_tip, keeper_tip_period_seconds, keeper_tip_period_cap)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` payout_schedule: [PayoutSchedule] 
+///
+/// Data:
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+///
+/// Returns the schedule's [PayoutDue], for keepers to simulate before executing.
+/// The recipient and the treasury's funds and limits are not checked.
+	pub fn check_payout_due(ctx: Context<CheckPayoutDue>, _schedule_id: u64, _treasury_seed_name: String) -> Result<PayoutDue> {
+		check_payout_due::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[writable]` payout_schedule: [PayoutSchedule] 
+/// 3. `[writable, signer]` recipient_signer: [AccountInfo] The recipient's wallet address
+/// 4. `[writable]` payout_address: [AccountInfo] The recipient's bound payout address
+/// 5. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 6. `[]` price_feed: [AccountInfo] (optional) The schedule's price feed, required for oracle-priced schedules
+///
+/// Data:
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn claim_payout(ctx: Context<ClaimPayout>, schedule_id: u64, _treasury_seed_name: String) -> Result<()> {
+		claim_payout::handler(ctx, schedule_id)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] 
+/// 2. `[writable]` payout_schedule: [PayoutSchedule] 
+/// 3. `[writable, signer]` recipient_signer: [AccountInfo] The recipient's wallet address
+/// 4. `[writable]` token_vault: [TokenVault] 
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` source: [AccountInfo] The vault's token account.
+/// 7. `[writable]` destination: [AccountInfo] The recipient's bound payout token account.
+/// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` payout_destination: [PayoutDestination] The recipient's token account override for this mint, if registered
+/// 11. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+/// 12. `[]` price_feed: [AccountInfo] (optional) The schedule's price feed, required for oracle-priced schedules
+///
+/// Data:
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn claim_token_payout(ctx: Context<ClaimTokenPayout>, schedule_id: u64, _treasury_seed_name: String) -> Result<()> {
+		claim_token_payout::handler(ctx, schedule_id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` fee_payer: [AccountInfo] Anyone may expire lapsed periods
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] 
+/// 3. `[writable]` payout_schedule: [PayoutSchedule] 
+///
+/// Data:
+/// - recipient_address: [Pubkey] The recipient's wallet address
+/// - schedule_id: [u64] Unique identifier for this schedule
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn expire_claim_periods(ctx: Context<ExpireClaimPeriods>, recipient_address: Pubkey, schedule_id: u64, _treasury_seed_name: String) -> Result<()> {
+		expire_claim_periods::handler(ctx, recipient_address, schedule_id)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] 
+/// 2. `[writable]` payment_request: [PaymentRequest] 
+/// 3. `[writable, signer]` recipient_signer: [AccountInfo] The recipient's wallet address
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - request_id: [u64] Identifier of the request, unique for the recipient
+/// - amount: [u64] Amount invoiced, in lamports or raw token units
+/// - token_mint: [Option<Pubkey>] Mint to be paid in (null for SOL)
+/// - due_date: [i64] When the invoice is due
+/// - invoice_hash: [[u8; 32]] Hash of the invoice document
+/// - invoice_uri: [String] Where the invoice can be found
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn submit_payment_request(ctx: Context<SubmitPaymentRequest>, request_id: u64, amount: u64, token_mint: Option<Pubkey>, due_date: i64, invoice_hash: [u8; 32], invoice_uri: String, _treasury_seed_name: String) -> Result<()> {
+		submit_payment_request::handler(ctx, request_id, amount, token_mint, due_date, invoice_hash, invoice_uri)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] The recipient that submitted the request
+/// 2. `[writable]` payment_request: [PaymentRequest] 
+/// 3. `[writable]` payout_address: [AccountInfo] The recipient's bound payout address
+/// 4. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission unless the approval request passed a token-holder vote
+/// 5. `[]` authority_member: [Member] (optional) Membership of the reviewer
+/// 6. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 7. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for SOL, if set
+/// 8. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the invoiced amount's tier needs approvals or a delay, or passed by token-holder vote
+///
+/// Data:
+/// - request_id: [u64] Identifier of the request, unique for the recipient
+/// - amount: [u64] Amount to approve and pay now, up to what remains of the request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn approve_sol_payment_request(ctx: Context<ApproveSolPaymentRequest>, _request_id: u64, amount: u64, _treasury_seed_name: String) -> Result<()> {
+		approve_sol_payment_request::handler(ctx, amount)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] The recipient that submitted the request
+/// 2. `[writable]` payment_request: [PaymentRequest] 
+/// 3. `[writable]` token_vault: [TokenVault] 
+/// 4. `[]` token_mint: [Mint] 
+/// 5. `[writable]` source: [AccountInfo] The source account.
+/// 6. `[writable]` destination: [AccountInfo] The recipient's bound payout token account.
+/// 7. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission unless the approval request passed a token-holder vote
+/// 10. `[]` authority_member: [Member] (optional) Membership of the reviewer
+/// 11. `[]` payout_destination: [PayoutDestination] The recipient's token account override for this mint, if registered
+/// 12. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+/// 13. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for this mint, if set
+/// 14. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the invoiced amount's tier needs approvals or a delay, or passed by token-holder vote
+///
+/// Data:
+/// - request_id: [u64] Identifier of the request, unique for the recipient
+/// - amount: [u64] Amount to approve and pay now, up to what remains of the request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn approve_token_payment_request(ctx: Context<ApproveTokenPaymentRequest>, _request_id: u64, amount: u64, _treasury_seed_name: String) -> Result<()> {
+		approve_token_payment_request::handler(ctx, amount)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The recipient that submitted the request
+/// 2. `[writable]` payment_request: [PaymentRequest] 
+/// 3. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission
+/// 4. `[]` authority_member: [Member] (optional) Membership of the reviewer
+///
+/// Data:
+/// - request_id: [u64] Identifier of the request, unique for the recipient
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn reject_payment_request(ctx: Context<RejectPaymentRequest>, _request_id: u64, _treasury_seed_name: String) -> Result<()> {
+		reject_payment_request::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The grantee
+/// 2. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 3. `[writable]` token_vault: [TokenVault] (optional) The vault of the grant's mint, required for token grants
+/// 4. `[writable, signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
+/// 5. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 6. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for the grant's asset, if set
+/// 7. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the grant total's tier needs approvals or a delay, or passed by token-holder vote
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - token_mint: [Option<Pubkey>] Mint the grant is paid in (null for SOL)
+/// - milestones: [Vec<MilestoneTerms>] The milestones in the order they are worked through
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn create_milestone_grant(ctx: Context<CreateMilestoneGrant>, grant_id: u64, token_mint: Option<Pubkey>, milestones: Vec<MilestoneTerms>, _treasury_seed_name: String) -> Result<()> {
+		create_milestone_grant::handler(ctx, grant_id, token_mint, milestones)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The grantee
+/// 2. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 3. `[signer]` recipient_signer: [AccountInfo] The grantee's wallet address
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - evidence_hash: [[u8; 32]] Hash of the evidence the milestone is complete
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn submit_milestone_evidence(ctx: Context<SubmitMilestoneEvidence>, _grant_id: u64, milestone_index: u8, evidence_hash: [u8; 32], _treasury_seed_name: String) -> Result<()> {
+		submit_milestone_evidence::handler(ctx, milestone_index, evidence_hash)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 2. `[signer]` approver: [AccountInfo] One of the milestone's approvers
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn approve_milestone(ctx: Context<ApproveMilestone>, _grant_id: u64, milestone_index: u8, _treasury_seed_name: String) -> Result<()> {
+		approve_milestone::handler(ctx, milestone_index)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] The grantee
+/// 3. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 4. `[writable]` payout_address: [AccountInfo] The grantee's bound payout address
+/// 5. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 6. `[]` authority_member: [Member] (optional) Membership of the fee payer
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn release_sol_milestone(ctx: Context<ReleaseSolMilestone>, _grant_id: u64, milestone_index: u8, _treasury_seed_name: String) -> Result<()> {
+		release_sol_milestone::handler(ctx, milestone_index)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] The grantee
+/// 3. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 4. `[]` token_vault: [TokenVault] 
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` source: [AccountInfo] The source account.
+/// 7. `[writable]` destination: [AccountInfo] The grantee's bound payout token account.
+/// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 11. `[]` payout_destination: [PayoutDestination] The grantee's token account override for this mint, if registered
+/// 12. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn release_token_milestone(ctx: Context<ReleaseTokenMilestone>, _grant_id: u64, milestone_index: u8, _treasury_seed_name: String) -> Result<()> {
+		release_token_milestone::handler(ctx, milestone_index)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` milestone_grant: [MilestoneGrant] 
+/// 2. `[writable]` token_vault: [TokenVault] (optional) The vault of the grant's mint, required for token grants
+/// 3. `[signer]` authority: [AccountInfo] Must hold the create schedule permission
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+///
+/// Data:
+/// - grant_id: [u64] Unique identifier for this grant
+/// - milestone_index: [u8] Position of the milestone in the grant
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn reclaim_milestone(ctx: Context<ReclaimMilestone>, _grant_id: u64, milestone_index: u8, _treasury_seed_name: String) -> Result<()> {
+		reclaim_milestone::handler(ctx, milestone_index)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The vendor
+/// 2. `[writable]` escrow: [Escrow] 
+/// 3. `[writable]` token_vault: [TokenVault] (optional) The vault of the escrow's mint, required for token escrows
+/// 4. `[writable, signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
+/// 5. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 6. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for the escrow's asset, if set
+/// 7. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the amount's tier needs approvals or a delay, or passed by token-holder vote
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - token_mint: [Option<Pubkey>] Mint the escrow holds (null for SOL)
+/// - amount: [u64] Amount to hold for the vendor
+/// - arbitrator: [Pubkey] Settles the escrow if either party disputes it
+/// - release_time: [i64] When the dispute window closes and the amount can be released to the vendor
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn create_escrow(ctx: Context<CreateEscrow>, escrow_id: u64, token_mint: Option<Pubkey>, amount: u64, arbitrator: Pubkey, release_time: i64, _treasury_seed_name: String) -> Result<()> {
+		create_escrow::handler(ctx, escrow_id, token_mint, amount, arbitrator, release_time)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` recipient: [Recipient] The vendor
+/// 2. `[writable]` escrow: [Escrow] 
+/// 3. `[signer]` disputer: [AccountInfo] The vendor's wallet address, or a holder of the execute payout permission for the treasury's side
+/// 4. `[]` authority_member: [Member] (optional) Membership of the disputer
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn dispute_escrow(ctx: Context<DisputeEscrow>, _escrow_id: u64, _treasury_seed_name: String) -> Result<()> {
+		dispute_escrow::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission, unless the release time has passed and it is the vendor or the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] The vendor
+/// 3. `[writable]` escrow: [Escrow] 
+/// 4. `[writable]` payout_address: [AccountInfo] The vendor's bound payout address
+/// 5. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 6. `[]` authority_member: [Member] (optional) Membership of the fee payer
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn release_sol_escrow(ctx: Context<ReleaseSolEscrow>, _escrow_id: u64, _treasury_seed_name: String) -> Result<()> {
+		release_sol_escrow::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Must hold the execute payout permission, unless the release time has passed and it is the vendor or the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` recipient: [Recipient] The vendor
+/// 3. `[writable]` escrow: [Escrow] 
+/// 4. `[]` token_vault: [TokenVault] 
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` source: [AccountInfo] The source account.
+/// 7. `[writable]` destination: [AccountInfo] The vendor's bound payout token account.
+/// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 11. `[]` payout_destination: [PayoutDestination] The vendor's token account override for this mint, if registered
+/// 12. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn release_token_escrow(ctx: Context<ReleaseTokenEscrow>, _escrow_id: u64, _treasury_seed_name: String) -> Result<()> {
+		release_token_escrow::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] The vendor
+/// 2. `[writable]` escrow: [Escrow] 
+/// 3. `[writable]` payout_address: [AccountInfo] The vendor's bound payout address
+/// 4. `[signer]` arbitrator: [AccountInfo] The escrow's arbitrator
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - vendor_amount: [u64] Share of the escrowed amount paid to the vendor; the rest returns to the treasury
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn resolve_sol_escrow(ctx: Context<ResolveSolEscrow>, _escrow_id: u64, vendor_amount: u64, _treasury_seed_name: String) -> Result<()> {
+		resolve_sol_escrow::handler(ctx, vendor_amount)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` recipient: [Recipient] The vendor
+/// 2. `[writable]` escrow: [Escrow] 
+/// 3. `[writable]` token_vault: [TokenVault] 
+/// 4. `[]` token_mint: [Mint] 
+/// 5. `[writable]` source: [AccountInfo] The source account.
+/// 6. `[writable]` destination: [AccountInfo] The vendor's bound payout token account.
+/// 7. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[signer]` arbitrator: [AccountInfo] The escrow's arbitrator
+/// 10. `[]` payout_destination: [PayoutDestination] The vendor's token account override for this mint, if registered
+///
+/// Data:
+/// - escrow_id: [u64] Unique identifier for this escrow
+/// - vendor_amount: [u64] Share of the escrowed amount paid to the vendor; the rest returns to the vault
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn resolve_token_escrow(ctx: Context<ResolveTokenEscrow>, _escrow_id: u64, vendor_amount: u64, _treasury_seed_name: String) -> Result<()> {
+		resolve_token_escrow::handler(ctx, vendor_amount)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` grants_round: [GrantsRound] 
+/// 2. `[writable, signer]` authority: [AccountInfo] Must hold the create schedule permission
+/// 3. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - matching_pool: [u64] Lamports of the treasury's SOL set aside for matching
+/// - start_time: [i64] When contributions open
+/// - end_time: [i64] When contributions close and matching can be computed
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn create_grants_round(ctx: Context<CreateGrantsRound>, round_id: u64, matching_pool: u64, start_time: i64, end_time: i64, _treasury_seed_name: String) -> Result<()> {
+		create_grants_round::handler(ctx, round_id, matching_pool, start_time, end_time)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` grants_round: [GrantsRound] 
+/// 2. `[]` recipient: [Recipient] The project's recipient entry
+/// 3. `[writable]` round_project: [RoundProject] 
+/// 4. `[writable, signer]` authority: [AccountInfo] Must hold the manage recipients permission
+/// 5. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn register_round_project(ctx: Context<RegisterRoundProject>, _round_id: u64, _treasury_seed_name: String) -> Result<()> {
+		register_round_project::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` grants_round: [GrantsRound] 
+/// 2. `[]` recipient: [Recipient] The project's recipient entry
+/// 3. `[writable]` round_project: [RoundProject] 
+/// 4. `[writable]` contribution: [Contribution] The contributor's running total for the project
+/// 5. `[writable]` payout_address: [AccountInfo] The project's bound payout address, which receives the contribution
+/// 6. `[writable, signer]` contributor: [AccountInfo] The community wallet contributing
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - amount: [u64] Lamports to contribute
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn contribute_to_round(ctx: Context<ContributeToRound>, _round_id: u64, amount: u64, _treasury_seed_name: String) -> Result<()> {
+		contribute_to_round::handler(ctx, amount)
+	}
+
+/// Accounts:
+/// 0. `[signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless
+/// 1. `[]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` grants_round: [GrantsRound] 
+/// 3. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 4. `[]` remaining_accounts: the round's next [RoundProject] accounts to tally, in registration order
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn tally_round<'info>(ctx: Context<'_, '_, 'info, 'info, TallyRound<'info>>, _round_id: u64, _treasury_seed_name: String) -> Result<()> {
+		tally_round::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[signer]` fee_payer: [AccountInfo] Must hold the execute payout permission unless the treasury is permissionless
+/// 1. `[writable]` treasury: [TreasuryConfig] 
+/// 2. `[writable]` grants_round: [GrantsRound] 
+/// 3. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 4. `[]` authority_member: [Member] (optional) Membership of the fee payer
+/// 5. `[writable]` remaining_accounts: for each of the round's next projects, in registration order, or project with a deferred match, its [RoundProject], its [Recipient] and the recipient's payout address
+///
+/// Data:
+/// - round_id: [u64] Unique identifier for this round
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn distribute_round<'info>(ctx: Context<'_, '_, 'info, 'info, DistributeRound<'info>>, _round_id: u64, _treasury_seed_name: String) -> Result<()> {
+		distribute_round::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[writable]` token_vault: [TokenVault] (optional) The vault of the reward's mint, required for token bounties
+/// 3. `[writable, signer]` authority: [AccountInfo] Must hold the create schedule permission unless the approval request passed a token-holder vote
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 5. `[]` approval_policy: [ApprovalPolicy] The treasury's approval tiers for the reward's asset, if set
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Required when the reward's tier needs approvals or a delay, or passed by token-holder vote
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - token_mint: [Option<Pubkey>] Mint the reward is paid in (null for SOL)
+/// - reward: [u64] Amount paid for the accepted claim
+/// - deadline: [i64] Last time claims are taken
+/// - description_hash: [[u8; 32]] Hash of the bounty's description
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn post_bounty(ctx: Context<PostBounty>, bounty_id: u64, token_mint: Option<Pubkey>, reward: u64, deadline: i64, description_hash: [u8; 32], _treasury_seed_name: String) -> Result<()> {
+		post_bounty::handler(ctx, bounty_id, token_mint, reward, deadline, description_hash)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[]` recipient: [Recipient] The hunter's recipient entry
+/// 3. `[writable]` bounty_claim: [BountyClaim] 
+/// 4. `[writable, signer]` recipient_signer: [AccountInfo] The hunter's wallet address
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - evidence_hash: [[u8; 32]] Hash of the hunter's submission
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn submit_bounty_claim(ctx: Context<SubmitBountyClaim>, _bounty_id: u64, evidence_hash: [u8; 32], _treasury_seed_name: String) -> Result<()> {
+		submit_bounty_claim::handler(ctx, evidence_hash)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[writable]` recipient: [Recipient] The hunter whose claim is accepted
+/// 3. `[]` bounty_claim: [BountyClaim] The accepted claim
+/// 4. `[writable]` payout_address: [AccountInfo] The hunter's bound payout address
+/// 5. `[writable]` policy: [Policy] The treasury's outflow policy for SOL, if set
+/// 6. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission
+/// 7. `[]` authority_member: [Member] (optional) Membership of the reviewer
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn accept_sol_bounty_claim(ctx: Context<AcceptSolBountyClaim>, _bounty_id: u64, _treasury_seed_name: String) -> Result<()> {
+		accept_sol_bounty_claim::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[writable]` recipient: [Recipient] The hunter whose claim is accepted
+/// 3. `[]` bounty_claim: [BountyClaim] The accepted claim
+/// 4. `[]` token_vault: [TokenVault] 
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` source: [AccountInfo] The source account.
+/// 7. `[writable]` destination: [AccountInfo] The hunter's bound payout token account.
+/// 8. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 10. `[signer]` reviewer: [AccountInfo] Must hold the execute payout permission
+/// 11. `[]` authority_member: [Member] (optional) Membership of the reviewer
+/// 12. `[]` payout_destination: [PayoutDestination] The hunter's token account override for this mint, if registered
+/// 13. `[writable]` policy: [Policy] The treasury's outflow policy for this mint, if set
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn accept_token_bounty_claim(ctx: Context<AcceptTokenBountyClaim>, _bounty_id: u64, _treasury_seed_name: String) -> Result<()> {
+		accept_token_bounty_claim::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[writable]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` bounty: [Bounty] 
+/// 2. `[writable]` token_vault: [TokenVault] (optional) The vault of the reward's mint, required for token bounties
+///
+/// Data:
+/// - bounty_id: [u64] Unique identifier for this bounty
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn expire_bounty(ctx: Context<ExpireBounty>, _bounty_id: u64, _treasury_seed_name: String) -> Result<()> {
+		expire_bounty::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` governance: [GovernanceConfig] 
+/// 2. `[]` vault_token_account: [TokenAccount] Token account of the governance mint that holds deposited voting tokens
+/// 3. `[writable, signer]` authority: [AccountInfo] Must hold the config permission
+/// 4. `[]` authority_member: [Member] (optional) Membership of the authority
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 6. `[writable]` approval_request: [ApprovalRequest] (optional) Co-signed by a delegate, required while governance requires council config co-signing
+///
+/// Data:
+/// - governance_mint: [Option<Pubkey>] Mint whose deposited tokens carry voting weight (null for the token gate mint)
+/// - quorum: [u64] Least total weight a proposal's votes must reach
+/// - threshold_bps: [u16] Share of the weight cast that must vote yes, in basis points
+/// - voting_period_seconds: [u64] How long proposals take votes
+/// - is_enabled: [bool] Whether deposits and new proposals are taken
+/// - delegate_threshold: [u64] Least delegated weight a delegate needs to co-sign requests (0 turns co-signing off)
+/// - require_council_config: [bool] Whether config changes need a request co-signed by a delegate
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn set_governance(ctx: Context<SetGovernance>, governance_mint: Option<Pubkey>, quorum: u64, threshold_bps: u16, voting_period_seconds: u64, is_enabled: bool, delegate_threshold: u64, require_council_config: bool, _treasury_seed_name: String) -> Result<()> {
+		set_governance::handler(ctx, governance_mint, quorum, threshold_bps, voting_period_seconds, is_enabled, delegate_threshold, require_council_config)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` governance: [GovernanceConfig] 
+/// 2. `[writable]` voter_record: [VoterRecord] 
+/// 3. `[writable, signer]` voter: [AccountInfo] 
+/// 4. `[writable]` source: [AccountInfo] The source account.
+/// 5. `[writable]` destination: [AccountInfo] The governance vault.
+/// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - amount: [u64] Amount of governance tokens to deposit
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn deposit_governance_tokens(ctx: Context<DepositGovernanceTokens>, amount: u64, _treasury_seed_name: String) -> Result<()> {
+		deposit_governance_tokens::handler(ctx, amount)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` governance: [GovernanceConfig] 
+/// 2. `[writable]` voter_record: [VoterRecord] 
+/// 3. `[signer]` voter: [AccountInfo] 
+/// 4. `[writable]` source: [AccountInfo] The governance vault.
+/// 5. `[writable]` destination: [AccountInfo] The destination account.
+/// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///
+/// Data:
+/// - amount: [u64] Amount of governance tokens to withdraw
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn withdraw_governance_tokens(ctx: Context<WithdrawGovernanceTokens>, amount: u64, _treasury_seed_name: String) -> Result<()> {
+		withdraw_governance_tokens::handler(ctx, amount)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` governance: [GovernanceConfig] 
+/// 2. `[]` voter_record: [VoterRecord] Deposits of the proposer
+/// 3. `[writable]` approval_request: [ApprovalRequest] Describes the action voted on
+/// 4. `[writable]` proposal: [SpendingProposal] 
+/// 5. `[writable, signer]` proposer: [AccountInfo] Must have deposited governance tokens
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for the proposal's approval request
+/// - action: [ApprovalAction] The spending action to vote on
+/// - amount: [u64] Amount the action moves, as passed to it
+/// - token_mint: [Option<Pubkey>] Asset the action moves (null for SOL)
+/// - terms_hash: [[u8; 32]] `args_hash` of the instruction call performing the action, binding every argument
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn create_spending_proposal(ctx: Context<CreateSpendingProposal>, request_id: u64, action: ApprovalAction, amount: u64, token_mint: Option<Pubkey>, terms_hash: [u8; 32], _treasury_seed_name: String) -> Result<()> {
+		create_spending_proposal::handler(ctx, request_id, action, amount, token_mint, terms_hash)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` approval_request: [ApprovalRequest] 
+/// 2. `[writable]` proposal: [SpendingProposal] 
+/// 3. `[writable]` voter_record: [VoterRecord] Deposits of the voter, locked until voting ends
+/// 4. `[writable]` vote: [Vote] 
+/// 5. `[writable, signer]` voter: [AccountInfo] Must have deposited governance tokens
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for the proposal's approval request
+/// - approve: [bool] Whether to vote for the proposal
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn cast_vote(ctx: Context<CastVote>, request_id: u64, approve: bool, _treasury_seed_name: String) -> Result<()> {
+		cast_vote::handler(ctx, request_id, approve)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` approval_request: [ApprovalRequest] 
+/// 2. `[writable]` proposal: [SpendingProposal] 
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for the proposal's approval request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn finalize_proposal(ctx: Context<FinalizeProposal>, request_id: u64, _treasury_seed_name: String) -> Result<()> {
+		finalize_proposal::handler(ctx, request_id)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` governance: [GovernanceConfig] 
+/// 2. `[writable]` voter_record: [VoterRecord] 
+/// 3. `[writable]` delegation: [Delegation] 
+/// 4. `[writable, signer]` voter: [AccountInfo] Must have deposited governance tokens
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - delegate: [Pubkey] Council wallet receiving the voter's weight
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn delegate_votes(ctx: Context<DelegateVotes>, delegate: Pubkey, _treasury_seed_name: String) -> Result<()> {
+		delegate_votes::handler(ctx, delegate)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[writable]` voter_record: [VoterRecord] 
+/// 2. `[writable]` delegation: [Delegation] Closed to the voter
+/// 3. `[writable, signer]` voter: [AccountInfo] 
+///
+/// Data:
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn revoke_delegation(ctx: Context<RevokeDelegation>, _treasury_seed_name: String) -> Result<()> {
+		revoke_delegation::handler(ctx)
+	}
+
+/// Accounts:
+/// 0. `[]` treasury: [TreasuryConfig] 
+/// 1. `[]` governance: [GovernanceConfig] 
+/// 2. `[writable]` approval_request: [ApprovalRequest] 
+/// 3. `[signer]` delegate: [AccountInfo] Council wallet holding delegated weight
+/// 4. `[]` remaining_accounts: the [Delegation] accounts delegated to the delegate, each at most once
+///
+/// Data:
+/// - request_id: [u64] Unique identifier for this request
+/// - treasury_seed_name: [String] Auto-generated, from the input "treasury" for the its seed definition "Treasury", sets the seed named "name"
+	pub fn cosign_request<'info>(ctx: Context<'_, '_, 'info, 'info, CosignRequest<'info>>, request_id: u64, _treasury_seed_name: String) -> Result<()> {
+		cosign_request::handler(ctx, request_id)
+	}
+
 
 
 }
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ApprovalPolicy {
+	pub treasury: Pubkey,
+	pub token_mint: Option<Pubkey>, // Asset the tiers apply to (None for SOL)
+	pub tiers: Vec<ApprovalTier>,   // Ascending by `up_to`; amounts above the last tier are refused
+	pub bump: u8,
+}
+
+/// What moving an amount up to `up_to` requires beyond the caller's own permission
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ApprovalTier {
+	pub up_to: u64,
+	pub require_admin: bool, // The admin must be one of the approvers
+	pub approvals: u8,       // Distinct approvers needed
+	pub delay_seconds: u64,  // Wait after the last approval before the request can be used
+}
+
+impl ApprovalTier {
+	/// Check if the tier needs an approval request at all
+	pub fn needs_approval(&self) -> bool {
+		self.require_admin || self.approvals > 0 || self.delay_seconds > 0
+	}
+}
+
+impl ApprovalPolicy {
+	/// Check the tiers ascend and each fits in an approval request
+	pub fn validate_tiers(tiers: &[ApprovalTier]) -> Result<()> {
+		if tiers.len() > crate::MAX_APPROVAL_TIERS {
+			return Err(crate::error::ErrorCode::InvalidApprovalPolicy.into());
+		}
+
+		for (index, tier) in tiers.iter().enumerate() {
+			if tier.approvals as usize > crate::MAX_REQUEST_APPROVERS
+				|| (index > 0 && tier.up_to <= tiers[index - 1].up_to)
+			{
+				return Err(crate::error::ErrorCode::InvalidApprovalPolicy.into());
+			}
+		}
+
+		Ok(())
+	}
+
+	/// The tier covering `amount`, or `None` if the policy has no tiers
+	pub fn tier_for(&self, amount: u64) -> Result<Option<ApprovalTier>> {
+		if self.tiers.is_empty() {
+			return Ok(None);
+		}
+
+		self.tiers
+			.iter()
+			.find(|tier| amount <= tier.up_to)
+			.map(|tier| Some(*tier))
+			.ok_or(crate::error::ErrorCode::AmountAboveApprovalTiers.into())
+	}
+
+	/// The tier covering a total `commitment`, with `None` (unbounded) taking the top tier
+	pub fn tier_for_commitment(&self, commitment: Option<u64>) -> Result<Option<ApprovalTier>> {
+		match commitment {
+			Some(commitment) => self.tier_for(commitment),
+			None => Ok(self.tiers.last().copied()),
+		}
+	}
+}
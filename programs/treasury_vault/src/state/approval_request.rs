@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_REQUEST_APPROVERS;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ApprovalRequest {
+	pub treasury: Pubkey,
+	pub request_id: u64,
+	pub proposer: Pubkey,
+	pub action: ApprovalAction,
+	pub amount: u64,
+	pub token_mint: Option<Pubkey>, // None for SOL
+	#[max_len(MAX_REQUEST_APPROVERS)]
+	pub approvers: Vec<Pubkey>,
+	pub admin_approved: bool,
+	pub created_at: i64,
+	pub last_approved_at: i64,
+	pub is_executed: bool, // Spent by the action it approved
+	pub bump: u8,
+	pub vote_passed: bool, // Passed a token-holder vote, which authorizes the action outright
+	pub council_weight: u64, // Delegated weight of the delegates that co-signed, as of the request's creation
+	pub terms_hash: [u8; 32], // `args_hash` of the call a passed vote authorizes (zero for other requests)
+	pub expires_at: i64, // Neither approved nor spent from this time on
+}
+
+/// The action an approval request authorizes
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ApprovalAction {
+	/// Create the payout schedule `schedule_id` for the `recipient` account
+	CreatePayoutSchedule { recipient: Pubkey, schedule_id: u64 },
+	/// Create the stream `stream_id` for the `recipient` account
+	CreateStreamingSchedule { recipient: Pubkey, stream_id: u64 },
+	/// Withdraw SOL in an emergency
+	EmergencyWithdrawSol,
+	/// Withdraw tokens in an emergency
+	EmergencyWithdrawToken,
+	/// Approve and pay part or all of the `payment_request` account
+	PayPaymentRequest { payment_request: Pubkey },
+	/// Create the milestone grant `grant_id` for the `recipient` account, escrowing its total
+	CreateMilestoneGrant { recipient: Pubkey, grant_id: u64 },
+	/// Create the escrow `escrow_id` holding funds for the `recipient` account
+	CreateEscrow { recipient: Pubkey, escrow_id: u64 },
+	/// Post the bounty `bounty_id`, reserving its reward
+	PostBounty { bounty_id: u64 },
+	/// Make the configuration change whose arguments hash to `config_hash`
+	ChangeConfig { config_hash: [u8; 32] },
+}
+
+impl ApprovalAction {
+	/// The permission needed to propose and finally perform the action
+	pub fn permission(&self) -> u16 {
+		match self {
+			ApprovalAction::CreatePayoutSchedule { .. }
+			| ApprovalAction::CreateStreamingSchedule { .. }
+			| ApprovalAction::CreateMilestoneGrant { .. }
+			| ApprovalAction::CreateEscrow { .. }
+			| ApprovalAction::PostBounty { .. } => {
+				crate::PERMISSION_CREATE_SCHEDULE
+			},
+			ApprovalAction::EmergencyWithdrawSol | ApprovalAction::EmergencyWithdrawToken => crate::PERMISSION_EMERGENCY,
+			ApprovalAction::PayPaymentRequest { .. } => crate::PERMISSION_EXECUTE_PAYOUT,
+			ApprovalAction::ChangeConfig { .. } => crate::PERMISSION_CONFIG,
+		}
+	}
+
+	/// Check if token holders may put the action to a vote; emergency withdrawals
+	/// and config changes stay with the admin
+	pub fn is_votable(&self) -> bool {
+		!matches!(
+			self,
+			ApprovalAction::EmergencyWithdrawSol | ApprovalAction::EmergencyWithdrawToken | ApprovalAction::ChangeConfig { .. }
+		)
+	}
+}
+
+impl ApprovalRequest {
+	/// Check if the request passed a token-holder vote authorizing `action` and is unspent
+	pub fn authorizes_by_vote(&self, treasury: &Pubkey, action: ApprovalAction) -> bool {
+		self.vote_passed && self.treasury == *treasury && self.action == action && !self.is_executed
+	}
+
+	/// Check the request has not expired by `current_time`
+	pub fn validate_unexpired(&self, current_time: i64) -> Result<()> {
+		if current_time >= self.expires_at {
+			return Err(crate::error::ErrorCode::ApprovalRequestExpired.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check if the request carries everything `tier` requires at `current_time`
+	pub fn validate_approved(&self, tier: &crate::ApprovalTier, current_time: i64) -> Result<()> {
+		self.validate_unexpired(current_time)?;
+
+		if (tier.require_admin && !self.admin_approved) || self.approvers.len() < tier.approvals as usize {
+			return Err(crate::error::ErrorCode::InsufficientApprovals.into());
+		}
+
+		let ready_at = self.created_at.max(self.last_approved_at).saturating_add(tier.delay_seconds as i64);
+		if current_time < ready_at {
+			return Err(crate::error::ErrorCode::ApprovalDelayPending.into());
+		}
+
+		Ok(())
+	}
+}
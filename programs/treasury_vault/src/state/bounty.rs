@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Bounty {
+	pub treasury: Pubkey,
+	pub bounty_id: u64,
+	pub token_mint: Option<Pubkey>,   // None for SOL
+	pub reward: u64,                  // Reserved out of the booked balance while the bounty is open
+	pub deadline: i64,                // Claims are taken until then; afterwards an unawarded bounty can expire
+	pub description_hash: [u8; 32],
+	pub status: BountyStatus,
+	pub claim_count: u32,
+	pub winner: Option<Pubkey>,       // Recipient account of the accepted claim
+	pub bump: u8,
+}
+
+/// A hunter's claim on a bounty
+#[account]
+pub struct BountyClaim {
+	pub bounty: Pubkey,
+	pub recipient: Pubkey,            // Recipient account of the hunter
+	pub evidence_hash: [u8; 32],
+	pub submitted_at: i64,
+	pub bump: u8,
+}
+
+/// Lifecycle of a bounty
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BountyStatus {
+	/// Taking claims; the reward is reserved
+	Open,
+	/// A claim was accepted and the reward paid
+	Awarded,
+	/// The deadline passed without an accepted claim and the reward was released
+	Expired,
+}
+
+impl Bounty {
+	/// Check the bounty takes claims at `current_time`
+	pub fn validate_claimable(&self, current_time: i64) -> Result<()> {
+		if self.status != BountyStatus::Open {
+			return Err(crate::error::ErrorCode::BountyClosed.into());
+		}
+		if current_time > self.deadline {
+			return Err(crate::error::ErrorCode::BountyDeadlinePassed.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check `claim` can be accepted: the bounty is still open and the claim
+	/// was submitted by the deadline
+	pub fn validate_acceptable(&self, claim: &BountyClaim) -> Result<()> {
+		if self.status != BountyStatus::Open {
+			return Err(crate::error::ErrorCode::BountyClosed.into());
+		}
+		if claim.submitted_at > self.deadline {
+			return Err(crate::error::ErrorCode::BountyDeadlinePassed.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check the bounty can expire at `current_time`
+	pub fn validate_expirable(&self, current_time: i64) -> Result<()> {
+		if self.status != BountyStatus::Open {
+			return Err(crate::error::ErrorCode::BountyClosed.into());
+		}
+		if current_time <= self.deadline {
+			return Err(crate::error::ErrorCode::BountyDeadlineNotPassed.into());
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Escrow {
+	pub treasury: Pubkey,
+	pub escrow_id: u64,
+	pub recipient: Pubkey,            // Recipient account of the vendor
+	pub token_mint: Option<Pubkey>,   // None for SOL
+	pub amount: u64,                  // Held in the treasury or its vault, outside the booked balance
+	pub arbitrator: Pubkey,           // Splits the amount once either party disputes
+	pub release_time: i64,            // Disputes are possible until then; afterwards the amount can be released
+	pub status: EscrowStatus,
+	pub disputed_by: Option<Pubkey>,
+	pub vendor_amount: u64,           // What the vendor received once the escrow is settled
+	pub bump: u8,
+}
+
+/// Lifecycle of an escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EscrowStatus {
+	/// Funds held, waiting for the release time or a dispute
+	Held,
+	/// A party disputed; only the arbitrator can settle it
+	Disputed,
+	/// The full amount went to the vendor
+	Released,
+	/// The arbitrator split the amount between the vendor and the treasury
+	Resolved,
+}
+
+impl Escrow {
+	/// Check the escrow still holds its funds
+	pub fn is_open(&self) -> bool {
+		matches!(self.status, EscrowStatus::Held | EscrowStatus::Disputed)
+	}
+
+	/// Check the escrow can be disputed at `current_time`
+	pub fn validate_disputable(&self, current_time: i64) -> Result<()> {
+		if self.status != EscrowStatus::Held {
+			return Err(crate::error::ErrorCode::InvalidEscrowStatus.into());
+		}
+		if current_time >= self.release_time {
+			return Err(crate::error::ErrorCode::DisputeWindowClosed.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check the escrow can be released to the vendor
+	///
+	/// Before its release time only the treasury side may release it early,
+	/// which the caller authorizes.
+	pub fn validate_releasable(&self) -> Result<()> {
+		if self.status != EscrowStatus::Held {
+			return Err(crate::error::ErrorCode::InvalidEscrowStatus.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check `arbitrator` may split `vendor_amount` of the disputed escrow to the vendor
+	pub fn validate_resolution(&self, arbitrator: &Pubkey, vendor_amount: u64) -> Result<()> {
+		if *arbitrator != self.arbitrator {
+			return Err(crate::error::ErrorCode::UnauthorizedAccess.into());
+		}
+		if self.status != EscrowStatus::Disputed {
+			return Err(crate::error::ErrorCode::InvalidEscrowStatus.into());
+		}
+		if vendor_amount > self.amount {
+			return Err(crate::error::ErrorCode::InvalidEscrow.into());
+		}
+
+		Ok(())
+	}
+}
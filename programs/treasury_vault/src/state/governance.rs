@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+/// Token-holder voting on spending proposals
+#[account]
+pub struct GovernanceConfig {
+	pub treasury: Pubkey,
+	pub governance_mint: Pubkey,        // Mint whose deposited tokens carry voting weight
+	pub vault_token_account: Pubkey,    // Holds the deposited tokens
+	pub quorum: u64,                    // Least total weight a proposal's votes must reach
+	pub threshold_bps: u16,             // Share of the weight cast that must vote yes
+	pub voting_period_seconds: u64,
+	pub total_deposited: u64,
+	pub is_enabled: bool,               // New deposits and proposals are only taken while enabled
+	pub bump: u8,
+	pub delegate_threshold: u64,        // Least delegated weight a delegate needs to co-sign requests (0 turns co-signing off)
+	pub require_council_config: bool,   // Config changes need a request co-signed by a delegate
+}
+
+/// A holder's deposited voting tokens
+#[account]
+pub struct VoterRecord {
+	pub treasury: Pubkey,
+	pub voter: Pubkey,
+	pub deposited: u64,                 // Voting weight
+	pub locked_until: i64,              // End of the latest vote cast; deposits cannot be withdrawn before then
+	pub bump: u8,
+	pub delegate: Option<Pubkey>,       // Wallet the voter's weight is delegated to
+}
+
+/// A holder's weight delegated to a council wallet, until revoked
+#[account]
+pub struct Delegation {
+	pub treasury: Pubkey,
+	pub voter: Pubkey,
+	pub delegate: Pubkey,
+	pub weight: u64,                    // The voter's deposits when delegating
+	pub delegated_at: i64,              // Only requests created after this count the weight
+	pub bump: u8,
+}
+
+/// Token-holder vote on the approval request it was created with
+#[account]
+pub struct SpendingProposal {
+	pub treasury: Pubkey,
+	pub approval_request: Pubkey,       // Describes the action, amount and asset voted on
+	pub proposer: Pubkey,
+	pub voting_ends_at: i64,
+	pub quorum: u64,                    // Taken from the governance config when proposed
+	pub threshold_bps: u16,             // Taken from the governance config when proposed
+	pub yes_weight: u64,
+	pub no_weight: u64,
+	pub status: ProposalStatus,
+	pub bump: u8,
+}
+
+/// A voter's ballot on a spending proposal
+#[account]
+pub struct Vote {
+	pub proposal: Pubkey,
+	pub voter: Pubkey,
+	pub weight: u64,
+	pub approve: bool,
+	pub bump: u8,
+}
+
+/// Lifecycle of a spending proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+	/// Taking votes until the voting period ends
+	Voting,
+	/// Reached quorum and threshold; its approval request authorizes the action
+	Passed,
+	/// Missed quorum or threshold
+	Defeated,
+}
+
+impl GovernanceConfig {
+	/// Check the quorum, threshold and voting period are in range
+	pub fn validate_parameters(quorum: u64, threshold_bps: u16, voting_period_seconds: u64) -> Result<()> {
+		if quorum == 0 || threshold_bps == 0 || threshold_bps > 10_000
+			|| voting_period_seconds == 0 || voting_period_seconds > i64::MAX as u64
+		{
+			return Err(crate::error::ErrorCode::InvalidGovernance.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check the treasury takes deposits and proposals
+	pub fn validate_enabled(&self) -> Result<()> {
+		if !self.is_enabled {
+			return Err(crate::error::ErrorCode::GovernanceDisabled.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl VoterRecord {
+	/// Check the voter's weight is not delegated
+	pub fn validate_undelegated(&self) -> Result<()> {
+		if self.delegate.is_some() {
+			return Err(crate::error::ErrorCode::VotesDelegated.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl SpendingProposal {
+	/// Check the proposal takes votes at `current_time`
+	pub fn validate_voting(&self, current_time: i64) -> Result<()> {
+		if self.status != ProposalStatus::Voting || current_time >= self.voting_ends_at {
+			return Err(crate::error::ErrorCode::VotingClosed.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check if the votes cast reach the quorum and the yes share reaches the threshold
+	pub fn has_passed(&self) -> bool {
+		let cast = self.yes_weight as u128 + self.no_weight as u128;
+		cast >= self.quorum as u128
+			&& self.yes_weight > 0
+			&& self.yes_weight as u128 * 10_000 >= cast * self.threshold_bps as u128
+	}
+}
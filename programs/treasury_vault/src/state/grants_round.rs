@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct GrantsRound {
+	pub treasury: Pubkey,
+	pub round_id: u64,
+	pub matching_pool: u64,      // Lamports held back from the booked balance for matching
+	pub start_time: i64,         // Contributions are accepted from then
+	pub end_time: i64,           // up to but excluding then
+	pub project_count: u32,
+	pub status: RoundStatus,
+	pub qf_total: u128,          // Sum of every project's quadratic funding score, once tallied
+	pub tally_cursor: u32,       // Index of the next project to tally
+	pub distribute_cursor: u32,  // Index of the next project to match
+	pub matched_total: u64,      // Paid to projects so far, at most the matching pool
+	pub deferred_count: u32,     // Cranked projects whose match waits on their recipient caps
+	pub bump: u8,
+}
+
+/// Lifecycle of a grants round
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundStatus {
+	/// Taking project registrations and contributions
+	Open,
+	/// Closed; project scores are being summed page by page
+	Tallying,
+	/// Tallied; matched amounts are being paid page by page
+	Distributing,
+	/// Every project paid and the unpaid rest of the pool returned
+	Finalized,
+}
+
+/// A recipient taking part in a grants round
+#[account]
+pub struct RoundProject {
+	pub grants_round: Pubkey,
+	pub recipient: Pubkey,       // Recipient account of the project
+	pub index: u32,              // Registration order, the order the round is cranked in
+	pub contributed: u64,        // Total contributed by the community
+	pub sqrt_sum: u64,           // Sum over contributors of the square root of their total contribution
+	pub contributor_count: u32,
+	pub matched_amount: u64,
+	pub match_settled: bool,     // The match was paid or forfeited, rather than deferred
+	pub bump: u8,
+}
+
+/// A community wallet's total contribution to a round project
+#[account]
+pub struct Contribution {
+	pub round_project: Pubkey,
+	pub contributor: Pubkey,
+	pub amount: u64,
+	pub bump: u8,
+}
+
+impl GrantsRound {
+	/// Check the round takes contributions at `current_time`
+	pub fn validate_contributable(&self, current_time: i64) -> Result<()> {
+		if self.status != RoundStatus::Open || current_time < self.start_time || current_time >= self.end_time {
+			return Err(crate::error::ErrorCode::RoundNotOpen.into());
+		}
+
+		Ok(())
+	}
+
+	/// Share of the matching pool owed to a project with quadratic funding score `qf_score`
+	pub fn match_for(&self, qf_score: u128) -> Result<u64> {
+		if self.qf_total == 0 {
+			return Ok(0);
+		}
+
+		let share = (self.matching_pool as u128)
+			.checked_mul(qf_score)
+			.ok_or(crate::error::ErrorCode::MatchOverflow)?
+			/ self.qf_total;
+
+		Ok(share as u64)
+	}
+
+	/// Check every project is cranked and no deferred match is left to pay
+	pub fn is_distributed(&self) -> bool {
+		self.distribute_cursor == self.project_count && self.deferred_count == 0
+	}
+}
+
+impl RoundProject {
+	/// Record `amount` more from a contributor who had given `previous` so far
+	pub fn record_contribution(&mut self, previous: u64, amount: u64) {
+		let total = previous + amount;
+		self.sqrt_sum = self.sqrt_sum - integer_sqrt(previous) + integer_sqrt(total);
+		self.contributed += amount;
+		if previous == 0 {
+			self.contributor_count += 1;
+		}
+	}
+
+	/// Quadratic funding score: the square of the summed square roots of
+	/// each contribution, less what was contributed
+	pub fn qf_score(&self) -> u128 {
+		(self.sqrt_sum as u128 * self.sqrt_sum as u128).saturating_sub(self.contributed as u128)
+	}
+}
+
+/// Largest integer whose square is at most `value`
+fn integer_sqrt(value: u64) -> u64 {
+	if value < 2 {
+		return value;
+	}
+
+	let mut root = value;
+	let mut next = root.div_ceil(2);
+	while next < root {
+		root = next;
+		next = (root + value / root) / 2;
+	}
+
+	root
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Member {
+    pub treasury: Pubkey,
+    pub member: Pubkey,
+    pub permissions: u16, // Bitmask of the PERMISSION_* constants
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl Member {
+    /// Check if the member holds every permission in `permissions`
+    pub fn has_permissions(&self, permissions: u16) -> bool {
+        self.permissions & permissions == permissions
+    }
+}
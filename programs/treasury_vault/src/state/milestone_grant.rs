@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct MilestoneGrant {
+	pub treasury: Pubkey,
+	pub grant_id: u64,
+	pub recipient: Pubkey,            // Recipient account of the grantee
+	pub token_mint: Option<Pubkey>,   // None for SOL
+	pub milestones: Vec<Milestone>,   // Worked through in order
+	pub bump: u8,
+}
+
+/// The terms of a milestone, as set when the grant is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MilestoneTerms {
+	pub amount: u64,
+	pub deadline: i64,          // Sign-off must be complete by then, or the funds can be reclaimed
+	pub approvers: Vec<Pubkey>,
+	pub approvals_required: u8,
+}
+
+/// A milestone of a grant and how far it has got
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Milestone {
+	pub amount: u64,
+	pub deadline: i64,
+	pub approvers: Vec<Pubkey>,
+	pub approvals_required: u8,
+	pub approvals: u8,            // Bitmask of approvers who signed off, bit i for approvers[i]
+	pub evidence_hash: [u8; 32],  // Hash of the grantee's latest evidence
+	pub status: MilestoneStatus,
+}
+
+/// Lifecycle of a milestone
+///
+/// Released and Reclaimed are final; the funds of every other milestone are held in escrow.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MilestoneStatus {
+	/// Waiting for the grantee's evidence
+	Pending,
+	/// Evidence submitted, waiting for approvers
+	Submitted,
+	/// Enough approvers signed off; the funds can be released
+	Approved,
+	/// The funds were paid to the grantee
+	Released,
+	/// The deadline passed without sign-off and the funds went back to the treasury
+	Reclaimed,
+}
+
+impl MilestoneStatus {
+	/// Check if the milestone's funds have left escrow
+	pub fn is_final(&self) -> bool {
+		matches!(self, MilestoneStatus::Released | MilestoneStatus::Reclaimed)
+	}
+}
+
+impl MilestoneTerms {
+	/// Check the milestone pays something and its approvers are distinct and enough for the threshold
+	pub fn validate(&self) -> Result<()> {
+		let approvers = &self.approvers;
+		if self.amount == 0
+			|| approvers.is_empty()
+			|| approvers.len() > crate::MAX_MILESTONE_APPROVERS
+			|| self.approvals_required == 0
+			|| self.approvals_required as usize > approvers.len()
+			|| approvers.iter().enumerate().any(|(index, approver)| approvers[..index].contains(approver))
+		{
+			return Err(crate::error::ErrorCode::InvalidMilestones.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl MilestoneGrant {
+	/// Check there are a few milestones, each valid, with deadlines ascending after `current_time`
+	pub fn validate_terms(terms: &[MilestoneTerms], current_time: i64) -> Result<()> {
+		if terms.is_empty() || terms.len() > crate::MAX_MILESTONES {
+			return Err(crate::error::ErrorCode::InvalidMilestones.into());
+		}
+
+		let mut previous_deadline = current_time;
+		for milestone in terms {
+			milestone.validate()?;
+			if milestone.deadline <= previous_deadline {
+				return Err(crate::error::ErrorCode::InvalidMilestones.into());
+			}
+			previous_deadline = milestone.deadline;
+		}
+
+		Ok(())
+	}
+
+	/// Sum of every milestone's amount
+	pub fn total_amount(&self) -> Result<u64> {
+		self.milestones
+			.iter()
+			.try_fold(0u64, |total, milestone| total.checked_add(milestone.amount))
+			.ok_or(crate::error::ErrorCode::InvalidMilestones.into())
+	}
+
+	/// Check if every milestone has been released or reclaimed
+	pub fn is_settled(&self) -> bool {
+		self.milestones.iter().all(|milestone| milestone.status.is_final())
+	}
+
+	/// The milestone at `index`, once every milestone before it is final
+	pub fn current_milestone(&mut self, index: u8) -> Result<&mut Milestone> {
+		let index = index as usize;
+		if index >= self.milestones.len() {
+			return Err(crate::error::ErrorCode::InvalidMilestones.into());
+		}
+		if !self.milestones[..index].iter().all(|milestone| milestone.status.is_final()) {
+			return Err(crate::error::ErrorCode::MilestoneOutOfOrder.into());
+		}
+
+		Ok(&mut self.milestones[index])
+	}
+
+	/// The milestone at `index` if its deadline passed before it was approved
+	pub fn reclaimable_milestone(&mut self, index: u8, current_time: i64) -> Result<&mut Milestone> {
+		let milestone = self.milestones
+			.get_mut(index as usize)
+			.ok_or(crate::error::ErrorCode::InvalidMilestones)?;
+		if !matches!(milestone.status, MilestoneStatus::Pending | MilestoneStatus::Submitted) {
+			return Err(crate::error::ErrorCode::InvalidMilestoneStatus.into());
+		}
+		if current_time <= milestone.deadline {
+			return Err(crate::error::ErrorCode::MilestoneDeadlineNotPassed.into());
+		}
+
+		Ok(milestone)
+	}
+}
+
+impl Milestone {
+	/// Check the milestone is still open for evidence or sign-off at `current_time`
+	pub fn validate_open(&self, current_time: i64) -> Result<()> {
+		if current_time > self.deadline {
+			return Err(crate::error::ErrorCode::MilestoneDeadlinePassed.into());
+		}
+
+		Ok(())
+	}
+
+	/// Record `approver`'s sign-off, approving the milestone once enough have signed
+	pub fn approve(&mut self, approver: &Pubkey) -> Result<()> {
+		let index = self.approvers
+			.iter()
+			.position(|candidate| candidate == approver)
+			.ok_or(crate::error::ErrorCode::UnauthorizedAccess)?;
+		if self.approvals & (1 << index) != 0 {
+			return Err(crate::error::ErrorCode::AlreadyApproved.into());
+		}
+
+		self.approvals |= 1 << index;
+		if self.approvals.count_ones() >= self.approvals_required as u32 {
+			self.status = MilestoneStatus::Approved;
+		}
+
+		Ok(())
+	}
+}
+
+impl From<MilestoneTerms> for Milestone {
+	fn from(terms: MilestoneTerms) -> Self {
+		Milestone {
+			amount: terms.amount,
+			deadline: terms.deadline,
+			approvers: terms.approvers,
+			approvals_required: terms.approvals_required,
+			approvals: 0,
+			evidence_hash: [0u8; 32],
+			status: MilestoneStatus::Pending,
+		}
+	}
+}
@@ -6,9 +6,33 @@ pub mod recipient;
 pub mod payout_schedule;
 pub mod token_vault;
 pub mod streaming_schedule;
+pub mod member;
+pub mod payout_destination;
+pub mod approval_policy;
+pub mod approval_request;
+pub mod policy;
+pub mod payout_queue;
+pub mod payment_request;
+pub mod milestone_grant;
+pub mod escrow;
+pub mod grants_round;
+pub mod bounty;
+pub mod governance;
 
 pub use treasury_config::*;
 pub use recipient::*;
 pub use payout_schedule::*;
 pub use token_vault::*;
-pub use streaming_schedule::*;
\ No newline at end of file
+pub use streaming_schedule::*;
+pub use member::*;
+pub use payout_destination::*;
+pub use approval_policy::*;
+pub use approval_request::*;
+pub use policy::*;
+pub use payout_queue::*;
+pub use payment_request::*;
+pub use milestone_grant::*;
+pub use escrow::*;
+pub use grants_round::*;
+pub use bounty::*;
+pub use governance::*;
\ No newline at end of file
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PaymentRequest {
+	pub treasury: Pubkey,
+	pub recipient: Pubkey,          // Recipient account that submitted the request
+	pub request_id: u64,
+	pub amount: u64,                // Invoiced amount, in lamports or raw token units
+	pub token_mint: Option<Pubkey>, // None for SOL
+	pub due_date: i64,
+	pub invoice_hash: [u8; 32],     // Hash of the invoice document
+	pub invoice_uri: String,        // Where the invoice can be found
+	pub status: PaymentRequestStatus,
+	pub paid_amount: u64,
+	pub history: Vec<PaymentRequestEvent>, // Every status change, oldest first
+	pub bump: u8,
+}
+
+/// Lifecycle of a payment request
+///
+/// Pending and PartiallyPaid requests are open to review; Paid and Rejected are final.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PaymentRequestStatus {
+	/// Submitted and not reviewed yet
+	Pending,
+	/// Part of the amount has been approved and paid
+	PartiallyPaid,
+	/// The whole amount has been paid
+	Paid,
+	/// The unpaid rest of the amount was rejected
+	Rejected,
+}
+
+/// A status change of a payment request
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PaymentRequestEvent {
+	pub status: PaymentRequestStatus,
+	pub actor: Pubkey, // Who submitted, approved or rejected
+	pub amount: u64,   // Amount requested, paid or rejected
+	pub timestamp: i64,
+}
+
+impl PaymentRequestStatus {
+	/// Check if the request can still be reviewed
+	pub fn is_open(&self) -> bool {
+		matches!(self, PaymentRequestStatus::Pending | PaymentRequestStatus::PartiallyPaid)
+	}
+}
+
+impl PaymentRequest {
+	/// Amount neither paid nor rejected yet
+	pub fn remaining(&self) -> u64 {
+		self.amount - self.paid_amount
+	}
+
+	/// Check the request is open to a review that settles it
+	pub fn validate_reviewable(&self) -> Result<()> {
+		if !self.status.is_open() {
+			return Err(crate::error::ErrorCode::PaymentRequestClosed.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check `amount` can be approved, being part or all of what remains
+	///
+	/// Partial approvals stop one event short of the history cap, so the
+	/// final payment or rejection always has room to be recorded.
+	pub fn validate_approval(&self, amount: u64) -> Result<()> {
+		self.validate_reviewable()?;
+
+		if amount == 0 || amount > self.remaining() {
+			return Err(crate::error::ErrorCode::InvalidPaymentRequest.into());
+		}
+
+		if amount < self.remaining() && self.history.len() + 1 >= crate::MAX_PAYMENT_REQUEST_EVENTS {
+			return Err(crate::error::ErrorCode::PaymentRequestClosed.into());
+		}
+
+		Ok(())
+	}
+
+	/// Move to `status`, recording who did it and for how much
+	pub fn record(&mut self, status: PaymentRequestStatus, actor: Pubkey, amount: u64, timestamp: i64) {
+		self.status = status;
+		self.history.push(PaymentRequestEvent { status, actor, amount, timestamp });
+	}
+
+	/// Record a payment of `amount`, settling the request once nothing remains
+	pub fn record_payment(&mut self, actor: Pubkey, amount: u64, timestamp: i64) {
+		self.paid_amount += amount;
+		let status = if self.remaining() == 0 {
+			PaymentRequestStatus::Paid
+		} else {
+			PaymentRequestStatus::PartiallyPaid
+		};
+		self.record(status, actor, amount, timestamp);
+	}
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PayoutDestination {
+	pub recipient: Pubkey,
+	pub token_mint: Pubkey,
+	pub token_account: Pubkey, // Receives token payouts of this mint instead of the payout address's ATA
+	pub updated_at: i64,
+	pub bump: u8,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PendingPayoutQueue {
+	pub treasury: Pubkey,
+	pub entries: Vec<PendingPayout>, // Highest priority first, oldest first within a priority
+	pub next_sequence: u64,
+	pub bump: u8,
+}
+
+/// A due SOL payout held back by the treasury's spending limits
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PendingPayout {
+	pub sequence: u64, // Order of enqueueing across the queue's lifetime
+	pub recipient: Pubkey,
+	pub payout_schedule: Pubkey,
+	pub amount: u64,
+	pub priority: u8,
+	pub enqueued_at: i64,
+}
+
+impl PendingPayoutQueue {
+	/// Add a payout behind every entry of the same or higher priority
+	pub fn enqueue(
+		&mut self,
+		recipient: Pubkey,
+		payout_schedule: Pubkey,
+		amount: u64,
+		priority: u8,
+		enqueued_at: i64,
+	) -> Result<()> {
+		if self.entries.len() >= crate::MAX_QUEUED_PAYOUTS {
+			return Err(crate::error::ErrorCode::PayoutQueueFull.into());
+		}
+
+		let position = self.entries
+			.iter()
+			.position(|entry| entry.priority < priority)
+			.unwrap_or(self.entries.len());
+		self.entries.insert(position, PendingPayout {
+			sequence: self.next_sequence,
+			recipient,
+			payout_schedule,
+			amount,
+			priority,
+			enqueued_at,
+		});
+		self.next_sequence += 1;
+
+		Ok(())
+	}
+
+	/// Take the entry enqueued as `sequence` out of the queue
+	pub fn remove(&mut self, sequence: u64) -> Result<PendingPayout> {
+		let position = self.entries
+			.iter()
+			.position(|entry| entry.sequence == sequence)
+			.ok_or(crate::error::ErrorCode::InvalidQueuedPayout)?;
+
+		Ok(self.entries.remove(position))
+	}
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+const SECONDS_PER_DAY: i64 = 86_400;
+
 #[account]
 pub struct PayoutSchedule {
     pub treasury: Pubkey,
@@ -11,7 +13,346 @@ pub struct PayoutSchedule {
     pub interval_seconds: u64,
     pub executions: u64,
     pub max_executions: u64,
-    pub is_active: bool,
+    pub status: ScheduleStatus,
     pub last_execution_time: i64,
     pub bump: u8,
-}
\ No newline at end of file
+    pub is_paused: bool, // Paused while the recipient is deactivated
+    pub recurrence: Recurrence,
+    pub end_time: i64, // No payouts fall due after this time (0 for no end)
+    pub cancelled_by: Option<Pubkey>,
+    pub cancelled_at: i64,
+    pub cancel_reason: String,
+    pub splits: Vec<PayoutSplit>, // Shares paid to other recipients; the rest goes to this schedule's recipient
+    pub oracle_pricing: Option<OraclePricing>, // When set, `amount` is in USD and converted at execution
+    pub priority: u8, // Payouts queued over the spending limit drain highest priority first
+    pub claim_window_seconds: u64, // When set, the recipient claims each payout within this long of it falling due (0 pushes payouts through execution)
+}
+
+/// Whether a payout schedule can be executed, as returned by `check_payout_due`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PayoutDue {
+    pub due_periods: u64, // Unpaid periods an execution or claim now would cover (0 if not due)
+    pub next_due_time: Option<i64>, // When the next unpaid period falls due (None once closed or finished)
+    pub keeper_tip: u64, // Tip an execution now would pay its fee payer, funds permitting
+}
+
+/// Prices a schedule's USD `amount` in the paid asset through a price feed
+///
+/// `amount` is then counted in units of 10^-`USD_DECIMALS` dollars. The feed
+/// is a Pyth-style price account quoting the paid asset in USD.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OraclePricing {
+    pub price_feed: Pubkey,
+    pub max_staleness_seconds: u64, // Oldest publish time accepted, relative to execution
+    pub max_confidence_bps: u16, // Widest confidence interval accepted, relative to the price
+    pub max_slippage_bps: u16, // Furthest the price may stray from the feed's moving average
+}
+
+impl OraclePricing {
+    /// Check the thresholds are in range
+    pub fn validate(&self) -> Result<()> {
+        if self.max_staleness_seconds == 0
+            || self.max_confidence_bps > 10_000
+            || self.max_slippage_bps > 10_000
+        {
+            return Err(crate::error::ErrorCode::InvalidOraclePricing.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// A share of every payout that goes to another recipient
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PayoutSplit {
+    pub recipient: Pubkey, // Recipient account of the payee
+    pub share_bps: u16,
+}
+
+/// Lifecycle of a payout schedule
+///
+/// Pending, Active and Paused schedules are open and count towards the
+/// treasury's and recipient's open schedules; Completed and Cancelled are final.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScheduleStatus {
+    /// No payout has been made yet
+    Pending,
+    /// At least one payout has been made
+    Active,
+    /// Paused by an update until resumed
+    Paused,
+    /// Every payout has been made
+    Completed,
+    /// Cancelled before every payout was made
+    Cancelled,
+}
+
+impl ScheduleStatus {
+    /// Check if the schedule has not reached a final state
+    pub fn is_open(&self) -> bool {
+        matches!(self, ScheduleStatus::Pending | ScheduleStatus::Active | ScheduleStatus::Paused)
+    }
+}
+
+/// When a payout schedule falls due, anchored at `start_time`
+///
+/// Calendar rules pay at the UTC time of day of `start_time`, on the first
+/// matching date at or after it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Recurrence {
+    /// Every `interval_seconds` (0 pays whenever executed)
+    Interval,
+    /// On day `day` of every month, or the month's last day if it is shorter
+    MonthlyOnDay { day: u8 },
+    /// On `weekday` (0 = Monday .. 6 = Sunday) every `every_weeks` weeks
+    Weekly { weekday: u8, every_weeks: u8 },
+    /// On the last Monday to Friday of every month
+    LastBusinessDayOfMonth,
+}
+
+impl Recurrence {
+    /// Check the rule's parameters are in range
+    pub fn validate(&self) -> Result<()> {
+        let valid = match *self {
+            Recurrence::Interval | Recurrence::LastBusinessDayOfMonth => true,
+            Recurrence::MonthlyOnDay { day } => (1..=31).contains(&day),
+            Recurrence::Weekly { weekday, every_weeks } => weekday <= 6 && every_weeks > 0,
+        };
+
+        if !valid {
+            return Err(crate::error::ErrorCode::InvalidRecurrence.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl PayoutSchedule {
+    /// When the payout for `period` (0 for the first) falls due
+    ///
+    /// Computed from `start_time` alone so late executions do not shift
+    /// later payouts. Returns `None` if the time overflows.
+    pub fn due_time(&self, period: u64) -> Option<i64> {
+        let start_day = self.start_time.div_euclid(SECONDS_PER_DAY);
+        let time_of_day = self.start_time.rem_euclid(SECONDS_PER_DAY);
+        let period = i64::try_from(period).ok()?;
+
+        let due_day = match self.recurrence {
+            Recurrence::Interval => {
+                let interval = i64::try_from(self.interval_seconds).ok()?;
+                return self.start_time.checked_add(interval.checked_mul(period)?);
+            },
+            Recurrence::Weekly { weekday, every_weeks } => {
+                let first_day = start_day + (weekday as i64 - weekday_of(start_day)).rem_euclid(7);
+                first_day.checked_add(period.checked_mul(7 * every_weeks as i64)?)?
+            },
+            Recurrence::MonthlyOnDay { .. } | Recurrence::LastBusinessDayOfMonth => {
+                let (year, month, _) = civil_from_days(start_day);
+                let first_month = year.checked_mul(12)? + month as i64 - 1;
+                // Skip the start month if its date has already passed
+                let skip = (self.monthly_due_day(first_month) * SECONDS_PER_DAY + time_of_day < self.start_time) as i64;
+                self.monthly_due_day(first_month.checked_add(period)?.checked_add(skip)?)
+            },
+        };
+
+        due_day.checked_mul(SECONDS_PER_DAY)?.checked_add(time_of_day)
+    }
+
+    /// When the next payout falls due
+    pub fn next_due_time(&self) -> Option<i64> {
+        self.due_time(self.executions)
+    }
+
+    /// Check the splits name distinct other recipients of the same treasury
+    /// and their shares add up to at most 100%
+    pub fn validate_splits(&self, splits: &[PayoutSplit]) -> Result<()> {
+        if splits.len() > crate::MAX_PAYOUT_SPLITS {
+            return Err(crate::error::ErrorCode::InvalidPayoutSplit.into());
+        }
+
+        let mut total_bps: u32 = 0;
+        for (index, split) in splits.iter().enumerate() {
+            if split.share_bps == 0
+                || split.recipient == self.recipient
+                || splits[..index].iter().any(|other| other.recipient == split.recipient)
+            {
+                return Err(crate::error::ErrorCode::InvalidPayoutSplit.into());
+            }
+            total_bps += split.share_bps as u32;
+        }
+
+        if total_bps > 10_000 {
+            return Err(crate::error::ErrorCode::InvalidPayoutSplit.into());
+        }
+
+        Ok(())
+    }
+
+    /// Total the schedule can pay out over its lifetime, `None` when unbounded
+    pub fn commitment(&self) -> Option<u64> {
+        match self.max_executions {
+            0 => None,
+            max_executions => Some(self.amount.saturating_mul(max_executions)),
+        }
+    }
+
+    /// Check if a change from `previous` lets the schedule pay out more in total
+    pub fn raises_commitment(&self, previous: Option<u64>) -> bool {
+        match (self.commitment(), previous) {
+            (None, previous) => previous.is_some(),
+            (Some(_), None) => false,
+            (Some(commitment), Some(previous)) => commitment > previous,
+        }
+    }
+
+    /// Divide `amount` into each split's share, rounded down, and the
+    /// remainder for this schedule's recipient, which also takes the rounding dust
+    pub fn split_amount(&self, amount: u64) -> (Vec<u64>, u64) {
+        let shares: Vec<u64> = self.splits
+            .iter()
+            .map(|split| (amount as u128 * split.share_bps as u128 / 10_000) as u64)
+            .collect();
+        let remainder = amount - shares.iter().sum::<u64>();
+        (shares, remainder)
+    }
+
+    /// Check if the recipient claims payouts instead of having them executed
+    pub fn is_claim_mode(&self) -> bool {
+        self.claim_window_seconds > 0
+    }
+
+    /// Check claim mode is only used on schedules without splits whose
+    /// periods fall due at distinct times
+    pub fn validate_claim_mode(&self) -> Result<()> {
+        if !self.is_claim_mode() {
+            return Ok(());
+        }
+
+        if !self.splits.is_empty()
+            || self.claim_window_seconds > i64::MAX as u64
+            || (self.recurrence == Recurrence::Interval && self.interval_seconds == 0)
+        {
+            return Err(crate::error::ErrorCode::InvalidClaimWindow.into());
+        }
+
+        Ok(())
+    }
+
+    /// Number of unpaid periods whose claim window closed before `now`
+    pub fn expired_claim_periods(&self, now: i64) -> u64 {
+        if !self.is_claim_mode() {
+            return 0;
+        }
+
+        self.due_periods(now.saturating_sub(self.claim_window_seconds as i64).saturating_sub(1))
+    }
+
+    /// Check if payouts can be executed, neither paused nor finished
+    pub fn is_payable(&self) -> bool {
+        matches!(self.status, ScheduleStatus::Pending | ScheduleStatus::Active) && !self.is_paused
+    }
+
+    /// Check if no further payout can fall due
+    pub fn is_finished(&self) -> bool {
+        if self.max_executions > 0 && self.executions >= self.max_executions {
+            return true;
+        }
+
+        self.end_time > 0 && self.next_due_time().is_none_or(|due_time| due_time > self.end_time)
+    }
+
+    /// Number of unpaid periods due at or before `now` and `end_time`,
+    /// up to `max_executions`
+    pub fn due_periods(&self, now: i64) -> u64 {
+        let now = if self.end_time > 0 { now.min(self.end_time) } else { now };
+
+        let mut limit = if self.max_executions > 0 {
+            self.max_executions.saturating_sub(self.executions)
+        } else {
+            u64::MAX - self.executions
+        };
+
+        // Without an interval every period shares the start time, so pay one per execution
+        if self.recurrence == Recurrence::Interval && self.interval_seconds == 0 {
+            limit = limit.min(1);
+        }
+
+        // Due times only grow, so gallop past the first period not yet due
+        // (keeping calendar math near `now`) and then search back for it
+        let mut high = 1u64.min(limit);
+        while high < limit && self.due_time(self.executions + high - 1).is_some_and(|due_time| due_time <= now) {
+            high = high.saturating_mul(2).min(limit);
+        }
+
+        let mut low = high / 2;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.due_time(self.executions + mid) {
+                Some(due_time) if due_time <= now => low = mid + 1,
+                _ => high = mid,
+            }
+        }
+
+        low
+    }
+
+    /// Day number of the due date in `month`, counted as months since year 0
+    fn monthly_due_day(&self, month: i64) -> i64 {
+        let year = month.div_euclid(12);
+        let month = (month.rem_euclid(12) + 1) as u32;
+        let last_day = days_in_month(year, month);
+
+        match self.recurrence {
+            Recurrence::MonthlyOnDay { day } => days_from_civil(year, month, (day as u32).min(last_day)),
+            _ => {
+                let last = days_from_civil(year, month, last_day);
+                // Step back from Saturday or Sunday to Friday
+                last - (weekday_of(last) - 4).max(0)
+            },
+        }
+    }
+}
+
+/// Day of the week for a day number, 0 = Monday .. 6 = Sunday
+fn weekday_of(days: i64) -> i64 {
+    // 1970-01-01 was a Thursday
+    (days + 3).rem_euclid(7)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date for days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
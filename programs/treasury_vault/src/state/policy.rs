@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const SECONDS_PER_HOUR: i64 = 3_600;
+
+#[account]
+pub struct Policy {
+	pub treasury: Pubkey,
+	pub token_mint: Option<Pubkey>, // Asset the rules apply to (None for SOL)
+	pub rules: Vec<PolicyRule>,     // Evaluated in order for every outflow of the asset
+	pub bump: u8,
+}
+
+/// A rule every outflow of a policy's asset must pass
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum PolicyRule {
+	/// No single outflow may exceed `amount`
+	MaxPerTransaction { amount: u64 },
+	/// Outflows in each `period_seconds` window may add up to at most `cap`
+	PeriodCap { period_seconds: u64, cap: u64, spent: u64, period_start: i64 },
+	/// Funds may only go to the listed accounts
+	AllowedDestinations { destinations: Vec<Pubkey> },
+	/// Outflows only on `weekdays` (bit 0 = Monday .. bit 6 = Sunday),
+	/// from `start_hour` up to but excluding `end_hour`, in UTC
+	AllowedHours { weekdays: u8, start_hour: u8, end_hour: u8 },
+	/// The balance left after an outflow may not drop below `amount`
+	MinReserve { amount: u64 },
+}
+
+/// An outflow about to leave the treasury, as seen by the policy rules
+pub struct Outflow {
+	pub amount: u64,
+	pub destinations: Vec<Pubkey>, // Every account credited by the outflow
+	pub balance: u64,              // The asset's booked balance before the outflow
+	pub current_time: i64,
+}
+
+impl PolicyRule {
+	/// Check the rule's parameters are in range
+	pub fn validate(&self) -> Result<()> {
+		let valid = match self {
+			PolicyRule::MaxPerTransaction { .. } | PolicyRule::MinReserve { .. } => true,
+			PolicyRule::PeriodCap { period_seconds, .. } => *period_seconds > 0 && *period_seconds <= i64::MAX as u64,
+			PolicyRule::AllowedDestinations { destinations } => {
+				!destinations.is_empty() && destinations.len() <= crate::MAX_POLICY_DESTINATIONS
+			},
+			PolicyRule::AllowedHours { weekdays, start_hour, end_hour } => {
+				*weekdays > 0 && *weekdays < 1 << 7 && start_hour < end_hour && *end_hour <= 24
+			},
+		};
+
+		if !valid {
+			return Err(crate::error::ErrorCode::InvalidPolicy.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check the outflow passes this rule
+	pub fn check(&self, outflow: &Outflow) -> Result<()> {
+		match self {
+			PolicyRule::MaxPerTransaction { amount } => {
+				if outflow.amount > *amount {
+					return Err(crate::error::ErrorCode::TransactionLimitExceeded.into());
+				}
+			},
+			PolicyRule::PeriodCap { cap, .. } => {
+				if self.period_spent(outflow.current_time).saturating_add(outflow.amount) > *cap {
+					return Err(crate::error::ErrorCode::PeriodCapExceeded.into());
+				}
+			},
+			PolicyRule::AllowedDestinations { destinations } => {
+				if !outflow.destinations.iter().all(|destination| destinations.contains(destination)) {
+					return Err(crate::error::ErrorCode::DestinationNotAllowed.into());
+				}
+			},
+			PolicyRule::AllowedHours { weekdays, start_hour, end_hour } => {
+				let day = outflow.current_time.div_euclid(SECONDS_PER_DAY);
+				// 1970-01-01 was a Thursday
+				let weekday = (day + 3).rem_euclid(7);
+				let hour = outflow.current_time.rem_euclid(SECONDS_PER_DAY) / SECONDS_PER_HOUR;
+				if weekdays & (1 << weekday) == 0 || hour < *start_hour as i64 || hour >= *end_hour as i64 {
+					return Err(crate::error::ErrorCode::OutsideAllowedHours.into());
+				}
+			},
+			PolicyRule::MinReserve { amount } => {
+				if outflow.balance < outflow.amount.saturating_add(*amount) {
+					return Err(crate::error::ErrorCode::MinimumReserveBreached.into());
+				}
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Most this rule lets leave at `current_time` from `balance`
+	pub fn allowance(&self, balance: u64, current_time: i64) -> u64 {
+		match self {
+			PolicyRule::MaxPerTransaction { amount } => *amount,
+			PolicyRule::PeriodCap { cap, .. } => cap.saturating_sub(self.period_spent(current_time)),
+			PolicyRule::MinReserve { amount } => balance.saturating_sub(*amount),
+			PolicyRule::AllowedDestinations { .. } | PolicyRule::AllowedHours { .. } => u64::MAX,
+		}
+	}
+
+	/// Count a passed outflow against the rule
+	pub fn record(&mut self, outflow: &Outflow) {
+		if let PolicyRule::PeriodCap { period_seconds, spent, period_start, .. } = self {
+			if outflow.current_time - *period_start >= *period_seconds as i64 {
+				*spent = 0;
+				*period_start = outflow.current_time;
+			}
+			*spent = spent.saturating_add(outflow.amount);
+		}
+	}
+
+	/// Spent so far in the period window containing `current_time`
+	fn period_spent(&self, current_time: i64) -> u64 {
+		match self {
+			PolicyRule::PeriodCap { period_seconds, spent, period_start, .. } => {
+				if current_time - *period_start >= *period_seconds as i64 { 0 } else { *spent }
+			},
+			_ => 0,
+		}
+	}
+}
+
+impl Policy {
+	/// Check there are few enough rules and each is in range
+	pub fn validate_rules(rules: &[PolicyRule]) -> Result<()> {
+		if rules.len() > crate::MAX_POLICY_RULES {
+			return Err(crate::error::ErrorCode::InvalidPolicy.into());
+		}
+
+		rules.iter().try_for_each(PolicyRule::validate)
+	}
+
+	/// Check the outflow against every rule in order, then count it against them
+	pub fn evaluate(&mut self, outflow: &Outflow) -> Result<()> {
+		self.rules.iter().try_for_each(|rule| rule.check(outflow))?;
+		self.rules.iter_mut().for_each(|rule| rule.record(outflow));
+
+		Ok(())
+	}
+
+	/// Most the rules let leave at `current_time` from `balance`
+	pub fn allowance(&self, balance: u64, current_time: i64) -> u64 {
+		self.rules
+			.iter()
+			.map(|rule| rule.allowance(balance, current_time))
+			.min()
+			.unwrap_or(u64::MAX)
+	}
+}
@@ -1,4 +1,3 @@
-
 use anchor_lang::prelude::*;
 
 #[account]
@@ -11,4 +10,120 @@ pub struct Recipient {
 	pub total_received: u64,
 	pub last_payout_time: i64,
 	pub bump: u8,
+	pub active_schedules: u32, // Payout schedules owned by this recipient that are still active
+	pub active_streams: u32,   // Streams owned by this recipient that are still active
+	pub valid_until: i64,            // Whitelist expiry (0 for no expiry)
+	pub attestation_ref: [u8; 32],   // Reference to the off-chain attestation, e.g. a document hash
+	pub attested_at: i64,            // When the recipient was last attested
+	pub payout_address: Pubkey,      // Wallet that receives SOL payouts, changed only with the recipient's signature
+	pub caps: RecipientCaps,
+	pub period_received: u64,        // Received since period_start, counted against the per period cap
+	pub period_start: i64,
+	pub added_at: i64,               // When the recipient was added, for the treasury's cooling period
+}
+
+/// Limits on what a recipient can receive, in raw lamports or token units (0 for no cap)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct RecipientCaps {
+	pub per_payout: u64,
+	pub per_period: u64,
+	pub period_seconds: u64,
+	pub lifetime: u64,
+}
+
+impl RecipientCaps {
+	/// Check a per period cap comes with a period length
+	pub fn validate(&self) -> Result<()> {
+		if self.per_period > 0 && self.period_seconds == 0 {
+			return Err(crate::error::ErrorCode::InvalidRecipientCaps.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Recipient {
+	/// Check if any schedule or stream still depends on this recipient
+	pub fn has_open_schedules(&self) -> bool {
+		self.active_schedules > 0 || self.active_streams > 0
+	}
+
+	/// Check if the whitelist entry has lapsed and needs re-attestation
+	pub fn is_expired(&self, current_time: i64) -> bool {
+		self.valid_until != 0 && current_time >= self.valid_until
+	}
+
+	/// Verify the recipient may currently be paid
+	pub fn validate_payable(&self, current_time: i64) -> Result<()> {
+		if !self.is_active {
+			return Err(crate::error::ErrorCode::InactiveRecipient.into());
+		}
+
+		if self.is_expired(current_time) {
+			return Err(crate::error::ErrorCode::RecipientExpired.into());
+		}
+
+		Ok(())
+	}
+
+	/// Check if the recipient is still within the treasury's cooling period for new recipients
+	pub fn is_cooling(&self, treasury: &crate::TreasuryConfig, current_time: i64) -> bool {
+		treasury.cooling_period_seconds > 0
+			&& current_time < self.added_at.saturating_add(treasury.cooling_period_seconds as i64)
+	}
+
+	/// How much the recipient can still receive in one payout at `current_time`
+	pub fn remaining_allowance(&self, treasury: &crate::TreasuryConfig, current_time: i64) -> u64 {
+		let mut allowance = u64::MAX;
+
+		if self.caps.per_payout > 0 {
+			allowance = allowance.min(self.caps.per_payout);
+		}
+
+		if self.caps.per_period > 0 {
+			let period_received = if self.is_new_period(current_time) { 0 } else { self.period_received };
+			allowance = allowance.min(self.caps.per_period.saturating_sub(period_received));
+		}
+
+		if self.caps.lifetime > 0 {
+			allowance = allowance.min(self.caps.lifetime.saturating_sub(self.total_received));
+		}
+
+		if self.is_cooling(treasury, current_time) {
+			allowance = allowance.min(treasury.cooling_payout_cap.saturating_sub(self.total_received));
+		}
+
+		allowance
+	}
+
+	/// Verify the recipient may receive `amount` now without breaking a cap
+	///
+	/// Payouts are held outright during a cooling period without a cap.
+	pub fn validate_receivable(&self, treasury: &crate::TreasuryConfig, amount: u64, current_time: i64) -> Result<()> {
+		if amount <= self.remaining_allowance(treasury, current_time) {
+			return Ok(());
+		}
+
+		if self.is_cooling(treasury, current_time) && treasury.cooling_payout_cap == 0 {
+			return Err(crate::error::ErrorCode::RecipientCoolingDown.into());
+		}
+
+		Err(crate::error::ErrorCode::RecipientCapExceeded.into())
+	}
+
+	/// Record a payout of `amount` against the recipient's totals
+	pub fn record_payout(&mut self, amount: u64, current_time: i64) {
+		if self.is_new_period(current_time) {
+			self.period_received = 0;
+			self.period_start = current_time;
+		}
+
+		self.period_received = self.period_received.checked_add(amount).unwrap();
+		self.total_received = self.total_received.checked_add(amount).unwrap();
+		self.last_payout_time = current_time;
+	}
+
+	fn is_new_period(&self, current_time: i64) -> bool {
+		current_time.saturating_sub(self.period_start) >= self.caps.period_seconds as i64
+	}
 }
@@ -16,11 +16,20 @@ pub struct StreamingSchedule {
     pub is_cancelable: bool,     // Can be cancelled by admin
     pub created_by: Pubkey,      // Who created the stream
     pub bump: u8,
+    pub is_paused: bool,        // Paused while the recipient is deactivated
+    pub paused_at: i64,         // When the stream stopped accruing
 }
 
 impl StreamingSchedule {
     /// Calculate how much can be withdrawn at current time
     pub fn calculate_withdrawable_amount(&self, current_time: i64) -> u64 {
+        // A paused stream stops accruing at the moment it was paused
+        let current_time = if self.is_paused {
+            current_time.min(self.paused_at)
+        } else {
+            current_time
+        };
+
         // Stream hasn't started yet
         if current_time < self.start_time {
             return 0;
@@ -49,7 +58,42 @@ impl StreamingSchedule {
     /// Check if stream is still active and not fully withdrawn
     pub fn is_stream_active(&self, current_time: i64) -> bool {
         self.is_active && 
+        !self.is_paused && 
         current_time >= self.start_time && 
         self.withdrawn_amount < self.total_amount
     }
+
+    /// Stop accruing and optionally give up everything not yet vested.
+    /// Returns the amount clawed back to the treasury.
+    pub fn pause(&mut self, current_time: i64, clawback_unvested: bool) -> u64 {
+        let vested = self.withdrawn_amount
+            .saturating_add(self.calculate_withdrawable_amount(current_time));
+
+        self.is_paused = true;
+        self.paused_at = current_time;
+
+        if !clawback_unvested {
+            return 0;
+        }
+
+        // Whatever has vested stays withdrawable, the stream simply ends now
+        let clawed_back = self.total_amount.saturating_sub(vested);
+        self.total_amount = vested;
+        self.end_time = self.end_time.min(current_time);
+        clawed_back
+    }
+
+    /// Resume accruing, shifting the timeline by however long the stream was paused
+    pub fn resume(&mut self, current_time: i64) {
+        // Only time the stream would otherwise have spent accruing is made up for
+        let accrual_from = self.paused_at.max(self.start_time);
+        if self.end_time > self.paused_at && current_time > accrual_from {
+            let paused_for = current_time - accrual_from;
+            self.start_time = self.start_time.saturating_add(paused_for);
+            self.cliff_time = self.cliff_time.saturating_add(paused_for);
+            self.end_time = self.end_time.saturating_add(paused_for);
+        }
+        self.is_paused = false;
+        self.paused_at = 0;
+    }
 }
@@ -8,4 +8,5 @@ pub struct TokenVault {
 	pub token_account: Pubkey,
 	pub balance: u64,
 	pub bump: u8,
+	pub reserve_floor: u64, // Balance normal outflows must leave in place
 }
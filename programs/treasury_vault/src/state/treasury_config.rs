@@ -20,4 +20,145 @@ pub struct TreasuryConfig {
     pub token_gate_mint: Option<Pubkey>,
     pub token_gate_amount: u64,
     pub bump: u8,
-}
\ No newline at end of file
+    pub status: TreasuryStatus,
+    pub successor: Option<Pubkey>, // Receives swept funds when decommissioned
+    pub open_schedules: u32,       // Payout schedules that are still active
+    pub open_streams: u32,         // Streams that are still active
+    pub token_vault_count: u32,    // Token vaults that have not been swept
+    pub deactivation_policy: DeactivationPolicy,
+    pub attester: Option<Pubkey>, // Must sign recipient admissions and renewals when set
+    pub require_payout_cosign: bool, // Payout destination changes also need a recipient manager's signature
+    pub skip_missed_payouts: bool, // Late executions pay only the latest period instead of catching up
+    pub cooling_period_seconds: u64, // How long new recipients are held to the cooling payout cap
+    pub cooling_payout_cap: u64,   // Most a recipient can receive during its cooling period (0 holds every payout)
+    pub reserve_floor: u64,        // Booked SOL balance normal outflows must leave in place
+    pub keeper_mode: KeeperMode,   // Who may execute due payouts
+    pub keeper_tip: u64,           // Lamports paid to the fee payer of each payout execution
+    pub keeper_tip_period_seconds: u64, // Length of the window keeper tips are capped over
+    pub keeper_tip_period_cap: u64, // Most keeper tips paid per window
+    pub keeper_tips_paid: u64,     // Keeper tips paid in the current window
+    pub keeper_tip_period_start: i64,
+    pub open_escrows: u32,         // Escrows that are still held or disputed
+    pub open_rounds: u32,          // Grants rounds whose matching pool is not yet paid out
+    pub open_bounties: u32,        // Bounties whose reward is still reserved
+    pub open_grants: u32,          // Milestone grants with a milestone still held
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TreasuryStatus {
+    Active,
+    WindingDown,
+}
+
+/// What happens to a recipient's streams when the recipient is deactivated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DeactivationPolicy {
+    /// Pause schedules and streams so they can resume on reactivation
+    PauseOnly,
+    /// Pause schedules and return the unvested part of every stream to the treasury
+    ClawbackUnvested,
+}
+
+/// Who may execute due payouts and drain the payout queue
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KeeperMode {
+    /// Only signers holding the execute payout permission
+    Allowlisted,
+    /// Any signer
+    Permissionless,
+}
+
+impl TreasuryConfig {
+    /// Check if the treasury still accepts new schedules and streams
+    pub fn is_winding_down(&self) -> bool {
+        self.status == TreasuryStatus::WindingDown
+    }
+
+    /// How much can still be paid out at `now` before a spending limit is hit
+    pub fn remaining_spend(&self, now: i64) -> u64 {
+        let daily_total = if now - self.last_day_reset >= 86400 { 0 } else { self.daily_total };
+        let weekly_total = if now - self.last_week_reset >= 604800 { 0 } else { self.weekly_total };
+        let monthly_total = if now - self.last_month_reset >= 2592000 { 0 } else { self.monthly_total };
+
+        self.daily_limit.saturating_sub(daily_total)
+            .min(self.weekly_limit.saturating_sub(weekly_total))
+            .min(self.monthly_limit.saturating_sub(monthly_total))
+    }
+
+    /// The tightest of the daily, weekly and monthly limits, the most a single payout can move
+    pub fn smallest_spending_limit(&self) -> u64 {
+        self.daily_limit.min(self.weekly_limit).min(self.monthly_limit)
+    }
+
+    /// Restart the daily, weekly and monthly totals whose windows have passed
+    pub fn reset_spend_windows(&mut self, now: i64) {
+        if now - self.last_day_reset >= 86400 { // 24 hours in seconds
+            self.daily_total = 0;
+            self.last_day_reset = now;
+        }
+
+        if now - self.last_week_reset >= 604800 { // 7 days in seconds
+            self.weekly_total = 0;
+            self.last_week_reset = now;
+        }
+
+        if now - self.last_month_reset >= 2592000 { // 30 days in seconds
+            self.monthly_total = 0;
+            self.last_month_reset = now;
+        }
+    }
+
+    /// Check `amount` fits the spending limits, then count it against them
+    ///
+    /// Expects the windows to have been reset for the current time.
+    pub fn record_spend(&mut self, amount: u64) -> Result<()> {
+        let daily_total = self.daily_total.checked_add(amount);
+        let weekly_total = self.weekly_total.checked_add(amount);
+        let monthly_total = self.monthly_total.checked_add(amount);
+
+        match (daily_total, weekly_total, monthly_total) {
+            (Some(daily_total), Some(weekly_total), Some(monthly_total))
+                if daily_total <= self.daily_limit
+                    && weekly_total <= self.weekly_limit
+                    && monthly_total <= self.monthly_limit =>
+            {
+                self.daily_total = daily_total;
+                self.weekly_total = weekly_total;
+                self.monthly_total = monthly_total;
+                Ok(())
+            },
+            _ => Err(crate::error::ErrorCode::SpendingLimitExceeded.into()),
+        }
+    }
+
+    /// Tip owed to the keeper of an execution at `now`, within what is left of the period cap
+    pub fn keeper_tip_due(&self, now: i64) -> u64 {
+        let tips_paid = if now - self.keeper_tip_period_start >= self.keeper_tip_period_seconds as i64 {
+            0
+        } else {
+            self.keeper_tips_paid
+        };
+
+        self.keeper_tip.min(self.keeper_tip_period_cap.saturating_sub(tips_paid))
+    }
+
+    /// Count a paid keeper tip against the period cap, restarting the window if it has passed
+    pub fn record_keeper_tip(&mut self, tip: u64, now: i64) {
+        if now - self.keeper_tip_period_start >= self.keeper_tip_period_seconds as i64 {
+            self.keeper_tips_paid = 0;
+            self.keeper_tip_period_start = now;
+        }
+
+        self.keeper_tips_paid = self.keeper_tips_paid.saturating_add(tip);
+    }
+
+    /// Check if every schedule, stream, escrow, grants round, bounty and milestone grant has finished or been cancelled
+    pub fn has_open_obligations(&self) -> bool {
+        self.open_schedules > 0
+            || self.open_streams > 0
+            || self.open_escrows > 0
+            || self.open_rounds > 0
+            || self.open_bounties > 0
+            || self.open_grants > 0
+    }
+}
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
 use anchor_spl::token::{Token, TokenAccount};
 use crate::*;
 
@@ -92,6 +94,622 @@ pub fn validate_associated_token_account(
     Ok(())
 }
 
+/// Verifies the signer holds `permission` on the treasury
+///
+/// The admin holds every permission and the treasurer holds
+/// `TREASURER_PERMISSIONS`; anyone else needs a `Member` account.
+pub fn authorize(
+    treasury: &Account<TreasuryConfig>,
+    signer: &Pubkey,
+    member: Option<&Account<Member>>,
+    permission: u16,
+) -> Result<()> {
+    if *signer == treasury.admin {
+        return Ok(());
+    }
+
+    if *signer == treasury.treasurer && TREASURER_PERMISSIONS & permission == permission {
+        return Ok(());
+    }
+
+    if let Some(member) = member {
+        if member.treasury == treasury.key() && member.member == *signer && member.has_permissions(permission) {
+            return Ok(());
+        }
+    }
+
+    Err(crate::error::ErrorCode::UnauthorizedAccess.into())
+}
+
+/// Verifies the signer may grant or revoke the `changed` permission bits
+///
+/// The admin may change any; anyone else only the privileged permissions
+/// they hold themselves.
+pub fn authorize_grant(
+    treasury: &Account<TreasuryConfig>,
+    signer: &Pubkey,
+    member: Option<&Account<Member>>,
+    changed: u16,
+) -> Result<()> {
+    let privileged = changed & PRIVILEGED_PERMISSIONS;
+    if *signer == treasury.admin || privileged == 0 {
+        return Ok(());
+    }
+
+    if member.is_some_and(|member| member.member == *signer && member.has_permissions(privileged)) {
+        return Ok(());
+    }
+
+    Err(crate::error::ErrorCode::UnauthorizedAccess.into())
+}
+
+/// Verifies the signer holds `permission`, unless the approval request passed
+/// a token-holder vote for `action` called with arguments hashing to
+/// `terms_hash`, which authorizes exactly that call for any signer
+pub fn authorize_action(
+    treasury: &Account<TreasuryConfig>,
+    signer: &Pubkey,
+    member: Option<&Account<Member>>,
+    approval_request: Option<&ApprovalRequest>,
+    action: ApprovalAction,
+    terms_hash: [u8; 32],
+    permission: u16,
+) -> Result<()> {
+    if approval_request.is_some_and(|request| {
+        request.authorizes_by_vote(&treasury.key(), action) && request.terms_hash == terms_hash
+    }) {
+        return Ok(());
+    }
+
+    authorize(treasury, signer, member, permission)
+}
+
+/// Verifies the signer may execute payouts: anyone when the treasury is
+/// permissionless, otherwise only holders of the execute payout permission
+pub fn authorize_keeper(
+    treasury: &Account<TreasuryConfig>,
+    signer: &Pubkey,
+    member: Option<&Account<Member>>,
+) -> Result<()> {
+    if treasury.keeper_mode == KeeperMode::Permissionless {
+        return Ok(());
+    }
+
+    authorize(treasury, signer, member, PERMISSION_EXECUTE_PAYOUT)
+}
+
+/// Verifies a payout destination change is signed by the recipient,
+/// and co-signed by a recipient manager when the treasury requires it
+pub fn authorize_payout_change(
+    treasury: &Account<TreasuryConfig>,
+    recipient: &Account<Recipient>,
+    recipient_signer: &Pubkey,
+    cosigner: Option<&Signer>,
+    cosigner_member: Option<&Account<Member>>,
+) -> Result<()> {
+    if *recipient_signer != recipient.recipient_address {
+        return Err(crate::error::ErrorCode::NotRecipient.into());
+    }
+
+    if treasury.require_payout_cosign {
+        let cosigner = cosigner.ok_or(crate::error::ErrorCode::MissingPayoutCosign)?;
+        authorize(treasury, &cosigner.key(), cosigner_member, PERMISSION_MANAGE_RECIPIENTS)?;
+    }
+
+    Ok(())
+}
+
+/// Resolves the token account that may receive the recipient's payouts of `mint`
+///
+/// A registered `PayoutDestination` wins; while none exists the payout
+/// address's associated token account is used.
+pub fn expected_payout_token_account(
+    recipient: &Account<Recipient>,
+    payout_destination: &AccountInfo,
+    mint: &Pubkey,
+) -> Result<Pubkey> {
+    if payout_destination.owner != &crate::ID {
+        return Ok(get_expected_token_account_address(
+            &recipient.payout_address,
+            mint,
+            &anchor_spl::token::ID,
+        ));
+    }
+
+    let data = payout_destination.try_borrow_data()?;
+    let destination = PayoutDestination::try_deserialize(&mut &data[..])?;
+    if destination.recipient != recipient.key() || destination.token_mint != *mint {
+        return Err(crate::error::ErrorCode::InvalidPayoutDestination.into());
+    }
+
+    Ok(destination.token_account)
+}
+
+/// Loads the recipient owed `split` from the account passed for it,
+/// checking it is the split's recipient of this treasury and may be paid
+pub fn load_split_recipient<'info>(
+    treasury: &Account<TreasuryConfig>,
+    split: &PayoutSplit,
+    account_info: &'info AccountInfo<'info>,
+    current_time: i64,
+) -> Result<Account<'info, Recipient>> {
+    if account_info.key() != split.recipient {
+        return Err(crate::error::ErrorCode::InvalidPayoutSplit.into());
+    }
+
+    let recipient = Account::<Recipient>::try_from(account_info)?;
+    if recipient.treasury != treasury.key() {
+        return Err(crate::error::ErrorCode::InvalidPayoutSplit.into());
+    }
+    recipient.validate_payable(current_time)?;
+
+    Ok(recipient)
+}
+
+/// Seed identifying an asset's approval or outflow policy, the default key for SOL
+pub fn policy_asset(token_mint: Option<Pubkey>) -> Pubkey {
+    token_mint.unwrap_or_default()
+}
+
+/// Enforces the treasury's approval tier for `action` moving `amount` of `token_mint`
+///
+/// Without a policy for the asset, or within a tier that needs nothing more,
+/// the caller's own permission is enough. Otherwise the approval request must
+/// match the action, carry the tier's approvals and have waited out its
+/// delay, and is then spent. A request passed by token-holder vote stands in
+/// for every tier, but only for the call whose arguments hash to `terms_hash`,
+/// and is spent whether or not the amount needed it.
+pub fn enforce_approval_policy(
+    treasury: &Account<TreasuryConfig>,
+    approval_policy: &AccountInfo,
+    approval_request: Option<&mut Account<ApprovalRequest>>,
+    action: ApprovalAction,
+    terms_hash: [u8; 32],
+    amount: u64,
+    token_mint: Option<Pubkey>,
+    current_time: i64,
+) -> Result<()> {
+    enforce_commitment_approval_policy(treasury, approval_policy, approval_request, action, terms_hash, amount, Some(amount), token_mint, current_time)
+}
+
+/// Enforces the approval tier covering `commitment`, the total `action` can move
+/// over its lifetime (`None` when unbounded, which takes the top tier)
+///
+/// The approval request still matches `amount`, the sum each payment moves.
+pub fn enforce_commitment_approval_policy(
+    treasury: &Account<TreasuryConfig>,
+    approval_policy: &AccountInfo,
+    approval_request: Option<&mut Account<ApprovalRequest>>,
+    action: ApprovalAction,
+    terms_hash: [u8; 32],
+    amount: u64,
+    commitment: Option<u64>,
+    token_mint: Option<Pubkey>,
+    current_time: i64,
+) -> Result<()> {
+    let approval_request = match approval_request {
+        Some(request) if request.vote_passed => {
+            if !request.authorizes_by_vote(&treasury.key(), action)
+                || request.terms_hash != terms_hash
+                || request.amount != amount
+                || request.token_mint != token_mint
+            {
+                return Err(crate::error::ErrorCode::ApprovalMismatch.into());
+            }
+            request.validate_unexpired(current_time)?;
+            request.is_executed = true;
+
+            return Ok(());
+        },
+        approval_request => approval_request,
+    };
+
+    // An uninitialized policy account means the asset has no policy
+    if approval_policy.owner != &crate::ID {
+        return Ok(());
+    }
+
+    let policy = ApprovalPolicy::try_deserialize(&mut &approval_policy.try_borrow_data()?[..])?;
+    let tier = match policy.tier_for_commitment(commitment)? {
+        Some(tier) if tier.needs_approval() => tier,
+        _ => return Ok(()),
+    };
+
+    let request = approval_request.ok_or(crate::error::ErrorCode::ApprovalRequired)?;
+    if request.treasury != treasury.key()
+        || request.action != action
+        || request.amount != amount
+        || request.token_mint != token_mint
+        || request.is_executed
+    {
+        return Err(crate::error::ErrorCode::ApprovalMismatch.into());
+    }
+    request.validate_approved(&tier, current_time)?;
+
+    request.is_executed = true;
+
+    Ok(())
+}
+
+/// Checks the paid asset has no approval tiers for an oracle-priced schedule
+///
+/// Its `amount` is in USD, which tiers set in the asset's own units cannot
+/// weigh until the feed is read at execution.
+pub fn validate_oracle_pricing_allowed(approval_policy: &AccountInfo) -> Result<()> {
+    if approval_policy.owner == &crate::ID {
+        return Err(crate::error::ErrorCode::OraclePricingUnderApprovalPolicy.into());
+    }
+
+    Ok(())
+}
+
+/// Loads the treasury's governance config, or `None` while its account is uninitialized
+pub fn load_governance(governance: &AccountInfo) -> Result<Option<GovernanceConfig>> {
+    if governance.owner != &crate::ID {
+        return Ok(None);
+    }
+
+    Ok(Some(GovernanceConfig::try_deserialize(&mut &governance.try_borrow_data()?[..])?))
+}
+
+/// Hash identifying an instruction call: the instruction's name followed by its arguments
+pub fn args_hash<T: AnchorSerialize>(instruction: &str, args: &T) -> Result<[u8; 32]> {
+    let mut data = instruction.as_bytes().to_vec();
+    args.serialize(&mut data)?;
+
+    Ok(anchor_lang::solana_program::hash::hash(&data).to_bytes())
+}
+
+/// Enforces council co-signing of configuration changes
+///
+/// When the treasury's governance requires it, the change needs an unspent
+/// approval request for exactly `config_hash` that a delegate has co-signed,
+/// which is then spent. Otherwise the caller's own permission is enough.
+pub fn enforce_council_cosign(
+    treasury: &Pubkey,
+    governance: Option<&GovernanceConfig>,
+    approval_request: Option<&mut Account<ApprovalRequest>>,
+    config_hash: [u8; 32],
+) -> Result<()> {
+    if !governance.is_some_and(|governance| governance.require_council_config) {
+        return Ok(());
+    }
+
+    let request = approval_request.ok_or(crate::error::ErrorCode::ApprovalRequired)?;
+    if request.treasury != *treasury
+        || request.action != (ApprovalAction::ChangeConfig { config_hash })
+        || request.is_executed
+    {
+        return Err(crate::error::ErrorCode::ApprovalMismatch.into());
+    }
+    if request.council_weight == 0 {
+        return Err(crate::error::ErrorCode::InsufficientApprovals.into());
+    }
+    request.validate_unexpired(Clock::get()?.unix_timestamp)?;
+
+    request.is_executed = true;
+
+    Ok(())
+}
+
+/// Loads an asset's outflow policy, or `None` while its account is uninitialized
+pub fn load_policy(policy: &AccountInfo) -> Result<Option<Policy>> {
+    if policy.owner != &crate::ID {
+        return Ok(None);
+    }
+
+    Ok(Some(Policy::try_deserialize(&mut &policy.try_borrow_data()?[..])?))
+}
+
+/// Most that can leave the treasury at `current_time` from `balance`,
+/// within its spending limits and the asset's policy
+pub fn outflow_allowance(
+    treasury: &TreasuryConfig,
+    policy: &AccountInfo,
+    balance: u64,
+    current_time: i64,
+) -> Result<u64> {
+    let allowance = treasury.remaining_spend(current_time);
+    Ok(match load_policy(policy)? {
+        Some(policy) => allowance.min(policy.allowance(balance, current_time)),
+        None => allowance,
+    })
+}
+
+/// Runs every outflow through the treasury's spending limits and then the
+/// asset's policy rules in order, counting it against both once all pass
+///
+/// Each rule fails with its own error; without a policy only the spending
+/// limits apply.
+pub fn enforce_outflow_policy(
+    treasury: &mut Account<TreasuryConfig>,
+    policy: &AccountInfo,
+    outflow: &Outflow,
+) -> Result<()> {
+    treasury.reset_spend_windows(outflow.current_time);
+    treasury.record_spend(outflow.amount)?;
+
+    if let Some(mut loaded) = load_policy(policy)? {
+        if loaded.treasury != treasury.key() {
+            return Err(crate::error::ErrorCode::InvalidPolicy.into());
+        }
+        loaded.evaluate(outflow)?;
+
+        let mut data = policy.try_borrow_mut_data()?;
+        loaded.try_serialize(&mut &mut data[..])?;
+    }
+
+    Ok(())
+}
+
+/// Checks a SOL outflow of `amount` leaves the treasury account rent-exempt
+/// and, unless `below_floor_approved`, its booked balance at or above the
+/// reserve floor
+pub fn validate_sol_reserve(
+    treasury: &Account<TreasuryConfig>,
+    amount: u64,
+    below_floor_approved: bool,
+) -> Result<()> {
+    validate_reserved_sol_outflow(treasury, amount)?;
+
+    if !below_floor_approved && treasury.total_balance.saturating_sub(amount) < treasury.reserve_floor {
+        return Err(crate::error::ErrorCode::ReserveFloorBreached.into());
+    }
+
+    Ok(())
+}
+
+/// Checks a SOL outflow of `amount` that was reserved out of the booked
+/// balance earlier leaves the treasury account rent-exempt
+///
+/// The reserve floor was checked when the amount was reserved, and paying it
+/// out leaves the booked balance unchanged.
+pub fn validate_reserved_sol_outflow(
+    treasury: &Account<TreasuryConfig>,
+    amount: u64,
+) -> Result<()> {
+    let treasury_info = treasury.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+    if treasury_info.lamports() < amount.saturating_add(rent_exempt_minimum) {
+        return Err(crate::error::ErrorCode::RentExemptionBreached.into());
+    }
+
+    Ok(())
+}
+
+/// Pays the keeper of a payout execution its tip from the treasury's SOL,
+/// within the tip period cap
+///
+/// The tip is skipped rather than failing the execution when it would take
+/// the treasury below its reserve floor or rent-exempt minimum.
+pub fn pay_keeper_tip(
+    treasury: &mut Account<TreasuryConfig>,
+    keeper: &AccountInfo,
+    now: i64,
+) -> Result<()> {
+    let tip = treasury.keeper_tip_due(now);
+    if tip == 0 || tip > treasury.total_balance || validate_sol_reserve(treasury, tip, false).is_err() {
+        return Ok(());
+    }
+
+    **treasury.to_account_info().try_borrow_mut_lamports()? -= tip;
+    **keeper.try_borrow_mut_lamports()? += tip;
+
+    treasury.total_balance -= tip;
+    treasury.record_keeper_tip(tip, now);
+
+    msg!("Paid keeper tip of {} lamports", tip);
+
+    Ok(())
+}
+
+/// Moves a claimed schedule to active, completing it once max_executions or
+/// the end time is reached
+pub fn complete_claim_schedule(
+    payout_schedule: &mut PayoutSchedule,
+    treasury: &mut TreasuryConfig,
+    recipient: &mut Recipient,
+) {
+    payout_schedule.status = ScheduleStatus::Active;
+    if payout_schedule.is_finished() {
+        payout_schedule.status = ScheduleStatus::Completed;
+        treasury.open_schedules = treasury.open_schedules.saturating_sub(1);
+        recipient.active_schedules = recipient.active_schedules.saturating_sub(1);
+    }
+}
+
+/// Checks a token outflow of `amount` leaves the vault's balance at or above
+/// its reserve floor, unless `below_floor_approved`
+pub fn validate_token_reserve(
+    token_vault: &TokenVault,
+    amount: u64,
+    below_floor_approved: bool,
+) -> Result<()> {
+    if !below_floor_approved && token_vault.balance.saturating_sub(amount) < token_vault.reserve_floor {
+        return Err(crate::error::ErrorCode::ReserveFloorBreached.into());
+    }
+
+    Ok(())
+}
+
+/// Checks an emergency withdrawal may go below the reserve floor
+///
+/// Needs an unspent approval request for exactly this withdrawal carrying
+/// at least `MIN_FLOOR_OVERRIDE_APPROVALS` approvals, whatever the amount's
+/// approval tier asks for.
+pub fn validate_floor_override(
+    treasury: &Pubkey,
+    approval_request: Option<&ApprovalRequest>,
+    action: ApprovalAction,
+    amount: u64,
+    token_mint: Option<Pubkey>,
+) -> Result<()> {
+    let request = approval_request.ok_or(crate::error::ErrorCode::ReserveFloorBreached)?;
+    if request.treasury != *treasury
+        || request.action != action
+        || request.amount != amount
+        || request.token_mint != token_mint
+        || request.is_executed
+    {
+        return Err(crate::error::ErrorCode::ApprovalMismatch.into());
+    }
+
+    if request.approvers.len() < MIN_FLOOR_OVERRIDE_APPROVALS {
+        return Err(crate::error::ErrorCode::InsufficientApprovals.into());
+    }
+    request.validate_unexpired(Clock::get()?.unix_timestamp)?;
+
+    Ok(())
+}
+
+/// Amount of the paid asset, with `decimals` decimals, owed for one period
+///
+/// Schedules without oracle pricing pay their raw `amount`; oracle-priced
+/// schedules convert their USD `amount` at the feed's current price.
+pub fn period_payout_amount(
+    payout_schedule: &PayoutSchedule,
+    price_feed: Option<&UncheckedAccount>,
+    decimals: u8,
+    current_time: i64,
+) -> Result<u64> {
+    let pricing = match payout_schedule.oracle_pricing {
+        Some(pricing) => pricing,
+        None => return Ok(payout_schedule.amount),
+    };
+
+    let price_feed = price_feed.ok_or(crate::error::ErrorCode::InvalidPriceFeed)?;
+    if price_feed.key() != pricing.price_feed {
+        return Err(crate::error::ErrorCode::InvalidPriceFeed.into());
+    }
+    let (price, exponent) = read_oracle_price(&pricing, &price_feed.try_borrow_data()?, current_time)?;
+
+    // units = usd * 10^-USD_DECIMALS / (price * 10^exponent) * 10^decimals
+    let scale = decimals as i32 - exponent - USD_DECIMALS as i32;
+    let numerator = 10u128
+        .checked_pow(scale.max(0) as u32)
+        .and_then(|factor| factor.checked_mul(payout_schedule.amount as u128));
+    let denominator = 10u128
+        .checked_pow((-scale).max(0) as u32)
+        .and_then(|factor| factor.checked_mul(price as u128));
+
+    match (numerator, denominator) {
+        (Some(numerator), Some(denominator)) => u64::try_from(numerator / denominator)
+            .map_err(|_| crate::error::ErrorCode::InvalidPriceFeed.into()),
+        _ => Err(crate::error::ErrorCode::InvalidPriceFeed.into()),
+    }
+}
+
+/// Reads the aggregate price and exponent from a Pyth-style price account,
+/// checking it is trading, fresh, confident and near its moving average
+pub fn read_oracle_price(pricing: &OraclePricing, data: &[u8], current_time: i64) -> Result<(i64, i32)> {
+    const MAGIC: u32 = 0xa1b2_c3d4;
+    const ACCOUNT_TYPE_PRICE: u32 = 3;
+    const STATUS_TRADING: u32 = 1;
+
+    if data.len() < 240 {
+        return Err(crate::error::ErrorCode::InvalidPriceFeed.into());
+    }
+    let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    let read_i64 = |offset: usize| i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+    let exponent = read_u32(20) as i32;
+    let ema_price = read_i64(48);
+    let publish_time = read_i64(96);
+    let price = read_i64(208);
+    let confidence = read_i64(216) as u64;
+
+    if read_u32(0) != MAGIC
+        || read_u32(8) != ACCOUNT_TYPE_PRICE
+        || read_u32(224) != STATUS_TRADING
+        || price <= 0
+        || ema_price <= 0
+    {
+        return Err(crate::error::ErrorCode::InvalidPriceFeed.into());
+    }
+
+    if current_time.saturating_sub(publish_time) > pricing.max_staleness_seconds as i64 {
+        return Err(crate::error::ErrorCode::StalePrice.into());
+    }
+
+    if confidence as u128 * 10_000 > price as u128 * pricing.max_confidence_bps as u128 {
+        return Err(crate::error::ErrorCode::PriceConfidenceTooWide.into());
+    }
+
+    if price.abs_diff(ema_price) as u128 * 10_000 > ema_price as u128 * pricing.max_slippage_bps as u128 {
+        return Err(crate::error::ErrorCode::PriceSlippageExceeded.into());
+    }
+
+    Ok((price, exponent))
+}
+
+/// Builds the message an attester signs to admit or renew a recipient
+pub fn attestation_message(treasury: &Pubkey, recipient_address: &Pubkey, valid_until: i64) -> [u8; 72] {
+    let mut message = [0u8; 72];
+    message[..32].copy_from_slice(treasury.as_ref());
+    message[32..64].copy_from_slice(recipient_address.as_ref());
+    message[64..].copy_from_slice(&valid_until.to_le_bytes());
+    message
+}
+
+/// Validates that the attester signed the recipient's admission
+///
+/// The attester signature is checked by the Ed25519 precompile; this only
+/// confirms that the instruction right before the current one verified the
+/// expected signer and message.
+pub fn validate_attestation(
+    treasury: &Account<TreasuryConfig>,
+    instructions_sysvar: Option<&AccountInfo>,
+    recipient_address: &Pubkey,
+    valid_until: i64,
+) -> Result<()> {
+    // Skip validation if no attester is configured
+    let attester = match treasury.attester {
+        Some(attester) => attester,
+        None => return Ok(()),
+    };
+
+    let instructions_sysvar = instructions_sysvar
+        .ok_or(crate::error::ErrorCode::MissingAttestation)?;
+
+    // The signature verification must immediately precede this instruction
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(crate::error::ErrorCode::MissingAttestation.into());
+    }
+    let ed25519_ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    if ed25519_ix.program_id != ed25519_program::ID || !ed25519_ix.accounts.is_empty() {
+        return Err(crate::error::ErrorCode::MissingAttestation.into());
+    }
+
+    // Exactly one signature whose key and message live in the same instruction
+    let data = &ed25519_ix.data;
+    if data.len() < 16 || data[0] != 1 {
+        return Err(crate::error::ErrorCode::InvalidAttestation.into());
+    }
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    let public_key_offset = read_u16(6) as usize;
+    let message_data_offset = read_u16(10) as usize;
+    let message_data_size = read_u16(12) as usize;
+    if read_u16(4) != u16::MAX || read_u16(8) != u16::MAX || read_u16(14) != u16::MAX {
+        return Err(crate::error::ErrorCode::InvalidAttestation.into());
+    }
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(crate::error::ErrorCode::InvalidAttestation)?;
+    let message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(crate::error::ErrorCode::InvalidAttestation)?;
+
+    // Verify the attester signed this treasury, recipient and expiry
+    let expected = attestation_message(&treasury.key(), recipient_address, valid_until);
+    if public_key != attester.as_ref() || message != expected.as_ref() {
+        return Err(crate::error::ErrorCode::InvalidAttestation.into());
+    }
+
+    Ok(())
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
@@ -8,10 +8,13 @@ use {
 		csl_spl_token_ix_interface,
 		csl_spl_assoc_token_ix_interface,
 	},
+    anchor_lang::AccountDeserialize,
     solana_program_test::tokio,
     solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+        account::Account, ed25519_instruction::new_ed25519_instruction_with_signature, pubkey::Pubkey,
+        rent::Rent, signature::Keypair, signer::Signer, system_program,
     },
+    treasury_vault::{utils::attestation_message, Recipient},
 };
 
 
@@ -38,6 +41,8 @@ async fn add_recipient_ix_success() {
 	let recipient_address: Pubkey = Pubkey::default();
 	let name: String = Default::default();
 	let role: u8 = Default::default();
+	let valid_until: i64 = Default::default();
+	let attestation_ref: [u8; 32] = Default::default();
 	let treasury_seed_name: String = Default::default();
 
 	// KEYPAIR
@@ -90,16 +95,211 @@ async fn add_recipient_ix_success() {
 		recipient_address,
 		&name,
 		role,
+		valid_until,
+		attestation_ref,
 		&treasury_seed_name,
 		None, // token_gate_mint
 		None, // recipient_token_account
 		None, // token_program
+		None, // attestation
+		None,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+}
+
+#[tokio::test]
+async fn add_recipient_ix_attested() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("kyc");
+	let attestation_ref: [u8; 32] = [3u8; 32];
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let attester_keypair = Keypair::new();
+	let impostor_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+	let valid_until = banks_client.get_sysvar::<solana_sdk::clock::Clock>().await.unwrap().unix_timestamp + 365 * 86_400;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("KYC gated payroll"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::update_treasury_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		Some(attester_keypair.pubkey()),
+		None,
+		None,
+		None,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let message = attestation_message(&treasury_pda, &recipient_address_pubkey, valid_until);
+	let attest = |signer: &Keypair, message: &[u8]| {
+		let signature: [u8; 64] = signer.sign_message(message).as_ref().try_into().unwrap();
+		new_ed25519_instruction_with_signature(message, &signature, &signer.pubkey().to_bytes())
+	};
+
+	// Unattested wallets can't be whitelisted
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		valid_until,
+		attestation_ref,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
 		recent_blockhash,
 	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
 
+	// A signature from anyone but the attester is refused
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		valid_until,
+		attestation_ref,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		Some(attest(&impostor_keypair, &message)),
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	// The attester's approval must cover the requested expiry
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		valid_until + 1,
+		attestation_ref,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		Some(attest(&attester_keypair, &message)),
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		valid_until,
+		attestation_ref,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		Some(attest(&attester_keypair, &message)),
+		None,
+		recent_blockhash,
+	);
 	let result = banks_client.process_transaction(ix).await;
 
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.valid_until, valid_until);
+	assert_eq!(recipient.attestation_ref, attestation_ref);
 }
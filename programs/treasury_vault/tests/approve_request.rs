@@ -0,0 +1,436 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{
+        ApprovalAction, ApprovalRequest, ApprovalTier, OraclePricing, Recurrence, APPROVAL_REQUEST_LIFETIME_SECONDS,
+        PERMISSION_APPROVE,
+    },
+};
+
+
+#[tokio::test]
+async fn approve_request_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("operations");
+	let delay_seconds: u64 = 3_600;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+	let approver_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let approver_pubkey = approver_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (approver_member_pda, _approver_member_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"member",
+			treasury_pda.as_ref(),
+			approver_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let approval_policy_pda = treasury_vault_ix_interface::approval_policy_pda(treasury_pda, None);
+
+	let schedule_pda = |schedule_id: u64| Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	let request_pda = |request_id: u64| Pubkey::find_program_address(
+		&[
+			b"approval_request",
+			treasury_pda.as_ref(),
+			request_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [admin_pubkey, treasurer_pubkey, approver_pubkey] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Operations"),
+		treasurer_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the withdrawal can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_member_ix_setup(
+		treasury_pda,
+		approver_member_pda,
+		&admin_keypair,
+		system_program::ID,
+		approver_pubkey,
+		PERMISSION_APPROVE,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Vendor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Small amounts need only the treasurer, mid-size the admin, large two approvers and a delay
+	let ix = treasury_vault_ix_interface::set_approval_policy_ix_setup(
+		treasury_pda,
+		approval_policy_pda,
+		&admin_keypair,
+		system_program::ID,
+		None,
+		vec![
+			ApprovalTier { up_to: 1_000, require_admin: false, approvals: 0, delay_seconds: 0 },
+			ApprovalTier { up_to: 10_000, require_admin: true, approvals: 1, delay_seconds: 0 },
+			ApprovalTier { up_to: u64::MAX, require_admin: true, approvals: 2, delay_seconds },
+		],
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let oracle_pricing = OraclePricing {
+		price_feed: Pubkey::new_unique(),
+		max_staleness_seconds: 60,
+		max_confidence_bps: 100,
+		max_slippage_bps: 500,
+	};
+
+	let create_schedule_ix = |schedule_id: u64, amount: u64, max_executions: u64, approval_request: Option<Pubkey>, recent_blockhash| {
+		treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			schedule_pda(schedule_id),
+			&treasurer_keypair,
+			system_program::ID,
+			recipient_address_pubkey,
+			schedule_id,
+			amount,
+			None,
+			0,
+			0,
+			max_executions,
+			Recurrence::Interval,
+			0,
+			None,
+			&treasury_seed_name,
+			None,
+			approval_request,
+			recent_blockhash,
+		)
+	};
+
+	// The treasurer alone can create small schedules but not mid-size ones
+	let result = banks_client.process_transaction(create_schedule_ix(1, 1_000, 1, None, recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	let result = banks_client.process_transaction(create_schedule_ix(2, 5_000, 1, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Tiers cover a schedule's total payout, and unbounded schedules take the top tier
+	let result = banks_client.process_transaction(create_schedule_ix(2, 100, 20, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(create_schedule_ix(2, 100, 0, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Tiers in lamports cannot weigh a USD amount, so oracle pricing is refused
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(2),
+		&treasurer_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		2,
+		100,
+		None,
+		0,
+		0,
+		1,
+		Recurrence::Interval,
+		0,
+		Some(oracle_pricing),
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	// The admin's approval unlocks the mid-size schedule once
+	let ix = treasury_vault_ix_interface::create_approval_request_ix_setup(
+		treasury_pda,
+		request_pda(1),
+		&treasurer_keypair,
+		system_program::ID,
+		1,
+		ApprovalAction::CreatePayoutSchedule { recipient: recipient_pda, schedule_id: 2 },
+		5_000,
+		None,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// An approval from someone other than the admin is not enough
+	let ix = treasury_vault_ix_interface::approve_request_ix_setup(
+		treasury_pda,
+		request_pda(1),
+		&approver_keypair,
+		1,
+		&treasury_seed_name,
+		Some(approver_member_pda),
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(create_schedule_ix(2, 5_000, 1, Some(request_pda(1)), recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let ix = treasury_vault_ix_interface::approve_request_ix_setup(
+		treasury_pda,
+		request_pda(1),
+		&admin_keypair,
+		1,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(create_schedule_ix(2, 5_000, 1, Some(request_pda(1)), recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	let result = banks_client.process_transaction(create_schedule_ix(3, 5_000, 1, Some(request_pda(1)), recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Updates that raise a schedule's total payout go through the same tiers, those lowering it do not
+	let update_schedule_ix = |amount: Option<u64>, max_executions: Option<u64>, oracle_pricing: Option<Option<OraclePricing>>| {
+		treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			schedule_pda(1),
+			&treasurer_keypair,
+			recipient_address_pubkey,
+			1,
+			amount,
+			None,
+			None,
+			max_executions,
+			None,
+			None,
+			None,
+			None,
+			oracle_pricing,
+			None,
+			None,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let result = banks_client.process_transaction(update_schedule_ix(None, Some(5), None)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(update_schedule_ix(None, Some(0), None)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(update_schedule_ix(None, None, Some(Some(oracle_pricing)))).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(update_schedule_ix(Some(500), Some(2), None)).await;
+	assert!(result.is_ok());
+
+	// A large emergency withdrawal needs both approvers and then the delay
+	let ix = treasury_vault_ix_interface::create_approval_request_ix_setup(
+		treasury_pda,
+		request_pda(2),
+		&admin_keypair,
+		system_program::ID,
+		2,
+		ApprovalAction::EmergencyWithdrawSol,
+		50_000,
+		None,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	for (approver, member) in [(&admin_keypair, None), (&approver_keypair, Some(approver_member_pda))] {
+		let ix = treasury_vault_ix_interface::approve_request_ix_setup(
+			treasury_pda,
+			request_pda(2),
+			approver,
+			2,
+			&treasury_seed_name,
+			member,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	let withdraw_ix = |recent_blockhash| treasury_vault_ix_interface::emergency_withdraw_sol_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		50_000,
+		&treasury_seed_name,
+		None,
+		Some(request_pda(2)),
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(withdraw_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	clock.unix_timestamp += delay_seconds as i64;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = banks_client.process_transaction(withdraw_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let request_account = banks_client.get_account(request_pda(2)).await.unwrap().unwrap();
+	let request = ApprovalRequest::try_deserialize(&mut request_account.data.as_ref()).unwrap();
+	assert!(request.admin_approved);
+	assert_eq!(request.approvers, vec![admin_pubkey, approver_pubkey]);
+	assert!(request.is_executed);
+
+	// Requests can no longer be approved once their lifetime is over
+	let ix = treasury_vault_ix_interface::create_approval_request_ix_setup(
+		treasury_pda,
+		request_pda(3),
+		&admin_keypair,
+		system_program::ID,
+		3,
+		ApprovalAction::EmergencyWithdrawSol,
+		50_000,
+		None,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	clock.unix_timestamp += APPROVAL_REQUEST_LIFETIME_SECONDS;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = treasury_vault_ix_interface::approve_request_ix_setup(
+		treasury_pda,
+		request_pda(3),
+		&admin_keypair,
+		3,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+}
@@ -0,0 +1,154 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+    treasury_vault::{Recurrence, TreasuryConfig, TreasuryStatus},
+};
+
+
+#[tokio::test]
+async fn begin_decommission_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("event_treasury");
+	let schedule_id: u64 = 1;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let successor_pubkey = Pubkey::new_unique();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Per-event treasury"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Speaker"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::begin_decommission_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		successor_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.status, TreasuryStatus::WindingDown);
+	assert_eq!(treasury.successor, Some(successor_pubkey));
+
+	// New schedules are blocked while winding down
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		1_000,
+		None,
+		0,
+		0,
+		1,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+}
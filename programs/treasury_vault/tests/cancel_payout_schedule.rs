@@ -0,0 +1,375 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{PayoutSchedule, Recurrence, ScheduleStatus, TreasuryConfig},
+};
+
+
+#[tokio::test]
+async fn cancel_payout_schedule_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("payroll");
+	let payout_amount: u64 = 1_000;
+	let start_time: i64 = 1_736_499_600;
+	let interval_seconds: u64 = 1_000;
+	let reason: String = String::from("Contract terminated");
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let schedule_pda = |schedule_id: u64| Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [admin_pubkey, recipient_address_pubkey] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	clock.unix_timestamp = start_time;
+	context.set_sysvar(&clock);
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Payroll"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 2_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the payout can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 2_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Engineer"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Schedule 2 ends after its second period
+	for (schedule_id, end_time) in [(1u64, 0i64), (2, start_time + 1_500), (3, 0)] {
+		let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			schedule_pda(schedule_id),
+			&admin_keypair,
+			system_program::ID,
+			recipient_address_pubkey,
+			schedule_id,
+			payout_amount,
+			None,
+			start_time,
+			interval_seconds,
+			0,
+			Recurrence::Interval,
+			end_time,
+			None,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	let get_schedule = |account: Account| PayoutSchedule::try_deserialize(&mut account.data.as_ref()).unwrap();
+	let schedule = get_schedule(banks_client.get_account(schedule_pda(1)).await.unwrap().unwrap());
+	assert_eq!(schedule.status, ScheduleStatus::Pending);
+
+	// A paused schedule cannot pay out until resumed
+	let ix = treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(1),
+		&admin_keypair,
+		recipient_address_pubkey,
+		1,
+		None,
+		None,
+		None,
+		None,
+		Some(false),
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let schedule = get_schedule(banks_client.get_account(schedule_pda(1)).await.unwrap().unwrap());
+	assert_eq!(schedule.status, ScheduleStatus::Paused);
+
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(1),
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		1,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	// The reason is length-limited
+	let ix = treasury_vault_ix_interface::cancel_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(1),
+		&admin_keypair,
+		recipient_address_pubkey,
+		1,
+		&"x".repeat(65),
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::cancel_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(1),
+		&admin_keypair,
+		recipient_address_pubkey,
+		1,
+		&reason,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let schedule = get_schedule(banks_client.get_account(schedule_pda(1)).await.unwrap().unwrap());
+	assert_eq!(schedule.status, ScheduleStatus::Cancelled);
+	assert_eq!(schedule.cancelled_by, Some(admin_pubkey));
+	assert_eq!(schedule.cancelled_at, start_time);
+	assert_eq!(schedule.cancel_reason, reason);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.open_schedules, 2);
+
+	// Schedule 2 completes once both periods before its end time are paid
+	clock.unix_timestamp = start_time + 5_000;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let before = banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(2),
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		2,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+	let after = banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+	assert_eq!(after - before, 2 * payout_amount);
+
+	let schedule = get_schedule(banks_client.get_account(schedule_pda(2)).await.unwrap().unwrap());
+	assert_eq!(schedule.status, ScheduleStatus::Completed);
+
+	// Capping schedule 3 at the periods it has already paid completes it
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(3),
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		3,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(3),
+		&admin_keypair,
+		recipient_address_pubkey,
+		3,
+		None,
+		None,
+		None,
+		Some(1),
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let schedule = get_schedule(banks_client.get_account(schedule_pda(3)).await.unwrap().unwrap());
+	assert_eq!(schedule.status, ScheduleStatus::Completed);
+
+	// Completed and cancelled schedules can be neither revived nor cancelled
+	for schedule_id in [1u64, 2] {
+		let ix = treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			schedule_pda(schedule_id),
+			&admin_keypair,
+			recipient_address_pubkey,
+			schedule_id,
+			None,
+			None,
+			None,
+			None,
+			Some(true),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			recent_blockhash,
+		);
+		assert!(banks_client.process_transaction(ix).await.is_err());
+
+		let ix = treasury_vault_ix_interface::cancel_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			schedule_pda(schedule_id),
+			&admin_keypair,
+			recipient_address_pubkey,
+			schedule_id,
+			&reason,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		);
+		assert!(banks_client.process_transaction(ix).await.is_err());
+	}
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.open_schedules, 0);
+}
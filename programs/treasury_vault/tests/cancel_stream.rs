@@ -110,6 +110,7 @@ async fn cancel_stream_ix_success() {
 		recipient_address,
 		stream_id,
 		&treasury_seed_name,
+		None,
 		recent_blockhash,
 	);
 
@@ -0,0 +1,344 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::{AccountDeserialize, AnchorDeserialize},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{PayoutDue, PayoutSchedule, Recipient, Recurrence, ScheduleStatus, TreasuryConfig},
+};
+
+
+#[tokio::test]
+async fn claim_payout_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("claims");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_000_000;
+	let interval_seconds: u64 = 86_400;
+	let claim_window_seconds: u64 = 2 * 86_400;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = recipient_keypair.pubkey();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, recipient_address_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let start_time = clock.unix_timestamp;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Claimed payouts"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so payouts can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		None,
+		start_time,
+		interval_seconds,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let update_ix = |interval_seconds: Option<u64>, claim_window_seconds: Option<u64>, recent_blockhash| {
+		treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			&admin_keypair,
+			recipient_address_pubkey,
+			schedule_id,
+			None,
+			None,
+			interval_seconds,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			claim_window_seconds,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let claim_ix = |recent_blockhash| {
+		treasury_vault_ix_interface::claim_payout_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			&recipient_keypair,
+			recipient_address_pubkey,
+			schedule_id,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	// Schedules are executed, not claimed, until a claim window is set
+	let result = banks_client.process_transaction(claim_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(update_ix(None, Some(claim_window_seconds), recent_blockhash)).await.unwrap();
+
+	// Claim mode needs periods that fall due at distinct times
+	let result = banks_client.process_transaction(update_ix(Some(0), None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Claim mode schedules cannot be executed
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	// The first period is due at the start time
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	banks_client.process_transaction(claim_ix(recent_blockhash)).await.unwrap();
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(claim_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Five days on, the periods due on days one and two are past their window
+	clock.unix_timestamp = start_time + 5 * 86_400;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = treasury_vault_ix_interface::check_payout_due_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payout_schedule_pda,
+		schedule_id,
+		&treasury_seed_name,
+		recent_blockhash,
+	);
+	let simulation = banks_client.simulate_transaction(ix).await.unwrap();
+	let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+	let payout_due = PayoutDue::try_from_slice(&return_data.data).unwrap();
+	assert_eq!(payout_due.due_periods, 3);
+	assert_eq!(payout_due.next_due_time, Some(start_time + 3 * 86_400));
+	assert_eq!(payout_due.keeper_tip, 0);
+
+	let result = banks_client.process_transaction(claim_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payout_schedule_account = banks_client.get_account(payout_schedule_pda).await.unwrap().unwrap();
+	let payout_schedule = PayoutSchedule::try_deserialize(&mut payout_schedule_account.data.as_ref()).unwrap();
+	assert_eq!(payout_schedule.executions, 6);
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.total_received, 4 * payout_amount);
+
+	// Expired periods were never paid, so their amounts are still in the treasury
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.total_balance, 1_000_000_000 - 4 * payout_amount);
+
+	let expire_ix = |recent_blockhash| {
+		treasury_vault_ix_interface::expire_claim_periods_ix_setup(
+			&admin_keypair,
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			recipient_address_pubkey,
+			schedule_id,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	// Nothing has lapsed since the claim
+	let result = banks_client.process_transaction(expire_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Capped at eight periods, the last two lapse unclaimed and anyone may
+	// expire them, completing the schedule
+	let ix = treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		recipient_address_pubkey,
+		schedule_id,
+		None,
+		None,
+		None,
+		Some(8),
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	clock.unix_timestamp = start_time + 20 * 86_400;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	banks_client.process_transaction(expire_ix(recent_blockhash)).await.unwrap();
+
+	let payout_schedule_account = banks_client.get_account(payout_schedule_pda).await.unwrap().unwrap();
+	let payout_schedule = PayoutSchedule::try_deserialize(&mut payout_schedule_account.data.as_ref()).unwrap();
+	assert_eq!(payout_schedule.executions, 8);
+	assert_eq!(payout_schedule.status, ScheduleStatus::Completed);
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.active_schedules, 0);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.total_balance, 1_000_000_000 - 4 * payout_amount);
+	assert_eq!(treasury.open_schedules, 0);
+
+	// A completed schedule has nothing left to expire or claim
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(expire_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(claim_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+}
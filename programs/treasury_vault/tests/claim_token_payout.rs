@@ -0,0 +1,315 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+		csl_spl_token_ix_interface,
+		csl_spl_assoc_token_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    anchor_spl::{
+        associated_token::{get_associated_token_address, spl_associated_token_account},
+        token::{spl_token, TokenAccount},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{PayoutSchedule, Recurrence, TokenVault},
+};
+
+
+#[tokio::test]
+async fn claim_token_payout_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("token_claims");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_000_000;
+	let vault_amount: u64 = 10_000_000;
+	let interval_seconds: u64 = 86_400;
+	let claim_window_seconds: u64 = 2 * 86_400;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let vault_authority_keypair = Keypair::new();
+	let mint_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = recipient_keypair.pubkey();
+	let vault_authority_pubkey = vault_authority_keypair.pubkey();
+	let mint_pubkey = mint_keypair.pubkey();
+	let vault_token_account = get_associated_token_address(&vault_authority_pubkey, &mint_pubkey);
+	let recipient_token_account = get_associated_token_address(&recipient_address_pubkey, &mint_pubkey);
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (token_vault_pda, _token_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"token_vault",
+			treasury_pda.as_ref(),
+			mint_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_destination_pda, _payout_destination_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payout_destination",
+			recipient_pda.as_ref(),
+			mint_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, recipient_address_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let rent = banks_client.get_rent().await.unwrap();
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let start_time = clock.unix_timestamp;
+
+	let ix = Transaction::new_signed_with_payer(
+		&[
+			system_instruction::create_account(
+				&admin_pubkey,
+				&mint_pubkey,
+				rent.minimum_balance(spl_token::state::Mint::LEN),
+				spl_token::state::Mint::LEN as u64,
+				&spl_token::ID,
+			),
+			spl_token::instruction::initialize_mint(&spl_token::ID, &mint_pubkey, &admin_pubkey, None, 6).unwrap(),
+			spl_associated_token_account::instruction::create_associated_token_account(&admin_pubkey, &recipient_address_pubkey, &mint_pubkey, &spl_token::ID),
+		],
+		Some(&admin_pubkey),
+		&[&admin_keypair, &mint_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Claimed token payouts"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_token_vault_ix_setup(
+		treasury_pda,
+		token_vault_pda,
+		mint_pubkey,
+		&admin_keypair,
+		system_program::ID,
+		&admin_keypair,
+		vault_token_account,
+		vault_authority_pubkey,
+		mint_pubkey,
+		csl_spl_token_ix_interface::ID,
+		csl_spl_assoc_token_ix_interface::ID,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[spl_token::instruction::mint_to(&spl_token::ID, &mint_pubkey, &vault_token_account, &admin_pubkey, &[], vault_amount).unwrap()],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly minted tokens so claims can debit the vault balance
+	treasury_vault_ix_interface::book_token_vault_balance(&mut context, token_vault_pda, vault_amount).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		Some(mint_pubkey),
+		start_time,
+		interval_seconds,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Token schedules can be put in claim mode
+	let ix = treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		recipient_address_pubkey,
+		schedule_id,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		Some(claim_window_seconds),
+		&treasury_seed_name,
+		None,
+		Some(mint_pubkey),
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Token schedules are not claimed as SOL
+	let ix = treasury_vault_ix_interface::claim_payout_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&recipient_keypair,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let claim_ix = |destination: Pubkey, recent_blockhash| {
+		treasury_vault_ix_interface::claim_token_payout_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			&recipient_keypair,
+			token_vault_pda,
+			mint_pubkey,
+			vault_token_account,
+			destination,
+			&vault_authority_keypair,
+			csl_spl_token_ix_interface::ID,
+			schedule_id,
+			&treasury_seed_name,
+			payout_destination_pda,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	// Tokens only go to the recipient's bound payout token account
+	let result = banks_client.process_transaction(claim_ix(vault_token_account, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// The first period is due at the start time
+	banks_client.process_transaction(claim_ix(recipient_token_account, recent_blockhash)).await.unwrap();
+
+	// Five days on, the periods due on days one and two are past their window
+	clock.unix_timestamp = start_time + 5 * 86_400;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(claim_ix(recipient_token_account, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payout_schedule_account = banks_client.get_account(payout_schedule_pda).await.unwrap().unwrap();
+	let payout_schedule = PayoutSchedule::try_deserialize(&mut payout_schedule_account.data.as_ref()).unwrap();
+	assert_eq!(payout_schedule.executions, 6);
+
+	let recipient_account = banks_client.get_account(recipient_token_account).await.unwrap().unwrap();
+	let recipient_tokens = TokenAccount::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient_tokens.amount, 4 * payout_amount);
+
+	// Expired periods were never paid, so their amounts are still in the vault
+	let token_vault_account = banks_client.get_account(token_vault_pda).await.unwrap().unwrap();
+	let token_vault = TokenVault::try_deserialize(&mut token_vault_account.data.as_ref()).unwrap();
+	assert_eq!(token_vault.balance, vault_amount - 4 * payout_amount);
+}
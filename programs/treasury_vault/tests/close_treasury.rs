@@ -0,0 +1,121 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction,
+        system_program, transaction::Transaction,
+    },
+};
+
+
+#[tokio::test]
+async fn close_treasury_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("event_treasury");
+	let deposit_amount: u64 = 2_000_000_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let successor_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let recent_blockhash = context.last_blockhash;
+	let banks_client = context.banks_client.clone();
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Per-event treasury"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, deposit_amount)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Closing is refused while the treasury is still active
+	let ix = treasury_vault_ix_interface::close_treasury_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		successor_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::begin_decommission_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		successor_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let treasury_lamports = banks_client.get_balance(treasury_pda).await.unwrap();
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = treasury_vault_ix_interface::close_treasury_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		successor_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(banks_client.get_account(treasury_pda).await.unwrap().is_none());
+	assert_eq!(banks_client.get_balance(successor_pubkey).await.unwrap(), treasury_lamports);
+	assert!(treasury_lamports > deposit_amount);
+}
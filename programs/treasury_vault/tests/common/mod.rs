@@ -44,13 +44,27 @@ pub mod treasury_vault_ix_interface {
 			signature::{Keypair, Signer},
 			instruction::Instruction,
 			pubkey::Pubkey,
+			sysvar,
 			transaction::Transaction,
 		},
 		treasury_vault::{
 			ID as PROGRAM_ID,
 			accounts as treasury_vault_accounts,
 			instruction as treasury_vault_instruction,
+			ApprovalAction,
+			ApprovalTier,
+			DeactivationPolicy,
+			KeeperMode,
+			MilestoneTerms,
+			OraclePricing,
+			PayoutSplit,
+			PolicyRule,
+			RecipientCaps,
+			Recurrence,
+			TokenVault,
+			TreasuryConfig,
 		},
+		solana_program_test::ProgramTestContext,
 		anchor_lang::{
 			prelude::*,
 			InstructionData,
@@ -59,6 +73,60 @@ pub mod treasury_vault_ix_interface {
 
 	pub use treasury_vault::ID;
 
+	pub fn approval_policy_pda(treasury: Pubkey, token_mint: Option<Pubkey>) -> Pubkey {
+		Pubkey::find_program_address(
+			&[
+				b"approval_policy",
+				treasury.as_ref(),
+				token_mint.unwrap_or_default().as_ref(),
+			],
+			&PROGRAM_ID,
+		).0
+	}
+
+	pub fn policy_pda(treasury: Pubkey, token_mint: Option<Pubkey>) -> Pubkey {
+		Pubkey::find_program_address(
+			&[
+				b"policy",
+				treasury.as_ref(),
+				token_mint.unwrap_or_default().as_ref(),
+			],
+			&PROGRAM_ID,
+		).0
+	}
+
+	pub fn governance_pda(treasury: Pubkey) -> Pubkey {
+		Pubkey::find_program_address(
+			&[
+				b"governance",
+				treasury.as_ref(),
+			],
+			&PROGRAM_ID,
+		).0
+	}
+
+	/// Set the treasury's total_balance, booking lamports transferred to it directly
+	pub async fn book_total_balance(context: &mut ProgramTestContext, treasury: Pubkey, total_balance: u64) {
+		let mut treasury_account = context.banks_client.get_account(treasury).await.unwrap().unwrap();
+		let mut treasury_config = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+		treasury_config.total_balance = total_balance;
+		let mut data = Vec::new();
+		treasury_config.try_serialize(&mut data).unwrap();
+		treasury_account.data[..data.len()].copy_from_slice(&data);
+		context.set_account(&treasury, &treasury_account.into());
+	}
+
+	/// Set the token vault's balance, booking tokens minted to its account directly
+	pub async fn book_token_vault_balance(context: &mut ProgramTestContext, token_vault: Pubkey, balance: u64) {
+		let mut token_vault_account = context.banks_client.get_account(token_vault).await.unwrap().unwrap();
+		let mut token_vault_config = TokenVault::try_deserialize(&mut token_vault_account.data.as_ref()).unwrap();
+		token_vault_config.balance = balance;
+		let mut data = Vec::new();
+		token_vault_config.try_serialize(&mut data).unwrap();
+		token_vault_account.data[..data.len()].copy_from_slice(&data);
+		context.set_account(&token_vault, &token_vault_account.into());
+	}
+
 	pub fn initialize_treasury_ix_setup(
 		admin: &Keypair,
 		treasury: Pubkey,
@@ -115,12 +183,23 @@ pub mod treasury_vault_ix_interface {
 		require_token_gate: Option<bool>,
 		token_gate_mint: Option<Pubkey>,
 		token_gate_amount: Option<u64>,
+		deactivation_policy: Option<DeactivationPolicy>,
+		attester: Option<Pubkey>,
+		require_payout_cosign: Option<bool>,
+		skip_missed_payouts: Option<bool>,
+		cooling_period_seconds: Option<u64>,
+		cooling_payout_cap: Option<u64>,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::UpdateTreasuryConfig {
 			treasury: treasury,
 			admin: admin.pubkey(),
+			authority_member: authority_member,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
 		};
 
 		let data = 	treasury_vault_instruction::UpdateTreasuryConfig {
@@ -133,6 +212,12 @@ pub mod treasury_vault_ix_interface {
 				require_token_gate,
 				token_gate_mint,
 				token_gate_amount,
+				deactivation_policy,
+				attester,
+				require_payout_cosign,
+				skip_missed_payouts,
+				cooling_period_seconds,
+				cooling_payout_cap,
 				_treasury_seed_name: treasury_seed_name.clone(),
 		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
@@ -188,6 +273,7 @@ pub mod treasury_vault_ix_interface {
 		token_program: Pubkey,
 		associated_token_program: Pubkey,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::InitializeTokenVault {
@@ -202,6 +288,7 @@ pub mod treasury_vault_ix_interface {
 			mint: mint,
 			token_program: token_program,
 			associated_token_program: associated_token_program,
+			authority_member: authority_member,
 		};
 
 		let data = 	treasury_vault_instruction::InitializeTokenVault {
@@ -269,10 +356,14 @@ pub mod treasury_vault_ix_interface {
 		recipient_address: Pubkey,
 		name: &String,
 		role: u8,
+		valid_until: i64,
+		attestation_ref: [u8; 32],
 		treasury_seed_name: &String,
 		token_gate_mint: Option<Pubkey>,
 		recipient_token_account: Option<Pubkey>,
 		token_program: Option<Pubkey>,
+		attestation: Option<Instruction>,
+		authority_member: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::AddRecipient {
@@ -283,16 +374,58 @@ pub mod treasury_vault_ix_interface {
 			token_gate_mint: token_gate_mint,
 			recipient_token_account: recipient_token_account,
 			token_program: token_program,
+			instructions_sysvar: attestation.as_ref().map(|_| sysvar::instructions::ID),
+			authority_member: authority_member,
 		};
 
 		let data = 	treasury_vault_instruction::AddRecipient {
 				recipient_address,
 				name: name.clone(),
 				role,
+				valid_until,
+				attestation_ref,
 				_treasury_seed_name: treasury_seed_name.clone(),
 		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
-			&[instruction], 
+			&attestation.into_iter().chain([instruction]).collect::<Vec<_>>(), 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn renew_recipient_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		authority: &Keypair,
+		recipient_address: Pubkey,
+		valid_until: i64,
+		attestation_ref: [u8; 32],
+		treasury_seed_name: &String,
+		attestation: Option<Instruction>,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::RenewRecipient {
+			treasury: treasury,
+			recipient: recipient,
+			authority: authority.pubkey(),
+			instructions_sysvar: attestation.as_ref().map(|_| sysvar::instructions::ID),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::RenewRecipient {
+				recipient_address,
+				valid_until,
+				attestation_ref,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&attestation.into_iter().chain([instruction]).collect::<Vec<_>>(), 
 			Some(&authority.pubkey()),
 		);
 
@@ -311,13 +444,16 @@ pub mod treasury_vault_ix_interface {
 		name: Option<String>,
 		role: Option<u8>,
 		is_active: Option<bool>,
+		caps: Option<RecipientCaps>,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::UpdateRecipient {
 			treasury: treasury,
 			recipient: recipient,
 			authority: authority.pubkey(),
+			authority_member: authority_member,
 		};
 
 		let data = 	treasury_vault_instruction::UpdateRecipient {
@@ -325,6 +461,7 @@ pub mod treasury_vault_ix_interface {
 				name,
 				role,
 				is_active,
+				caps,
 				_treasury_seed_name: treasury_seed_name.clone(),
 		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
@@ -352,7 +489,12 @@ pub mod treasury_vault_ix_interface {
 		start_time: i64,
 		interval_seconds: u64,
 		max_executions: u64,
+		recurrence: Recurrence,
+		end_time: i64,
+		oracle_pricing: Option<OraclePricing>,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::CreatePayoutSchedule {
@@ -361,6 +503,9 @@ pub mod treasury_vault_ix_interface {
 			payout_schedule: payout_schedule,
 			authority: authority.pubkey(),
 			system_program: system_program,
+			authority_member: authority_member,
+			approval_policy: approval_policy_pda(treasury, token_mint),
+			approval_request: approval_request,
 		};
 
 		let data = 	treasury_vault_instruction::CreatePayoutSchedule {
@@ -371,6 +516,9 @@ pub mod treasury_vault_ix_interface {
 				start_time,
 				interval_seconds,
 				max_executions,
+				recurrence,
+				end_time,
+				oracle_pricing,
 				_treasury_seed_name: treasury_seed_name.clone(),
 		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
@@ -397,7 +545,16 @@ pub mod treasury_vault_ix_interface {
 		interval_seconds: Option<u64>,
 		max_executions: Option<u64>,
 		is_active: Option<bool>,
+		recurrence: Option<Recurrence>,
+		end_time: Option<i64>,
+		splits: Option<Vec<PayoutSplit>>,
+		oracle_pricing: Option<Option<OraclePricing>>,
+		priority: Option<u8>,
+		claim_window_seconds: Option<u64>,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		token_mint: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::UpdatePayoutSchedule {
@@ -405,6 +562,9 @@ pub mod treasury_vault_ix_interface {
 			recipient: recipient,
 			payout_schedule: payout_schedule,
 			authority: authority.pubkey(),
+			authority_member: authority_member,
+			approval_policy: approval_policy_pda(treasury, token_mint),
+			approval_request: approval_request,
 		};
 
 		let data = 	treasury_vault_instruction::UpdatePayoutSchedule {
@@ -415,6 +575,50 @@ pub mod treasury_vault_ix_interface {
 				interval_seconds,
 				max_executions,
 				is_active,
+				recurrence,
+				end_time,
+				splits,
+				oracle_pricing,
+				priority,
+				claim_window_seconds,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cancel_payout_schedule_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payout_schedule: Pubkey,
+		authority: &Keypair,
+		recipient_address: Pubkey,
+		schedule_id: u64,
+		reason: &String,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CancelPayoutSchedule {
+			treasury: treasury,
+			recipient: recipient,
+			payout_schedule: payout_schedule,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::CancelPayoutSchedule {
+				recipient_address,
+				schedule_id,
+				reason: reason.clone(),
 				_treasury_seed_name: treasury_seed_name.clone(),
 		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
@@ -435,8 +639,13 @@ pub mod treasury_vault_ix_interface {
 		recipient: Pubkey,
 		payout_schedule: Pubkey,
 		recipient_address: Pubkey,
+		payout_address: Pubkey,
 		schedule_id: u64,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		price_feed: Option<Pubkey>,
+		payout_queue: Option<Pubkey>,
+		split_accounts: &[Pubkey],
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::ExecuteSolPayout {
@@ -445,12 +654,20 @@ pub mod treasury_vault_ix_interface {
 			recipient: recipient,
 			payout_schedule: payout_schedule,
 			recipient_address: recipient_address,
+			payout_address: payout_address,
+			policy: policy_pda(treasury, None),
+			authority_member: authority_member,
+			price_feed: price_feed,
+			payout_queue: payout_queue,
 		};
 
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(split_accounts.iter().map(|account| AccountMeta::new(*account, false)));
+
 		let data = 	treasury_vault_instruction::ExecuteSolPayout {
 				schedule_id,
 				_treasury_seed_name: treasury_seed_name.clone(),
-		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
 		let mut transaction = Transaction::new_with_payer(
 			&[instruction], 
 			Some(&fee_payer.pubkey()),
@@ -477,6 +694,10 @@ pub mod treasury_vault_ix_interface {
 		recipient_address: Pubkey,
 		schedule_id: u64,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		payout_destination: Pubkey,
+		price_feed: Option<Pubkey>,
+		split_accounts: &[Pubkey],
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::ExecuteTokenPayout {
@@ -490,13 +711,20 @@ pub mod treasury_vault_ix_interface {
 			destination: destination,
 			authority: authority.pubkey(),
 			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			authority_member: authority_member,
+			payout_destination: payout_destination,
+			policy: policy_pda(treasury, Some(token_mint)),
+			price_feed: price_feed,
 		};
 
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(split_accounts.iter().map(|account| AccountMeta::new(*account, false)));
+
 		let data = 	treasury_vault_instruction::ExecuteTokenPayout {
 				recipient_address,
 				schedule_id,
 				_treasury_seed_name: treasury_seed_name.clone(),
-		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
 		let mut transaction = Transaction::new_with_payer(
 			&[instruction], 
 			Some(&fee_payer.pubkey()),
@@ -515,11 +743,16 @@ pub mod treasury_vault_ix_interface {
 		admin: &Keypair,
 		amount: u64,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::EmergencyWithdrawSol {
 			treasury: treasury,
 			admin: admin.pubkey(),
+			authority_member: authority_member,
+			approval_policy: approval_policy_pda(treasury, None),
+			approval_request: approval_request,
 		};
 
 		let data = 	treasury_vault_instruction::EmergencyWithdrawSol {
@@ -549,6 +782,8 @@ pub mod treasury_vault_ix_interface {
 		csl_spl_token_v0_0_0: Pubkey,
 		amount: u64,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::EmergencyWithdrawToken {
@@ -560,6 +795,9 @@ pub mod treasury_vault_ix_interface {
 			destination: destination,
 			authority: authority.pubkey(),
 			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			authority_member: authority_member,
+			approval_policy: approval_policy_pda(treasury, Some(token_mint)),
+			approval_request: approval_request,
 		};
 
 		let data = 	treasury_vault_instruction::EmergencyWithdrawToken {
@@ -594,6 +832,8 @@ pub mod treasury_vault_ix_interface {
 		duration_seconds: u64,
 		token_mint: Option<Pubkey>,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::CreateStreamingSchedule {
@@ -602,6 +842,9 @@ pub mod treasury_vault_ix_interface {
 			streaming_schedule: streaming_schedule,
 			authority: authority.pubkey(),
 			system_program: system_program,
+			authority_member: authority_member,
+			approval_policy: approval_policy_pda(treasury, token_mint),
+			approval_request: approval_request,
 		};
 
 		let data = 	treasury_vault_instruction::CreateStreamingSchedule {
@@ -636,6 +879,7 @@ pub mod treasury_vault_ix_interface {
 		recipient_address: Pubkey,
 		stream_id: u64,
 		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = treasury_vault_accounts::CancelStream {
@@ -644,6 +888,7 @@ pub mod treasury_vault_ix_interface {
 			streaming_schedule: streaming_schedule,
 			authority: authority.pubkey(),
 			recipient_wallet: recipient_wallet,
+			authority_member: authority_member,
 		};
 
 		let data = 	treasury_vault_instruction::CancelStream {
@@ -668,6 +913,7 @@ pub mod treasury_vault_ix_interface {
 		recipient: Pubkey,
 		streaming_schedule: Pubkey,
 		recipient_signer: &Keypair,
+		payout_address: Pubkey,
 		stream_id: u64,
 		treasury_seed_name: &String,
 		recent_blockhash: Hash,
@@ -677,6 +923,8 @@ pub mod treasury_vault_ix_interface {
 			recipient: recipient,
 			streaming_schedule: streaming_schedule,
 			recipient_signer: recipient_signer.pubkey(),
+			payout_address: payout_address,
+			policy: policy_pda(treasury, None),
 		};
 
 		let data = 	treasury_vault_instruction::WithdrawFromStream {
@@ -695,6 +943,2274 @@ pub mod treasury_vault_ix_interface {
 		return transaction;
 	}
 
+
+	pub fn begin_decommission_ix_setup(
+		treasury: Pubkey,
+		admin: &Keypair,
+		successor: Pubkey,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::BeginDecommission {
+			treasury: treasury,
+			admin: admin.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::BeginDecommission {
+				successor,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&admin.pubkey()),
+		);
+
+		transaction.sign(&[
+			&admin,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn sweep_token_vault_ix_setup(
+		treasury: Pubkey,
+		token_vault: Pubkey,
+		token_mint: Pubkey,
+		admin: &Keypair,
+		source: Pubkey,
+		destination: Pubkey,
+		successor: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SweepTokenVault {
+			treasury: treasury,
+			token_vault: token_vault,
+			token_mint: token_mint,
+			admin: admin.pubkey(),
+			source: source,
+			destination: destination,
+			successor: successor,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::SweepTokenVault {
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&admin.pubkey()),
+		);
+
+		transaction.sign(&[
+			&admin,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn close_treasury_ix_setup(
+		treasury: Pubkey,
+		admin: &Keypair,
+		successor: Pubkey,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CloseTreasury {
+			treasury: treasury,
+			admin: admin.pubkey(),
+			successor: successor,
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::CloseTreasury {
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&admin.pubkey()),
+		);
+
+		transaction.sign(&[
+			&admin,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+
+	pub fn deactivate_recipient_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		authority: &Keypair,
+		recipient_address: Pubkey,
+		schedules: &[Pubkey],
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::DeactivateRecipient {
+			treasury: treasury,
+			recipient: recipient,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(schedules.iter().map(|schedule| AccountMeta::new(*schedule, false)));
+
+		let data = 	treasury_vault_instruction::DeactivateRecipient {
+				recipient_address,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn reactivate_recipient_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		authority: &Keypair,
+		recipient_address: Pubkey,
+		schedules: &[Pubkey],
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ReactivateRecipient {
+			treasury: treasury,
+			recipient: recipient,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(schedules.iter().map(|schedule| AccountMeta::new(*schedule, false)));
+
+		let data = 	treasury_vault_instruction::ReactivateRecipient {
+				recipient_address,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn add_member_ix_setup(
+		treasury: Pubkey,
+		member: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		member_address: Pubkey,
+		permissions: u16,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::AddMember {
+			treasury: treasury,
+			member: member,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			system_program: system_program,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::AddMember {
+				member_address,
+				permissions,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn update_member_ix_setup(
+		treasury: Pubkey,
+		member: Pubkey,
+		authority: &Keypair,
+		member_address: Pubkey,
+		permissions: u16,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::UpdateMember {
+			treasury: treasury,
+			member: member,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::UpdateMember {
+				member_address,
+				permissions,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn remove_member_ix_setup(
+		treasury: Pubkey,
+		member: Pubkey,
+		authority: &Keypair,
+		member_address: Pubkey,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::RemoveMember {
+			treasury: treasury,
+			member: member,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::RemoveMember {
+				member_address,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_payout_address_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		recipient_signer: &Keypair,
+		cosigner: Option<&Keypair>,
+		cosigner_member: Option<Pubkey>,
+		recipient_address: Pubkey,
+		payout_address: Pubkey,
+		treasury_seed_name: &String,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SetPayoutAddress {
+			treasury: treasury,
+			recipient: recipient,
+			recipient_signer: recipient_signer.pubkey(),
+			cosigner: cosigner.map(|c| c.pubkey()),
+			cosigner_member: cosigner_member,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::SetPayoutAddress {
+				recipient_address,
+				payout_address,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&recipient_signer.pubkey()),
+		);
+
+		let mut signers = vec![recipient_signer];
+		signers.extend(cosigner);
+		transaction.sign(&signers, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_payout_token_account_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payout_destination: Pubkey,
+		token_mint: Pubkey,
+		payout_token_account: Pubkey,
+		recipient_signer: &Keypair,
+		cosigner: Option<&Keypair>,
+		cosigner_member: Option<Pubkey>,
+		system_program: Pubkey,
+		recipient_address: Pubkey,
+		treasury_seed_name: &String,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SetPayoutTokenAccount {
+			treasury: treasury,
+			recipient: recipient,
+			payout_destination: payout_destination,
+			token_mint: token_mint,
+			payout_token_account: payout_token_account,
+			recipient_signer: recipient_signer.pubkey(),
+			cosigner: cosigner.map(|c| c.pubkey()),
+			cosigner_member: cosigner_member,
+			system_program: system_program,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::SetPayoutTokenAccount {
+				recipient_address,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&recipient_signer.pubkey()),
+		);
+
+		let mut signers = vec![recipient_signer];
+		signers.extend(cosigner);
+		transaction.sign(&signers, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_approval_policy_ix_setup(
+		treasury: Pubkey,
+		approval_policy: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		token_mint: Option<Pubkey>,
+		tiers: Vec<ApprovalTier>,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SetApprovalPolicy {
+			treasury: treasury,
+			approval_policy: approval_policy,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			system_program: system_program,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::SetApprovalPolicy {
+				token_mint,
+				tiers,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_approval_request_ix_setup(
+		treasury: Pubkey,
+		approval_request: Pubkey,
+		proposer: &Keypair,
+		system_program: Pubkey,
+		request_id: u64,
+		action: ApprovalAction,
+		amount: u64,
+		token_mint: Option<Pubkey>,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CreateApprovalRequest {
+			treasury: treasury,
+			approval_request: approval_request,
+			proposer: proposer.pubkey(),
+			authority_member: authority_member,
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::CreateApprovalRequest {
+				request_id,
+				action,
+				amount,
+				token_mint,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&proposer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&proposer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_request_ix_setup(
+		treasury: Pubkey,
+		approval_request: Pubkey,
+		approver: &Keypair,
+		request_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ApproveRequest {
+			treasury: treasury,
+			approval_request: approval_request,
+			approver: approver.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::ApproveRequest {
+				request_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&approver.pubkey()),
+		);
+
+		transaction.sign(&[
+			&approver,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_policy_ix_setup(
+		treasury: Pubkey,
+		policy: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		token_mint: Option<Pubkey>,
+		rules: Vec<PolicyRule>,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SetPolicy {
+			treasury: treasury,
+			policy: policy,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			system_program: system_program,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::SetPolicy {
+				token_mint,
+				rules,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_reserve_floor_ix_setup(
+		treasury: Pubkey,
+		token_vault: Option<Pubkey>,
+		authority: &Keypair,
+		reserve_floor: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SetReserveFloor {
+			treasury: treasury,
+			token_vault: token_vault,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::SetReserveFloor {
+				reserve_floor,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn initialize_payout_queue_ix_setup(
+		treasury: Pubkey,
+		payout_queue: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::InitializePayoutQueue {
+			treasury: treasury,
+			payout_queue: payout_queue,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::InitializePayoutQueue {
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn drain_queue_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		payout_queue: Pubkey,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		payout_accounts: &[Pubkey],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::DrainQueue {
+			fee_payer: fee_payer.pubkey(),
+			treasury: treasury,
+			payout_queue: payout_queue,
+			policy: policy_pda(treasury, None),
+			authority_member: authority_member,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(payout_accounts.iter().map(|account| AccountMeta::new(*account, false)));
+
+		let data = 	treasury_vault_instruction::DrainQueue {
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn prune_queued_payout_ix_setup(
+		treasury: Pubkey,
+		payout_queue: Pubkey,
+		authority: &Keypair,
+		sequence: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::PruneQueuedPayout {
+			treasury: treasury,
+			payout_queue: payout_queue,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::PruneQueuedPayout {
+				sequence,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_keeper_config_ix_setup(
+		treasury: Pubkey,
+		authority: &Keypair,
+		keeper_mode: KeeperMode,
+		keeper_tip: u64,
+		keeper_tip_period_seconds: u64,
+		keeper_tip_period_cap: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SetKeeperConfig {
+			treasury: treasury,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			governance: governance_pda(treasury),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::SetKeeperConfig {
+				keeper_mode,
+				keeper_tip,
+				keeper_tip_period_seconds,
+				keeper_tip_period_cap,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn check_payout_due_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		payout_schedule: Pubkey,
+		schedule_id: u64,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CheckPayoutDue {
+			treasury: treasury,
+			payout_schedule: payout_schedule,
+		};
+
+		let data = 	treasury_vault_instruction::CheckPayoutDue {
+				_schedule_id: schedule_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claim_payout_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payout_schedule: Pubkey,
+		recipient_signer: &Keypair,
+		payout_address: Pubkey,
+		schedule_id: u64,
+		treasury_seed_name: &String,
+		price_feed: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ClaimPayout {
+			treasury: treasury,
+			recipient: recipient,
+			payout_schedule: payout_schedule,
+			recipient_signer: recipient_signer.pubkey(),
+			payout_address: payout_address,
+			policy: policy_pda(treasury, None),
+			price_feed: price_feed,
+		};
+
+		let data = 	treasury_vault_instruction::ClaimPayout {
+				schedule_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&recipient_signer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&recipient_signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claim_token_payout_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payout_schedule: Pubkey,
+		recipient_signer: &Keypair,
+		token_vault: Pubkey,
+		token_mint: Pubkey,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		schedule_id: u64,
+		treasury_seed_name: &String,
+		payout_destination: Pubkey,
+		price_feed: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ClaimTokenPayout {
+			treasury: treasury,
+			recipient: recipient,
+			payout_schedule: payout_schedule,
+			recipient_signer: recipient_signer.pubkey(),
+			token_vault: token_vault,
+			token_mint: token_mint,
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			payout_destination: payout_destination,
+			policy: policy_pda(treasury, Some(token_mint)),
+			price_feed: price_feed,
+		};
+
+		let data = 	treasury_vault_instruction::ClaimTokenPayout {
+				schedule_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&recipient_signer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&recipient_signer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn expire_claim_periods_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payout_schedule: Pubkey,
+		recipient_address: Pubkey,
+		schedule_id: u64,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ExpireClaimPeriods {
+			fee_payer: fee_payer.pubkey(),
+			treasury: treasury,
+			recipient: recipient,
+			payout_schedule: payout_schedule,
+		};
+
+		let data = 	treasury_vault_instruction::ExpireClaimPeriods {
+				recipient_address,
+				schedule_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn submit_payment_request_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payment_request: Pubkey,
+		recipient_signer: &Keypair,
+		system_program: Pubkey,
+		request_id: u64,
+		amount: u64,
+		token_mint: Option<Pubkey>,
+		due_date: i64,
+		invoice_hash: [u8; 32],
+		invoice_uri: &String,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SubmitPaymentRequest {
+			treasury: treasury,
+			recipient: recipient,
+			payment_request: payment_request,
+			recipient_signer: recipient_signer.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::SubmitPaymentRequest {
+				request_id,
+				amount,
+				token_mint,
+				due_date,
+				invoice_hash,
+				invoice_uri: invoice_uri.clone(),
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&recipient_signer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&recipient_signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_sol_payment_request_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payment_request: Pubkey,
+		payout_address: Pubkey,
+		reviewer: &Keypair,
+		request_id: u64,
+		amount: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ApproveSolPaymentRequest {
+			treasury: treasury,
+			recipient: recipient,
+			payment_request: payment_request,
+			payout_address: payout_address,
+			reviewer: reviewer.pubkey(),
+			authority_member: authority_member,
+			policy: policy_pda(treasury, None),
+			approval_policy: approval_policy_pda(treasury, None),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::ApproveSolPaymentRequest {
+				_request_id: request_id,
+				amount,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&reviewer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&reviewer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_token_payment_request_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payment_request: Pubkey,
+		token_vault: Pubkey,
+		token_mint: Pubkey,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		reviewer: &Keypair,
+		request_id: u64,
+		amount: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		payout_destination: Pubkey,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ApproveTokenPaymentRequest {
+			treasury: treasury,
+			recipient: recipient,
+			payment_request: payment_request,
+			token_vault: token_vault,
+			token_mint: token_mint,
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			reviewer: reviewer.pubkey(),
+			authority_member: authority_member,
+			payout_destination: payout_destination,
+			policy: policy_pda(treasury, Some(token_mint)),
+			approval_policy: approval_policy_pda(treasury, Some(token_mint)),
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::ApproveTokenPaymentRequest {
+				_request_id: request_id,
+				amount,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&reviewer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&reviewer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn reject_payment_request_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		payment_request: Pubkey,
+		reviewer: &Keypair,
+		request_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::RejectPaymentRequest {
+			treasury: treasury,
+			recipient: recipient,
+			payment_request: payment_request,
+			reviewer: reviewer.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::RejectPaymentRequest {
+				_request_id: request_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&reviewer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&reviewer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_milestone_grant_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		milestone_grant: Pubkey,
+		token_vault: Option<Pubkey>,
+		authority: &Keypair,
+		system_program: Pubkey,
+		grant_id: u64,
+		token_mint: Option<Pubkey>,
+		milestones: Vec<MilestoneTerms>,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CreateMilestoneGrant {
+			treasury: treasury,
+			recipient: recipient,
+			milestone_grant: milestone_grant,
+			token_vault: token_vault,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			approval_policy: approval_policy_pda(treasury, token_mint),
+			approval_request: approval_request,
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::CreateMilestoneGrant {
+				grant_id,
+				token_mint,
+				milestones,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn submit_milestone_evidence_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		milestone_grant: Pubkey,
+		recipient_signer: &Keypair,
+		grant_id: u64,
+		milestone_index: u8,
+		evidence_hash: [u8; 32],
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SubmitMilestoneEvidence {
+			treasury: treasury,
+			recipient: recipient,
+			milestone_grant: milestone_grant,
+			recipient_signer: recipient_signer.pubkey(),
+		};
+
+		let data = 	treasury_vault_instruction::SubmitMilestoneEvidence {
+				_grant_id: grant_id,
+				milestone_index,
+				evidence_hash,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&recipient_signer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&recipient_signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_milestone_ix_setup(
+		treasury: Pubkey,
+		milestone_grant: Pubkey,
+		approver: &Keypair,
+		grant_id: u64,
+		milestone_index: u8,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ApproveMilestone {
+			treasury: treasury,
+			milestone_grant: milestone_grant,
+			approver: approver.pubkey(),
+		};
+
+		let data = 	treasury_vault_instruction::ApproveMilestone {
+				_grant_id: grant_id,
+				milestone_index,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&approver.pubkey()),
+		);
+
+		transaction.sign(&[
+			&approver,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn release_sol_milestone_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		recipient: Pubkey,
+		milestone_grant: Pubkey,
+		payout_address: Pubkey,
+		grant_id: u64,
+		milestone_index: u8,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ReleaseSolMilestone {
+			fee_payer: fee_payer.pubkey(),
+			treasury: treasury,
+			recipient: recipient,
+			milestone_grant: milestone_grant,
+			payout_address: payout_address,
+			policy: policy_pda(treasury, None),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::ReleaseSolMilestone {
+				_grant_id: grant_id,
+				milestone_index,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn release_token_milestone_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		recipient: Pubkey,
+		milestone_grant: Pubkey,
+		token_vault: Pubkey,
+		token_mint: Pubkey,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		grant_id: u64,
+		milestone_index: u8,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		payout_destination: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ReleaseTokenMilestone {
+			fee_payer: fee_payer.pubkey(),
+			treasury: treasury,
+			recipient: recipient,
+			milestone_grant: milestone_grant,
+			token_vault: token_vault,
+			token_mint: token_mint,
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			authority_member: authority_member,
+			payout_destination: payout_destination,
+			policy: policy_pda(treasury, Some(token_mint)),
+		};
+
+		let data = 	treasury_vault_instruction::ReleaseTokenMilestone {
+				_grant_id: grant_id,
+				milestone_index,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn reclaim_milestone_ix_setup(
+		treasury: Pubkey,
+		milestone_grant: Pubkey,
+		token_vault: Option<Pubkey>,
+		authority: &Keypair,
+		grant_id: u64,
+		milestone_index: u8,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ReclaimMilestone {
+			treasury: treasury,
+			milestone_grant: milestone_grant,
+			token_vault: token_vault,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::ReclaimMilestone {
+				_grant_id: grant_id,
+				milestone_index,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_escrow_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		escrow: Pubkey,
+		token_vault: Option<Pubkey>,
+		authority: &Keypair,
+		system_program: Pubkey,
+		escrow_id: u64,
+		token_mint: Option<Pubkey>,
+		amount: u64,
+		arbitrator: Pubkey,
+		release_time: i64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CreateEscrow {
+			treasury: treasury,
+			recipient: recipient,
+			escrow: escrow,
+			token_vault: token_vault,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			approval_policy: approval_policy_pda(treasury, token_mint),
+			approval_request: approval_request,
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::CreateEscrow {
+				escrow_id,
+				token_mint,
+				amount,
+				arbitrator,
+				release_time,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn dispute_escrow_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		escrow: Pubkey,
+		disputer: &Keypair,
+		escrow_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::DisputeEscrow {
+			treasury: treasury,
+			recipient: recipient,
+			escrow: escrow,
+			disputer: disputer.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::DisputeEscrow {
+				_escrow_id: escrow_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&disputer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&disputer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn release_sol_escrow_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		recipient: Pubkey,
+		escrow: Pubkey,
+		payout_address: Pubkey,
+		escrow_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ReleaseSolEscrow {
+			fee_payer: fee_payer.pubkey(),
+			treasury: treasury,
+			recipient: recipient,
+			escrow: escrow,
+			payout_address: payout_address,
+			policy: policy_pda(treasury, None),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::ReleaseSolEscrow {
+				_escrow_id: escrow_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn release_token_escrow_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		recipient: Pubkey,
+		escrow: Pubkey,
+		token_vault: Pubkey,
+		token_mint: Pubkey,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		escrow_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		payout_destination: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ReleaseTokenEscrow {
+			fee_payer: fee_payer.pubkey(),
+			treasury: treasury,
+			recipient: recipient,
+			escrow: escrow,
+			token_vault: token_vault,
+			token_mint: token_mint,
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			authority_member: authority_member,
+			payout_destination: payout_destination,
+			policy: policy_pda(treasury, Some(token_mint)),
+		};
+
+		let data = 	treasury_vault_instruction::ReleaseTokenEscrow {
+				_escrow_id: escrow_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn resolve_sol_escrow_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		escrow: Pubkey,
+		payout_address: Pubkey,
+		arbitrator: &Keypair,
+		escrow_id: u64,
+		vendor_amount: u64,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ResolveSolEscrow {
+			treasury: treasury,
+			recipient: recipient,
+			escrow: escrow,
+			payout_address: payout_address,
+			arbitrator: arbitrator.pubkey(),
+		};
+
+		let data = 	treasury_vault_instruction::ResolveSolEscrow {
+				_escrow_id: escrow_id,
+				vendor_amount,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&arbitrator.pubkey()),
+		);
+
+		transaction.sign(&[
+			&arbitrator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn resolve_token_escrow_ix_setup(
+		treasury: Pubkey,
+		recipient: Pubkey,
+		escrow: Pubkey,
+		token_vault: Pubkey,
+		token_mint: Pubkey,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		arbitrator: &Keypair,
+		escrow_id: u64,
+		vendor_amount: u64,
+		treasury_seed_name: &String,
+		payout_destination: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ResolveTokenEscrow {
+			treasury: treasury,
+			recipient: recipient,
+			escrow: escrow,
+			token_vault: token_vault,
+			token_mint: token_mint,
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			arbitrator: arbitrator.pubkey(),
+			payout_destination: payout_destination,
+		};
+
+		let data = 	treasury_vault_instruction::ResolveTokenEscrow {
+				_escrow_id: escrow_id,
+				vendor_amount,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&arbitrator.pubkey()),
+		);
+
+		transaction.sign(&[
+			&arbitrator,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_grants_round_ix_setup(
+		treasury: Pubkey,
+		grants_round: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		round_id: u64,
+		matching_pool: u64,
+		start_time: i64,
+		end_time: i64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CreateGrantsRound {
+			treasury: treasury,
+			grants_round: grants_round,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::CreateGrantsRound {
+				round_id,
+				matching_pool,
+				start_time,
+				end_time,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn register_round_project_ix_setup(
+		treasury: Pubkey,
+		grants_round: Pubkey,
+		recipient: Pubkey,
+		round_project: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		round_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::RegisterRoundProject {
+			treasury: treasury,
+			grants_round: grants_round,
+			recipient: recipient,
+			round_project: round_project,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::RegisterRoundProject {
+				_round_id: round_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn contribute_to_round_ix_setup(
+		treasury: Pubkey,
+		grants_round: Pubkey,
+		recipient: Pubkey,
+		round_project: Pubkey,
+		contribution: Pubkey,
+		payout_address: Pubkey,
+		contributor: &Keypair,
+		system_program: Pubkey,
+		round_id: u64,
+		amount: u64,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ContributeToRound {
+			treasury: treasury,
+			grants_round: grants_round,
+			recipient: recipient,
+			round_project: round_project,
+			contribution: contribution,
+			payout_address: payout_address,
+			contributor: contributor.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::ContributeToRound {
+				_round_id: round_id,
+				amount,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&contributor.pubkey()),
+		);
+
+		transaction.sign(&[
+			&contributor,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn tally_round_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		grants_round: Pubkey,
+		round_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		round_projects: &[Pubkey],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::TallyRound {
+			fee_payer: fee_payer.pubkey(),
+			treasury: treasury,
+			grants_round: grants_round,
+			authority_member: authority_member,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(round_projects.iter().map(|account| AccountMeta::new_readonly(*account, false)));
+
+		let data = 	treasury_vault_instruction::TallyRound {
+				_round_id: round_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn distribute_round_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		grants_round: Pubkey,
+		round_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		payout_accounts: &[Pubkey],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::DistributeRound {
+			fee_payer: fee_payer.pubkey(),
+			treasury: treasury,
+			grants_round: grants_round,
+			policy: policy_pda(treasury, None),
+			authority_member: authority_member,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(payout_accounts.iter().map(|account| AccountMeta::new(*account, false)));
+
+		let data = 	treasury_vault_instruction::DistributeRound {
+				_round_id: round_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn post_bounty_ix_setup(
+		treasury: Pubkey,
+		bounty: Pubkey,
+		token_vault: Option<Pubkey>,
+		authority: &Keypair,
+		system_program: Pubkey,
+		bounty_id: u64,
+		token_mint: Option<Pubkey>,
+		reward: u64,
+		deadline: i64,
+		description_hash: [u8; 32],
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::PostBounty {
+			treasury: treasury,
+			bounty: bounty,
+			token_vault: token_vault,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			approval_policy: approval_policy_pda(treasury, token_mint),
+			approval_request: approval_request,
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::PostBounty {
+				bounty_id,
+				token_mint,
+				reward,
+				deadline,
+				description_hash,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn submit_bounty_claim_ix_setup(
+		treasury: Pubkey,
+		bounty: Pubkey,
+		recipient: Pubkey,
+		bounty_claim: Pubkey,
+		recipient_signer: &Keypair,
+		system_program: Pubkey,
+		bounty_id: u64,
+		evidence_hash: [u8; 32],
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SubmitBountyClaim {
+			treasury: treasury,
+			bounty: bounty,
+			recipient: recipient,
+			bounty_claim: bounty_claim,
+			recipient_signer: recipient_signer.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::SubmitBountyClaim {
+				_bounty_id: bounty_id,
+				evidence_hash,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&recipient_signer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&recipient_signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn accept_sol_bounty_claim_ix_setup(
+		treasury: Pubkey,
+		bounty: Pubkey,
+		recipient: Pubkey,
+		bounty_claim: Pubkey,
+		payout_address: Pubkey,
+		reviewer: &Keypair,
+		bounty_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::AcceptSolBountyClaim {
+			treasury: treasury,
+			bounty: bounty,
+			recipient: recipient,
+			bounty_claim: bounty_claim,
+			payout_address: payout_address,
+			policy: policy_pda(treasury, None),
+			reviewer: reviewer.pubkey(),
+			authority_member: authority_member,
+		};
+
+		let data = 	treasury_vault_instruction::AcceptSolBountyClaim {
+				_bounty_id: bounty_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&reviewer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&reviewer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn accept_token_bounty_claim_ix_setup(
+		treasury: Pubkey,
+		bounty: Pubkey,
+		recipient: Pubkey,
+		bounty_claim: Pubkey,
+		token_vault: Pubkey,
+		token_mint: Pubkey,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		reviewer: &Keypair,
+		bounty_id: u64,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		payout_destination: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::AcceptTokenBountyClaim {
+			treasury: treasury,
+			bounty: bounty,
+			recipient: recipient,
+			bounty_claim: bounty_claim,
+			token_vault: token_vault,
+			token_mint: token_mint,
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			reviewer: reviewer.pubkey(),
+			authority_member: authority_member,
+			payout_destination: payout_destination,
+			policy: policy_pda(treasury, Some(token_mint)),
+		};
+
+		let data = 	treasury_vault_instruction::AcceptTokenBountyClaim {
+				_bounty_id: bounty_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&reviewer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&reviewer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn expire_bounty_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		bounty: Pubkey,
+		token_vault: Option<Pubkey>,
+		bounty_id: u64,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::ExpireBounty {
+			treasury: treasury,
+			bounty: bounty,
+			token_vault: token_vault,
+		};
+
+		let data = 	treasury_vault_instruction::ExpireBounty {
+				_bounty_id: bounty_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_governance_ix_setup(
+		treasury: Pubkey,
+		governance: Pubkey,
+		vault_token_account: Pubkey,
+		authority: &Keypair,
+		system_program: Pubkey,
+		governance_mint: Option<Pubkey>,
+		quorum: u64,
+		threshold_bps: u16,
+		voting_period_seconds: u64,
+		is_enabled: bool,
+		delegate_threshold: u64,
+		require_council_config: bool,
+		treasury_seed_name: &String,
+		authority_member: Option<Pubkey>,
+		approval_request: Option<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::SetGovernance {
+			treasury: treasury,
+			governance: governance,
+			vault_token_account: vault_token_account,
+			authority: authority.pubkey(),
+			authority_member: authority_member,
+			system_program: system_program,
+			approval_request: approval_request,
+		};
+
+		let data = 	treasury_vault_instruction::SetGovernance {
+				governance_mint,
+				quorum,
+				threshold_bps,
+				voting_period_seconds,
+				is_enabled,
+				delegate_threshold,
+				require_council_config,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&authority.pubkey()),
+		);
+
+		transaction.sign(&[
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn deposit_governance_tokens_ix_setup(
+		treasury: Pubkey,
+		governance: Pubkey,
+		voter_record: Pubkey,
+		voter: &Keypair,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		system_program: Pubkey,
+		amount: u64,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::DepositGovernanceTokens {
+			treasury: treasury,
+			governance: governance,
+			voter_record: voter_record,
+			voter: voter.pubkey(),
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::DepositGovernanceTokens {
+				amount,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&voter.pubkey()),
+		);
+
+		transaction.sign(&[
+			&voter,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn withdraw_governance_tokens_ix_setup(
+		treasury: Pubkey,
+		governance: Pubkey,
+		voter_record: Pubkey,
+		voter: &Keypair,
+		source: Pubkey,
+		destination: Pubkey,
+		authority: &Keypair,
+		csl_spl_token_v0_0_0: Pubkey,
+		amount: u64,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::WithdrawGovernanceTokens {
+			treasury: treasury,
+			governance: governance,
+			voter_record: voter_record,
+			voter: voter.pubkey(),
+			source: source,
+			destination: destination,
+			authority: authority.pubkey(),
+			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
+		};
+
+		let data = 	treasury_vault_instruction::WithdrawGovernanceTokens {
+				amount,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&voter.pubkey()),
+		);
+
+		transaction.sign(&[
+			&voter,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_spending_proposal_ix_setup(
+		treasury: Pubkey,
+		governance: Pubkey,
+		voter_record: Pubkey,
+		approval_request: Pubkey,
+		proposal: Pubkey,
+		proposer: &Keypair,
+		system_program: Pubkey,
+		request_id: u64,
+		action: ApprovalAction,
+		amount: u64,
+		token_mint: Option<Pubkey>,
+		terms_hash: [u8; 32],
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CreateSpendingProposal {
+			treasury: treasury,
+			governance: governance,
+			voter_record: voter_record,
+			approval_request: approval_request,
+			proposal: proposal,
+			proposer: proposer.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::CreateSpendingProposal {
+				request_id,
+				action,
+				amount,
+				token_mint,
+				terms_hash,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&proposer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&proposer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cast_vote_ix_setup(
+		treasury: Pubkey,
+		approval_request: Pubkey,
+		proposal: Pubkey,
+		voter_record: Pubkey,
+		vote: Pubkey,
+		voter: &Keypair,
+		system_program: Pubkey,
+		request_id: u64,
+		approve: bool,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CastVote {
+			treasury: treasury,
+			approval_request: approval_request,
+			proposal: proposal,
+			voter_record: voter_record,
+			vote: vote,
+			voter: voter.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::CastVote {
+				request_id,
+				approve,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&voter.pubkey()),
+		);
+
+		transaction.sign(&[
+			&voter,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn finalize_proposal_ix_setup(
+		fee_payer: &Keypair,
+		treasury: Pubkey,
+		approval_request: Pubkey,
+		proposal: Pubkey,
+		request_id: u64,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::FinalizeProposal {
+			treasury: treasury,
+			approval_request: approval_request,
+			proposal: proposal,
+		};
+
+		let data = 	treasury_vault_instruction::FinalizeProposal {
+				request_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn delegate_votes_ix_setup(
+		treasury: Pubkey,
+		governance: Pubkey,
+		voter_record: Pubkey,
+		delegation: Pubkey,
+		voter: &Keypair,
+		system_program: Pubkey,
+		delegate: Pubkey,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::DelegateVotes {
+			treasury: treasury,
+			governance: governance,
+			voter_record: voter_record,
+			delegation: delegation,
+			voter: voter.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	treasury_vault_instruction::DelegateVotes {
+				delegate,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&voter.pubkey()),
+		);
+
+		transaction.sign(&[
+			&voter,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn revoke_delegation_ix_setup(
+		treasury: Pubkey,
+		voter_record: Pubkey,
+		delegation: Pubkey,
+		voter: &Keypair,
+		treasury_seed_name: &String,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::RevokeDelegation {
+			treasury: treasury,
+			voter_record: voter_record,
+			delegation: delegation,
+			voter: voter.pubkey(),
+		};
+
+		let data = 	treasury_vault_instruction::RevokeDelegation {
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&voter.pubkey()),
+		);
+
+		transaction.sign(&[
+			&voter,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cosign_request_ix_setup(
+		treasury: Pubkey,
+		governance: Pubkey,
+		approval_request: Pubkey,
+		delegate: &Keypair,
+		request_id: u64,
+		treasury_seed_name: &String,
+		delegations: &[Pubkey],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = treasury_vault_accounts::CosignRequest {
+			treasury: treasury,
+			governance: governance,
+			approval_request: approval_request,
+			delegate: delegate.pubkey(),
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(delegations.iter().map(|account| AccountMeta::new_readonly(*account, false)));
+
+		let data = 	treasury_vault_instruction::CosignRequest {
+				request_id,
+				_treasury_seed_name: treasury_seed_name.clone(),
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&delegate.pubkey()),
+		);
+
+		transaction.sign(&[
+			&delegate,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
 }
 
 pub mod csl_spl_token_ix_interface {
@@ -0,0 +1,295 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{
+        Escrow, EscrowStatus, KeeperMode, Recipient, TreasuryConfig,
+    },
+};
+
+
+#[tokio::test]
+async fn create_escrow_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("vendors");
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+	let vendor_keypair = Keypair::new();
+	let arbitrator_keypair = Keypair::new();
+	let keeper_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let vendor_pubkey = vendor_keypair.pubkey();
+	let arbitrator_pubkey = arbitrator_keypair.pubkey();
+	let keeper_pubkey = keeper_keypair.pubkey();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			vendor_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let escrow_pda = |escrow_id: u64| Pubkey::find_program_address(
+		&[
+			b"escrow",
+			treasury_pda.as_ref(),
+			escrow_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, treasurer_pubkey, vendor_pubkey, arbitrator_pubkey, keeper_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let now = clock.unix_timestamp;
+
+	// The daily limit covers the two releases but not the resolved share on top
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Vendor payments"),
+		treasurer_pubkey,
+		250_000_000,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so escrows can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		vendor_pubkey,
+		&String::from("Vendor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let create_ix = |escrow_id: u64, amount: u64, arbitrator: Pubkey| {
+		treasury_vault_ix_interface::create_escrow_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			escrow_pda(escrow_id),
+			None,
+			&admin_keypair,
+			system_program::ID,
+			escrow_id,
+			None,
+			amount,
+			arbitrator,
+			now + 1_000,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let dispute_ix = |disputer: &Keypair, escrow_id: u64, recent_blockhash| {
+		treasury_vault_ix_interface::dispute_escrow_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			escrow_pda(escrow_id),
+			disputer,
+			escrow_id,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let release_ix = |fee_payer: &Keypair, escrow_id: u64, recent_blockhash| {
+		treasury_vault_ix_interface::release_sol_escrow_ix_setup(
+			fee_payer,
+			treasury_pda,
+			recipient_pda,
+			escrow_pda(escrow_id),
+			vendor_pubkey,
+			escrow_id,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let resolve_ix = |arbitrator: &Keypair, vendor_amount: u64, recent_blockhash| {
+		treasury_vault_ix_interface::resolve_sol_escrow_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			escrow_pda(1),
+			vendor_pubkey,
+			arbitrator,
+			1,
+			vendor_amount,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	// The vendor cannot arbitrate their own escrow
+	let result = banks_client.process_transaction(create_ix(1, 300_000_000, vendor_pubkey)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(create_ix(1, 300_000_000, arbitrator_pubkey)).await.unwrap();
+	banks_client.process_transaction(create_ix(2, 200_000_000, arbitrator_pubkey)).await.unwrap();
+	banks_client.process_transaction(create_ix(3, 50_000_000, arbitrator_pubkey)).await.unwrap();
+
+	// Escrowed lamports stay in the treasury but cannot be withdrawn
+	let ix = treasury_vault_ix_interface::emergency_withdraw_sol_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		600_000_000,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	// Only a dispute hands the escrow to the arbitrator
+	let result = banks_client.process_transaction(resolve_ix(&arbitrator_keypair, 100_000_000, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Before the release time only the treasury's side can release
+	let result = banks_client.process_transaction(release_ix(&keeper_keypair, 1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(dispute_ix(&vendor_keypair, 1, recent_blockhash)).await.unwrap();
+
+	let result = banks_client.process_transaction(release_ix(&treasurer_keypair, 1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(resolve_ix(&treasurer_keypair, 100_000_000, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(resolve_ix(&arbitrator_keypair, 300_000_001, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// The ruling settles reserved funds, so it is not held to the spending limits
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	banks_client.process_transaction(resolve_ix(&arbitrator_keypair, 100_000_000, recent_blockhash)).await.unwrap();
+
+	// Once the release time passes, nobody can dispute and any keeper can release
+	clock.unix_timestamp = now + 1_000;
+	context.set_sysvar(&clock);
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(dispute_ix(&treasurer_keypair, 2, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// The vendor can release a due escrow to themselves whatever the keeper mode
+	let result = banks_client.process_transaction(release_ix(&keeper_keypair, 3, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(release_ix(&vendor_keypair, 3, recent_blockhash)).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::set_keeper_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		KeeperMode::Permissionless,
+		0,
+		0,
+		0,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let result = banks_client.process_transaction(release_ix(&keeper_keypair, 2, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let escrow_account = banks_client.get_account(escrow_pda(1)).await.unwrap().unwrap();
+	let escrow = Escrow::try_deserialize(&mut escrow_account.data.as_ref()).unwrap();
+	assert_eq!(escrow.status, EscrowStatus::Resolved);
+	assert_eq!(escrow.disputed_by, Some(vendor_pubkey));
+	assert_eq!(escrow.vendor_amount, 100_000_000);
+
+	let escrow_account = banks_client.get_account(escrow_pda(2)).await.unwrap().unwrap();
+	let escrow = Escrow::try_deserialize(&mut escrow_account.data.as_ref()).unwrap();
+	assert_eq!(escrow.status, EscrowStatus::Released);
+	assert_eq!(escrow.vendor_amount, 200_000_000);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.total_balance, 650_000_000);
+	assert_eq!(treasury.open_escrows, 0);
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.total_received, 350_000_000);
+}
@@ -0,0 +1,330 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{
+        GrantsRound, Recipient, RecipientCaps, RoundProject, RoundStatus, TreasuryConfig,
+    },
+};
+
+
+#[tokio::test]
+async fn create_grants_round_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("community");
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+	let project_keypairs = [Keypair::new(), Keypair::new()];
+	let contributor_keypairs = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let project_pubkeys: Vec<Pubkey> = project_keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+	let contributor_pubkeys: Vec<Pubkey> = contributor_keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (grants_round_pda, _grants_round_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"grants_round",
+			treasury_pda.as_ref(),
+			1u64.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let recipient_pdas: Vec<Pubkey> = project_pubkeys.iter().map(|project| Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			project.as_ref(),
+		],
+		&treasury_vault::ID,
+	).0).collect();
+
+	let round_project_pdas: Vec<Pubkey> = recipient_pdas.iter().map(|recipient| Pubkey::find_program_address(
+		&[
+			b"round_project",
+			grants_round_pda.as_ref(),
+			recipient.as_ref(),
+		],
+		&treasury_vault::ID,
+	).0).collect();
+
+	let contribution_pda = |project: usize, contributor: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"contribution",
+			round_project_pdas[project].as_ref(),
+			contributor.as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, treasurer_pubkey].into_iter().chain(project_pubkeys.clone()).chain(contributor_pubkeys.clone()) {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let now = clock.unix_timestamp;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Community grants"),
+		treasurer_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the matching pool can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	for (project, recipient) in project_pubkeys.iter().zip(&recipient_pdas) {
+		let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+			treasury_pda,
+			*recipient,
+			&admin_keypair,
+			system_program::ID,
+			*project,
+			&String::from("Project"),
+			0,
+			0,
+			[0u8; 32],
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			None,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	let ix = treasury_vault_ix_interface::create_grants_round_ix_setup(
+		treasury_pda,
+		grants_round_pda,
+		&admin_keypair,
+		system_program::ID,
+		1,
+		100_000_000,
+		now,
+		now + 1_000,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	for (recipient, round_project) in recipient_pdas.iter().zip(&round_project_pdas) {
+		let ix = treasury_vault_ix_interface::register_round_project_ix_setup(
+			treasury_pda,
+			grants_round_pda,
+			*recipient,
+			*round_project,
+			&treasurer_keypair,
+			system_program::ID,
+			1,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	let contribute_ix = |project: usize, contributor: &Keypair, amount: u64, recent_blockhash| {
+		treasury_vault_ix_interface::contribute_to_round_ix_setup(
+			treasury_pda,
+			grants_round_pda,
+			recipient_pdas[project],
+			round_project_pdas[project],
+			contribution_pda(project, &contributor.pubkey()),
+			project_pubkeys[project],
+			contributor,
+			system_program::ID,
+			1,
+			amount,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let tally_ix = |round_projects: &[Pubkey], recent_blockhash| {
+		treasury_vault_ix_interface::tally_round_ix_setup(
+			&treasurer_keypair,
+			treasury_pda,
+			grants_round_pda,
+			1,
+			&treasury_seed_name,
+			None,
+			round_projects,
+			recent_blockhash,
+		)
+	};
+
+	// Projects cannot contribute to themselves
+	let result = banks_client.process_transaction(contribute_ix(0, &project_keypairs[0], 1_000_000, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// The first project has two contributors of 4_000_000 each, the first giving in two parts;
+	// the second has two of 1_000_000 each
+	banks_client.process_transaction(contribute_ix(0, &contributor_keypairs[0], 1_000_000, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(contribute_ix(0, &contributor_keypairs[0], 3_000_000, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(contribute_ix(0, &contributor_keypairs[1], 4_000_000, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(contribute_ix(1, &contributor_keypairs[0], 1_000_000, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(contribute_ix(1, &contributor_keypairs[2], 1_000_000, recent_blockhash)).await.unwrap();
+
+	// Matching waits for the round to close, which also ends contributions
+	let result = banks_client.process_transaction(tally_ix(&round_project_pdas[..1], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	clock.unix_timestamp = now + 1_000;
+	context.set_sysvar(&clock);
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(contribute_ix(1, &contributor_keypairs[1], 1_000_000, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Tally page by page, in registration order
+	banks_client.process_transaction(tally_ix(&round_project_pdas[..1], recent_blockhash)).await.unwrap();
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(tally_ix(&round_project_pdas[..1], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(tally_ix(&round_project_pdas[1..], recent_blockhash)).await.unwrap();
+
+	// Scores are 4000^2 - 8_000_000 and 2000^2 - 2_000_000, so the pool splits 80/20,
+	// paid page by page straight from the round
+	let distribute_ix = |project: usize, recent_blockhash| {
+		treasury_vault_ix_interface::distribute_round_ix_setup(
+			&treasurer_keypair,
+			treasury_pda,
+			grants_round_pda,
+			1,
+			&treasury_seed_name,
+			None,
+			&[round_project_pdas[project], recipient_pdas[project], project_pubkeys[project]],
+			recent_blockhash,
+		)
+	};
+
+	let result = banks_client.process_transaction(distribute_ix(1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(distribute_ix(0, recent_blockhash)).await.unwrap();
+
+	// The unpaid match stays reserved until its page is cranked
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.total_balance, 900_000_000);
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	// A project held up by its caps keeps its match for a later crank
+	let caps_ix = |per_payout: u64, recent_blockhash| {
+		treasury_vault_ix_interface::update_recipient_ix_setup(
+			treasury_pda,
+			recipient_pdas[1],
+			&admin_keypair,
+			project_pubkeys[1],
+			None,
+			None,
+			None,
+			Some(RecipientCaps { per_payout, per_period: 0, period_seconds: 0, lifetime: 0 }),
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	banks_client.process_transaction(caps_ix(10_000_000, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(distribute_ix(1, recent_blockhash)).await.unwrap();
+
+	let grants_round_account = banks_client.get_account(grants_round_pda).await.unwrap().unwrap();
+	let grants_round = GrantsRound::try_deserialize(&mut grants_round_account.data.as_ref()).unwrap();
+	assert_eq!(grants_round.status, RoundStatus::Distributing);
+	assert_eq!(grants_round.deferred_count, 1);
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	banks_client.process_transaction(caps_ix(0, recent_blockhash)).await.unwrap();
+	let result = banks_client.process_transaction(distribute_ix(1, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let grants_round_account = banks_client.get_account(grants_round_pda).await.unwrap().unwrap();
+	let grants_round = GrantsRound::try_deserialize(&mut grants_round_account.data.as_ref()).unwrap();
+	assert_eq!(grants_round.status, RoundStatus::Finalized);
+	assert_eq!(grants_round.qf_total, 10_000_000);
+	assert_eq!(grants_round.matched_total, 100_000_000);
+	assert_eq!(grants_round.deferred_count, 0);
+
+	let round_project_account = banks_client.get_account(round_project_pdas[0]).await.unwrap().unwrap();
+	let round_project = RoundProject::try_deserialize(&mut round_project_account.data.as_ref()).unwrap();
+	assert_eq!(round_project.contributed, 8_000_000);
+	assert_eq!(round_project.contributor_count, 2);
+	assert_eq!(round_project.sqrt_sum, 4_000);
+	assert_eq!(round_project.matched_amount, 80_000_000);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.total_balance, 900_000_000);
+	assert_eq!(treasury.open_rounds, 0);
+
+	for (recipient_pda, matched_amount) in recipient_pdas.iter().zip([80_000_000, 20_000_000]) {
+		let recipient_account = banks_client.get_account(*recipient_pda).await.unwrap().unwrap();
+		let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+		assert_eq!(recipient.total_received, matched_amount);
+	}
+}
@@ -0,0 +1,277 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{
+        MilestoneGrant, MilestoneStatus, MilestoneTerms, Recipient, TreasuryConfig,
+    },
+};
+
+
+#[tokio::test]
+async fn create_milestone_grant_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("grants");
+	let evidence_hash: [u8; 32] = [9u8; 32];
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+	let grantee_keypair = Keypair::new();
+	let reviewer_keypairs = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let grantee_pubkey = grantee_keypair.pubkey();
+	let reviewer_pubkeys: Vec<Pubkey> = reviewer_keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			grantee_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (milestone_grant_pda, _milestone_grant_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_grant",
+			treasury_pda.as_ref(),
+			1u64.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, treasurer_pubkey, grantee_pubkey].into_iter().chain(reviewer_pubkeys.clone()) {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let now = clock.unix_timestamp;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Grants committee"),
+		treasurer_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so grants can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		grantee_pubkey,
+		&String::from("Grantee"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Two of three reviewers sign off the first milestone; the second needs only the first reviewer
+	let milestones = |first_deadline: i64, second_deadline: i64| vec![
+		MilestoneTerms { amount: 200_000_000, deadline: first_deadline, approvers: reviewer_pubkeys.clone(), approvals_required: 2 },
+		MilestoneTerms { amount: 300_000_000, deadline: second_deadline, approvers: vec![reviewer_pubkeys[0]], approvals_required: 1 },
+	];
+
+	let create_ix = |milestones: Vec<MilestoneTerms>| {
+		treasury_vault_ix_interface::create_milestone_grant_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			milestone_grant_pda,
+			None,
+			&admin_keypair,
+			system_program::ID,
+			1,
+			None,
+			milestones,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let evidence_ix = |index: u8, recent_blockhash| {
+		treasury_vault_ix_interface::submit_milestone_evidence_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			milestone_grant_pda,
+			&grantee_keypair,
+			1,
+			index,
+			evidence_hash,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let approve_ix = |approver: &Keypair, index: u8, recent_blockhash| {
+		treasury_vault_ix_interface::approve_milestone_ix_setup(
+			treasury_pda,
+			milestone_grant_pda,
+			approver,
+			1,
+			index,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let release_ix = |index: u8, recent_blockhash| {
+		treasury_vault_ix_interface::release_sol_milestone_ix_setup(
+			&treasurer_keypair,
+			treasury_pda,
+			recipient_pda,
+			milestone_grant_pda,
+			grantee_pubkey,
+			1,
+			index,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let reclaim_ix = |index: u8, recent_blockhash| {
+		treasury_vault_ix_interface::reclaim_milestone_ix_setup(
+			treasury_pda,
+			milestone_grant_pda,
+			None,
+			&admin_keypair,
+			1,
+			index,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	// Deadlines must ascend
+	let result = banks_client.process_transaction(create_ix(milestones(now + 2_000, now + 1_000))).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(create_ix(milestones(now + 1_000, now + 2_000))).await.unwrap();
+
+	// Milestones are worked through in order
+	let result = banks_client.process_transaction(evidence_ix(1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(evidence_ix(0, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(approve_ix(&reviewer_keypairs[0], 0, recent_blockhash)).await.unwrap();
+
+	// One sign-off is short of the threshold, and each reviewer signs once
+	let result = banks_client.process_transaction(release_ix(0, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(approve_ix(&reviewer_keypairs[0], 0, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(approve_ix(&reviewer_keypairs[1], 0, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(release_ix(0, recent_blockhash)).await.unwrap();
+
+	// The second milestone's funds stay reserved until its deadline passes,
+	// and keep the grant open meanwhile
+	let result = banks_client.process_transaction(reclaim_ix(1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.open_grants, 1);
+	assert!(treasury.has_open_obligations());
+
+	clock.unix_timestamp = now + 2_001;
+	context.set_sysvar(&clock);
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(evidence_ix(1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(reclaim_ix(1, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let milestone_grant_account = banks_client.get_account(milestone_grant_pda).await.unwrap().unwrap();
+	let milestone_grant = MilestoneGrant::try_deserialize(&mut milestone_grant_account.data.as_ref()).unwrap();
+	assert_eq!(milestone_grant.recipient, recipient_pda);
+	assert_eq!(milestone_grant.milestones[0].status, MilestoneStatus::Released);
+	assert_eq!(milestone_grant.milestones[0].approvals, 0b011);
+	assert_eq!(milestone_grant.milestones[0].evidence_hash, evidence_hash);
+	assert_eq!(milestone_grant.milestones[1].status, MilestoneStatus::Reclaimed);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.total_balance, 800_000_000);
+	assert_eq!(treasury.open_grants, 0);
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.total_received, 200_000_000);
+}
@@ -8,10 +8,13 @@ use {
 		csl_spl_token_ix_interface,
 		csl_spl_assoc_token_ix_interface,
 	},
+    anchor_lang::AccountDeserialize,
     solana_program_test::tokio,
     solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
     },
+    treasury_vault::{PayoutSchedule, Recurrence},
 };
 
 
@@ -109,13 +112,237 @@ async fn create_payout_schedule_ix_success() {
 		start_time,
 		interval_seconds,
 		max_executions,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+}
+
+
+#[tokio::test]
+async fn create_payout_schedule_ix_calendar() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("payroll");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_000;
+	let start_time: i64 = 1_736_499_600; // Friday 2025-01-10 09:00 UTC
+	let january_31: i64 = 1_738_314_000;
+	let february_3: i64 = 1_738_573_200;
+	let february_28: i64 = 1_740_733_200;
+	let march_31: i64 = 1_743_411_600;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [admin_pubkey, recipient_address_pubkey] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Payroll"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 2_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the payout can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 2_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Engineer"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Out of range rules are rejected
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		None,
+		start_time,
+		0,
+		0,
+		Recurrence::MonthlyOnDay { day: 0 },
+		0,
+		None,
 		&treasury_seed_name,
+		None,
+		None,
 		recent_blockhash,
 	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
 
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		None,
+		start_time,
+		0,
+		0,
+		Recurrence::MonthlyOnDay { day: 31 },
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
 	let result = banks_client.process_transaction(ix).await;
 
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	let schedule_account = banks_client.get_account(payout_schedule_pda).await.unwrap().unwrap();
+	let mut schedule = PayoutSchedule::try_deserialize(&mut schedule_account.data.as_ref()).unwrap();
+	assert_eq!(schedule.recurrence, Recurrence::MonthlyOnDay { day: 31 });
+	assert_eq!(schedule.due_time(0), Some(january_31));
+	assert_eq!(schedule.due_time(1), Some(february_28));
+	assert_eq!(schedule.due_time(2), Some(march_31));
+
+	// Every other Friday, starting on the start date
+	schedule.recurrence = Recurrence::Weekly { weekday: 4, every_weeks: 2 };
+	assert_eq!(schedule.due_time(0), Some(start_time));
+	assert_eq!(schedule.due_time(1), Some(start_time + 14 * 86_400));
+
+	// Saturday 2025-05-31 falls back to Friday 2025-05-30
+	schedule.recurrence = Recurrence::LastBusinessDayOfMonth;
+	assert_eq!(schedule.due_time(0), Some(january_31));
+	assert_eq!(schedule.due_time(4), Some(1_748_595_600));
+
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	for (now, due) in [
+		(start_time, false),
+		(february_3, true),  // January's payout, run late
+		(february_3, false), // February's is still due on the 28th
+		(february_28, true),
+	] {
+		clock.unix_timestamp = now;
+		context.set_sysvar(&clock);
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+		let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+			&admin_keypair,
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			recipient_address_pubkey,
+			recipient_address_pubkey,
+			schedule_id,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			&[],
+			recent_blockhash,
+		);
+		assert_eq!(banks_client.process_transaction(ix).await.is_ok(), due);
+	}
+
+	let schedule_account = banks_client.get_account(payout_schedule_pda).await.unwrap().unwrap();
+	let schedule = PayoutSchedule::try_deserialize(&mut schedule_account.data.as_ref()).unwrap();
+	assert_eq!(schedule.executions, 2);
+	assert_eq!(schedule.next_due_time(), Some(march_31));
 }
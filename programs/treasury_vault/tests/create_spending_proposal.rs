@@ -0,0 +1,428 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::{
+        solana_program::program_pack::Pack, AccountDeserialize, AccountSerialize,
+    },
+    anchor_spl::token::spl_token,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair,
+        signer::Signer, system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{
+        utils::args_hash, ApprovalAction, ApprovalRequest, PayoutSchedule, ProposalStatus, Recurrence,
+        SpendingProposal, TreasuryConfig, VoterRecord,
+    },
+};
+
+
+#[tokio::test]
+async fn create_spending_proposal_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("governed");
+	let governance_mint = Pubkey::new_unique();
+	let voting_period: i64 = 1_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+	let voter_keypairs = [Keypair::new(), Keypair::new()];
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let voter_pubkeys: Vec<Pubkey> = voter_keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+	let vault_token_account_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (governance_pda, _governance_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance",
+			treasury_pda.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let voter_record_pda = |voter: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"voter",
+			treasury_pda.as_ref(),
+			voter.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let approval_request_pda = |request_id: u64| Pubkey::find_program_address(
+		&[
+			b"approval_request",
+			treasury_pda.as_ref(),
+			request_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	let proposal_pda = |request_id: u64| Pubkey::find_program_address(
+		&[
+			b"proposal",
+			approval_request_pda(request_id).as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	let vote_pda = |request_id: u64, voter: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"vote",
+			proposal_pda(request_id).as_ref(),
+			voter.as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	let schedule_pda = |schedule_id: u64| Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, treasurer_pubkey, outsider_pubkey].into_iter().chain(voter_pubkeys.clone()) {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// The governance vault, a token account of the governance mint
+	let mut vault_data = vec![0u8; spl_token::state::Account::LEN];
+	spl_token::state::Account {
+		mint: governance_mint,
+		owner: treasury_pda,
+		state: spl_token::state::AccountState::Initialized,
+		..Default::default()
+	}.pack_into_slice(&mut vault_data);
+	program_test.add_account(
+		vault_token_account_pubkey,
+		Account {
+			lamports: Rent::default().minimum_balance(vault_data.len()),
+			data: vault_data,
+			owner: spl_token::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// Voting weight of 70 and 40 deposited governance tokens
+	for (voter, deposited) in voter_pubkeys.iter().zip([70u64, 40]) {
+		let (voter_record_pda, voter_record_bump) = voter_record_pda(voter);
+		let mut data = Vec::new();
+		VoterRecord {
+			treasury: treasury_pda,
+			voter: *voter,
+			deposited,
+			locked_until: 0,
+			bump: voter_record_bump,
+			delegate: None,
+		}.try_serialize(&mut data).unwrap();
+		data.resize(122, 0);
+		program_test.add_account(
+			voter_record_pda,
+			Account {
+				lamports: Rent::default().minimum_balance(122),
+				data,
+				owner: treasury_vault::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let now = clock.unix_timestamp;
+
+	// Holders of the token gate mint vote unless another mint is set
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Holder governed"),
+		treasurer_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		Some(governance_mint),
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so payouts can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let set_governance_ix = |authority: &Keypair| {
+		treasury_vault_ix_interface::set_governance_ix_setup(
+			treasury_pda,
+			governance_pda,
+			vault_token_account_pubkey,
+			authority,
+			system_program::ID,
+			None,
+			100,
+			6_000,
+			voting_period as u64,
+			true,
+			0,
+			false,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let result = banks_client.process_transaction(set_governance_ix(&outsider_keypair)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(set_governance_ix(&admin_keypair)).await.unwrap();
+
+	let schedule_action = |schedule_id: u64| ApprovalAction::CreatePayoutSchedule { recipient: recipient_pda, schedule_id };
+
+	// Proposals bind every argument of the schedule they authorize
+	let schedule_terms = |schedule_id: u64, max_executions: u64| {
+		args_hash(
+			"create_payout_schedule",
+			&(recipient_address_pubkey, schedule_id, 5_000_000u64, None::<Pubkey>, now, 0u64, max_executions, Recurrence::Interval, 0i64, None::<treasury_vault::OraclePricing>),
+		)
+		.unwrap()
+	};
+
+	let propose_ix = |request_id: u64, proposer: &Keypair, action: ApprovalAction| {
+		treasury_vault_ix_interface::create_spending_proposal_ix_setup(
+			treasury_pda,
+			governance_pda,
+			voter_record_pda(&proposer.pubkey()).0,
+			approval_request_pda(request_id),
+			proposal_pda(request_id),
+			proposer,
+			system_program::ID,
+			request_id,
+			action,
+			5_000_000,
+			None,
+			schedule_terms(request_id, 1),
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let vote_ix = |request_id: u64, voter: &Keypair, approve: bool, recent_blockhash| {
+		treasury_vault_ix_interface::cast_vote_ix_setup(
+			treasury_pda,
+			approval_request_pda(request_id),
+			proposal_pda(request_id),
+			voter_record_pda(&voter.pubkey()).0,
+			vote_pda(request_id, &voter.pubkey()),
+			voter,
+			system_program::ID,
+			request_id,
+			approve,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let finalize_ix = |request_id: u64, recent_blockhash| {
+		treasury_vault_ix_interface::finalize_proposal_ix_setup(
+			&outsider_keypair,
+			treasury_pda,
+			approval_request_pda(request_id),
+			proposal_pda(request_id),
+			request_id,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let create_schedule_ix = |authority: &Keypair, schedule_id: u64, amount: u64, max_executions: u64, request_id: u64, recent_blockhash| {
+		treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			schedule_pda(schedule_id),
+			authority,
+			system_program::ID,
+			recipient_address_pubkey,
+			schedule_id,
+			amount,
+			None,
+			now,
+			0,
+			max_executions,
+			Recurrence::Interval,
+			0,
+			None,
+			&treasury_seed_name,
+			None,
+			Some(approval_request_pda(request_id)),
+			recent_blockhash,
+		)
+	};
+
+	// Only holders with deposits propose, and emergency withdrawals cannot be voted on
+	let result = banks_client.process_transaction(propose_ix(1, &outsider_keypair, schedule_action(1))).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(propose_ix(1, &voter_keypairs[0], ApprovalAction::EmergencyWithdrawSol)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(propose_ix(1, &voter_keypairs[0], schedule_action(1))).await.unwrap();
+	banks_client.process_transaction(propose_ix(2, &voter_keypairs[1], schedule_action(2))).await.unwrap();
+
+	// 70 yes against 40 no reaches the quorum of 100 and the 60% threshold
+	banks_client.process_transaction(vote_ix(1, &voter_keypairs[0], true, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(vote_ix(1, &voter_keypairs[1], false, recent_blockhash)).await.unwrap();
+
+	// 40 yes alone misses the quorum
+	banks_client.process_transaction(vote_ix(2, &voter_keypairs[1], true, recent_blockhash)).await.unwrap();
+
+	// Each holder votes once per proposal
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(vote_ix(1, &voter_keypairs[0], false, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(finalize_ix(1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Spending still needs the create schedule permission before the vote passes
+	let result = banks_client.process_transaction(create_schedule_ix(&outsider_keypair, 1, 5_000_000, 1, 1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	clock.unix_timestamp = now + voting_period;
+	context.set_sysvar(&clock);
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(vote_ix(2, &voter_keypairs[0], true, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(finalize_ix(1, recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(finalize_ix(2, recent_blockhash)).await.unwrap();
+
+	// The passed proposal authorizes exactly its described schedule, once
+	let result = banks_client.process_transaction(create_schedule_ix(&outsider_keypair, 1, 6_000_000, 1, 1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(create_schedule_ix(&outsider_keypair, 1, 5_000_000, 0, 1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Holding the create schedule permission does not free the vote from its terms either
+	let result = banks_client.process_transaction(create_schedule_ix(&admin_keypair, 1, 5_000_000, 0, 1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(create_schedule_ix(&outsider_keypair, 2, 5_000_000, 1, 2, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(create_schedule_ix(&outsider_keypair, 1, 5_000_000, 1, 1, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal_account = banks_client.get_account(proposal_pda(1)).await.unwrap().unwrap();
+	let proposal = SpendingProposal::try_deserialize(&mut proposal_account.data.as_ref()).unwrap();
+	assert_eq!(proposal.status, ProposalStatus::Passed);
+	assert_eq!(proposal.yes_weight, 70);
+	assert_eq!(proposal.no_weight, 40);
+
+	let proposal_account = banks_client.get_account(proposal_pda(2)).await.unwrap().unwrap();
+	let proposal = SpendingProposal::try_deserialize(&mut proposal_account.data.as_ref()).unwrap();
+	assert_eq!(proposal.status, ProposalStatus::Defeated);
+
+	let approval_request_account = banks_client.get_account(approval_request_pda(1)).await.unwrap().unwrap();
+	let approval_request = ApprovalRequest::try_deserialize(&mut approval_request_account.data.as_ref()).unwrap();
+	assert!(approval_request.vote_passed);
+	assert!(approval_request.is_executed);
+
+	let approval_request_account = banks_client.get_account(approval_request_pda(2)).await.unwrap().unwrap();
+	let approval_request = ApprovalRequest::try_deserialize(&mut approval_request_account.data.as_ref()).unwrap();
+	assert!(!approval_request.vote_passed);
+
+	let voter_record_account = banks_client.get_account(voter_record_pda(&voter_pubkeys[1]).0).await.unwrap().unwrap();
+	let voter_record = VoterRecord::try_deserialize(&mut voter_record_account.data.as_ref()).unwrap();
+	assert_eq!(voter_record.locked_until, now + voting_period);
+
+	let payout_schedule_account = banks_client.get_account(schedule_pda(1)).await.unwrap().unwrap();
+	let payout_schedule = PayoutSchedule::try_deserialize(&mut payout_schedule_account.data.as_ref()).unwrap();
+	assert_eq!(payout_schedule.amount, 5_000_000);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.open_schedules, 1);
+}
@@ -112,6 +112,8 @@ async fn create_streaming_schedule_ix_success() {
 		duration_seconds,
 		token_mint,
 		&treasury_seed_name,
+		None,
+		None,
 		recent_blockhash,
 	);
 
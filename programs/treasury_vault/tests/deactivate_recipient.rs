@@ -0,0 +1,263 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{DeactivationPolicy, PayoutSchedule, Recipient, Recurrence, StreamingSchedule},
+};
+
+
+#[tokio::test]
+async fn deactivate_recipient_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("payroll");
+	let schedule_id: u64 = 1;
+	let stream_id: u64 = 1;
+	let stream_total: u64 = 1_000_000;
+	let stream_duration: u64 = 1_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (streaming_schedule_pda, _streaming_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"stream",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+	let clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Contractor payroll"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::update_treasury_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		Some(DeactivationPolicy::ClawbackUnvested),
+		None,
+		None,
+		None,
+		None,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 2_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		1_000,
+		None,
+		clock.unix_timestamp,
+		86_400,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Half of the stream has vested by now
+	let ix = treasury_vault_ix_interface::create_streaming_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		streaming_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		stream_id,
+		stream_total,
+		stream_total / stream_duration,
+		clock.unix_timestamp - (stream_duration / 2) as i64,
+		clock.unix_timestamp - (stream_duration / 2) as i64,
+		stream_duration,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Leaving out the stream is refused
+	let ix = treasury_vault_ix_interface::deactivate_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		recipient_address_pubkey,
+		&[payout_schedule_pda],
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::deactivate_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		recipient_address_pubkey,
+		&[payout_schedule_pda, streaming_schedule_pda],
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert!(!recipient.is_active);
+
+	let schedule_account = banks_client.get_account(payout_schedule_pda).await.unwrap().unwrap();
+	let payout_schedule = PayoutSchedule::try_deserialize(&mut schedule_account.data.as_ref()).unwrap();
+	assert!(payout_schedule.is_paused);
+
+	let stream_account = banks_client.get_account(streaming_schedule_pda).await.unwrap().unwrap();
+	let streaming_schedule = StreamingSchedule::try_deserialize(&mut stream_account.data.as_ref()).unwrap();
+	assert!(streaming_schedule.is_paused);
+	assert_eq!(streaming_schedule.total_amount, stream_total / 2);
+
+	// Payouts to the deactivated recipient are refused
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+}
@@ -0,0 +1,420 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::{
+        solana_program::program_pack::Pack, AccountDeserialize, AccountSerialize,
+    },
+    anchor_spl::token::spl_token,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair,
+        signer::Signer, system_program,
+    },
+    treasury_vault::{
+        utils::args_hash, ApprovalAction, ApprovalRequest, Delegation, KeeperMode, TreasuryConfig,
+        VoterRecord, APPROVAL_REQUEST_LIFETIME_SECONDS,
+    },
+};
+
+
+#[tokio::test]
+async fn delegate_votes_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("council");
+	let governance_mint = Pubkey::new_unique();
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+	let voter_keypairs = [Keypair::new(), Keypair::new(), Keypair::new()];
+	let council_keypairs = [Keypair::new(), Keypair::new()];
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let voter_pubkeys: Vec<Pubkey> = voter_keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+	let council_pubkeys: Vec<Pubkey> = council_keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+	let vault_token_account_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (governance_pda, _governance_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance",
+			treasury_pda.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (approval_request_pda, _approval_request_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"approval_request",
+			treasury_pda.as_ref(),
+			1u64.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let voter_record_pda = |voter: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"voter",
+			treasury_pda.as_ref(),
+			voter.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let delegation_pda = |voter: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"delegation",
+			treasury_pda.as_ref(),
+			voter.as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, treasurer_pubkey].into_iter().chain(voter_pubkeys.clone()).chain(council_pubkeys.clone()) {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// The governance vault, a token account of the governance mint
+	let mut vault_data = vec![0u8; spl_token::state::Account::LEN];
+	spl_token::state::Account {
+		mint: governance_mint,
+		owner: treasury_pda,
+		state: spl_token::state::AccountState::Initialized,
+		..Default::default()
+	}.pack_into_slice(&mut vault_data);
+	program_test.add_account(
+		vault_token_account_pubkey,
+		Account {
+			lamports: Rent::default().minimum_balance(vault_data.len()),
+			data: vault_data,
+			owner: spl_token::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// Voting weight of 70, 40 and 30 deposited governance tokens
+	for (voter, deposited) in voter_pubkeys.iter().zip([70u64, 40, 30]) {
+		let (voter_record_pda, voter_record_bump) = voter_record_pda(voter);
+		let mut data = Vec::new();
+		VoterRecord {
+			treasury: treasury_pda,
+			voter: *voter,
+			deposited,
+			locked_until: 0,
+			bump: voter_record_bump,
+			delegate: None,
+		}.try_serialize(&mut data).unwrap();
+		data.resize(122, 0);
+		program_test.add_account(
+			voter_record_pda,
+			Account {
+				lamports: Rent::default().minimum_balance(122),
+				data,
+				owner: treasury_vault::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Council approved"),
+		treasurer_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		Some(governance_mint),
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Delegates need 100 delegated weight to co-sign, and config changes need their co-sign
+	let set_governance_ix = |delegate_threshold: u64, recent_blockhash| {
+		treasury_vault_ix_interface::set_governance_ix_setup(
+			treasury_pda,
+			governance_pda,
+			vault_token_account_pubkey,
+			&admin_keypair,
+			system_program::ID,
+			None,
+			100,
+			6_000,
+			1_000,
+			true,
+			delegate_threshold,
+			true,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let result = banks_client.process_transaction(set_governance_ix(0, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(set_governance_ix(100, recent_blockhash)).await.unwrap();
+
+	let delegate_ix = |voter: &Keypair, delegate: Pubkey, recent_blockhash| {
+		treasury_vault_ix_interface::delegate_votes_ix_setup(
+			treasury_pda,
+			governance_pda,
+			voter_record_pda(&voter.pubkey()).0,
+			delegation_pda(&voter.pubkey()),
+			voter,
+			system_program::ID,
+			delegate,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let request_pda = |request_id: u64| Pubkey::find_program_address(
+		&[
+			b"approval_request",
+			treasury_pda.as_ref(),
+			request_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	let cosign_ix = |request_id: u64, delegate: &Keypair, delegations: &[Pubkey], recent_blockhash| {
+		treasury_vault_ix_interface::cosign_request_ix_setup(
+			treasury_pda,
+			governance_pda,
+			request_pda(request_id),
+			delegate,
+			request_id,
+			&treasury_seed_name,
+			delegations,
+			recent_blockhash,
+		)
+	};
+
+	let update_config_ix = |daily_limit: u64, approval_request: Option<Pubkey>, recent_blockhash| {
+		treasury_vault_ix_interface::update_treasury_config_ix_setup(
+			treasury_pda,
+			&admin_keypair,
+			None,
+			None,
+			None,
+			Some(daily_limit),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			&treasury_seed_name,
+			None,
+			approval_request,
+			recent_blockhash,
+		)
+	};
+
+	let result = banks_client.process_transaction(delegate_ix(&voter_keypairs[2], voter_pubkeys[2], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Weight locked by a running vote cannot be delegated until the vote ends
+	let mut voter_record_account = banks_client.get_account(voter_record_pda(&voter_pubkeys[2]).0).await.unwrap().unwrap();
+	let mut voter_record = VoterRecord::try_deserialize(&mut voter_record_account.data.as_ref()).unwrap();
+	voter_record.locked_until = clock.unix_timestamp + 5;
+	let mut data = Vec::new();
+	voter_record.try_serialize(&mut data).unwrap();
+	voter_record_account.data[..data.len()].copy_from_slice(&data);
+	context.set_account(&voter_record_pda(&voter_pubkeys[2]).0, &voter_record_account.into());
+
+	let result = banks_client.process_transaction(delegate_ix(&voter_keypairs[2], council_pubkeys[1], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	clock.unix_timestamp += 5;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	banks_client.process_transaction(delegate_ix(&voter_keypairs[0], council_pubkeys[0], recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(delegate_ix(&voter_keypairs[1], council_pubkeys[0], recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(delegate_ix(&voter_keypairs[2], council_pubkeys[1], recent_blockhash)).await.unwrap();
+
+	// Delegations only count for requests created after them
+	clock.unix_timestamp += 10;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let config_hash = args_hash(
+		"update_treasury_config",
+		&(
+			(None::<Pubkey>, None::<Pubkey>, &None::<String>, Some(5_000_000u64), None::<u64>, None::<u64>, None::<bool>, None::<Pubkey>),
+			(None::<u64>, None::<u8>, None::<Pubkey>, None::<bool>, None::<bool>, None::<u64>, None::<u64>),
+		),
+	).unwrap();
+	let ix = treasury_vault_ix_interface::create_approval_request_ix_setup(
+		treasury_pda,
+		approval_request_pda,
+		&admin_keypair,
+		system_program::ID,
+		1,
+		ApprovalAction::ChangeConfig { config_hash },
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Config changes wait for the council
+	let result = banks_client.process_transaction(update_config_ix(5_000_000, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(update_config_ix(5_000_000, Some(approval_request_pda), recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Co-signing takes the delegate threshold, counting each delegation once
+	let result = banks_client.process_transaction(cosign_ix(1, &council_keypairs[1], &[delegation_pda(&voter_pubkeys[2])], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(cosign_ix(1, &council_keypairs[0], &[delegation_pda(&voter_pubkeys[0]), delegation_pda(&voter_pubkeys[0])], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(cosign_ix(1, &council_keypairs[0], &[delegation_pda(&voter_pubkeys[0]), delegation_pda(&voter_pubkeys[2])], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(cosign_ix(1, &council_keypairs[0], &[delegation_pda(&voter_pubkeys[0]), delegation_pda(&voter_pubkeys[1])], recent_blockhash)).await.unwrap();
+
+	// Weight moved to another delegate after the request cannot be counted again
+	let ix = treasury_vault_ix_interface::revoke_delegation_ix_setup(
+		treasury_pda,
+		voter_record_pda(&voter_pubkeys[1]).0,
+		delegation_pda(&voter_pubkeys[1]),
+		&voter_keypairs[1],
+		&treasury_seed_name,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	banks_client.process_transaction(delegate_ix(&voter_keypairs[1], council_pubkeys[1], recent_blockhash)).await.unwrap();
+
+	let result = banks_client.process_transaction(cosign_ix(1, &council_keypairs[1], &[delegation_pda(&voter_pubkeys[2]), delegation_pda(&voter_pubkeys[1])], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// The co-signed request covers exactly the change it describes
+	let result = banks_client.process_transaction(update_config_ix(6_000_000, Some(approval_request_pda), recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let ix = treasury_vault_ix_interface::set_keeper_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		KeeperMode::Permissionless,
+		0,
+		0,
+		0,
+		&treasury_seed_name,
+		None,
+		Some(approval_request_pda),
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(set_governance_ix(50, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(update_config_ix(5_000_000, Some(approval_request_pda), recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.daily_limit, 5_000_000);
+
+	let approval_request_account = banks_client.get_account(approval_request_pda).await.unwrap().unwrap();
+	let approval_request = ApprovalRequest::try_deserialize(&mut approval_request_account.data.as_ref()).unwrap();
+	assert!(approval_request.is_executed);
+	assert_eq!(approval_request.council_weight, 110);
+	assert_eq!(approval_request.approvers, vec![council_pubkeys[0]]);
+
+	let delegation_account = banks_client.get_account(delegation_pda(&voter_pubkeys[1])).await.unwrap().unwrap();
+	let delegation = Delegation::try_deserialize(&mut delegation_account.data.as_ref()).unwrap();
+	assert_eq!(delegation.delegate, council_pubkeys[1]);
+	assert_eq!(delegation.weight, 40);
+
+	let voter_record_account = banks_client.get_account(voter_record_pda(&voter_pubkeys[1]).0).await.unwrap().unwrap();
+	let voter_record = VoterRecord::try_deserialize(&mut voter_record_account.data.as_ref()).unwrap();
+	assert_eq!(voter_record.delegate, Some(council_pubkeys[1]));
+
+	// Spent requests cannot be co-signed, nor can requests past their lifetime
+	let result = banks_client.process_transaction(cosign_ix(1, &council_keypairs[1], &[delegation_pda(&voter_pubkeys[2]), delegation_pda(&voter_pubkeys[1])], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	clock.unix_timestamp += 10;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_approval_request_ix_setup(
+		treasury_pda,
+		request_pda(2),
+		&admin_keypair,
+		system_program::ID,
+		2,
+		ApprovalAction::ChangeConfig { config_hash },
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	clock.unix_timestamp += APPROVAL_REQUEST_LIFETIME_SECONDS;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = banks_client.process_transaction(cosign_ix(2, &council_keypairs[1], &[delegation_pda(&voter_pubkeys[2]), delegation_pda(&voter_pubkeys[1])], recent_blockhash)).await;
+	assert!(result.is_err());
+}
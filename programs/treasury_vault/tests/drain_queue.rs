@@ -0,0 +1,316 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{PendingPayoutQueue, Recipient, Recurrence, TreasuryConfig},
+};
+
+
+#[tokio::test]
+async fn drain_queue_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("payables");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_000_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let recipient_pdas = recipient_addresses.map(|recipient_address| Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address.as_ref(),
+		],
+		&treasury_vault::ID,
+	).0);
+
+	let payout_schedule_pdas = recipient_pdas.map(|recipient_pda| Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0);
+
+	let (payout_queue_pda, _payout_queue_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payout_queue",
+			treasury_pda.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let now = clock.unix_timestamp;
+
+	// One payout a day fits the daily limit
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Payables"),
+		admin_pubkey,
+		payout_amount,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so payouts can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::initialize_payout_queue_ix_setup(
+		treasury_pda,
+		payout_queue_pda,
+		&admin_keypair,
+		system_program::ID,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	for index in 0..2 {
+		let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+			treasury_pda,
+			recipient_pdas[index],
+			&admin_keypair,
+			system_program::ID,
+			recipient_addresses[index],
+			&format!("Vendor {}", index),
+			0,
+			0,
+			[0u8; 32],
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			None,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+
+		let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pdas[index],
+			payout_schedule_pdas[index],
+			&admin_keypair,
+			system_program::ID,
+			recipient_addresses[index],
+			schedule_id,
+			payout_amount,
+			None,
+			0,
+			0,
+			0,
+			Recurrence::Interval,
+			0,
+			None,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	// The second recipient's payouts jump the queue
+	let ix = treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pdas[1],
+		payout_schedule_pdas[1],
+		&admin_keypair,
+		recipient_addresses[1],
+		schedule_id,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		Some(5),
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let execute_ix = |index: usize, payout_queue: Option<Pubkey>, recent_blockhash| {
+		treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+			&admin_keypair,
+			treasury_pda,
+			recipient_pdas[index],
+			payout_schedule_pdas[index],
+			recipient_addresses[index],
+			recipient_addresses[index],
+			schedule_id,
+			&treasury_seed_name,
+			None,
+			None,
+			payout_queue,
+			&[],
+			recent_blockhash,
+		)
+	};
+
+	let drain_ix = |order: &[usize], recent_blockhash| {
+		let payout_accounts: Vec<Pubkey> = order
+			.iter()
+			.flat_map(|&index| [recipient_pdas[index], recipient_addresses[index]])
+			.collect();
+		treasury_vault_ix_interface::drain_queue_ix_setup(
+			&admin_keypair,
+			treasury_pda,
+			payout_queue_pda,
+			&treasury_seed_name,
+			None,
+			&payout_accounts,
+			recent_blockhash,
+		)
+	};
+
+	// The first payout uses up the day; later ones fail without the queue and wait in it with it
+	banks_client.process_transaction(execute_ix(0, None, recent_blockhash)).await.unwrap();
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(execute_ix(0, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(execute_ix(0, Some(payout_queue_pda), recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(execute_ix(1, Some(payout_queue_pda), recent_blockhash)).await.unwrap();
+
+	// A queued payout that should not be paid can be dropped by the config authority
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	banks_client.process_transaction(execute_ix(0, Some(payout_queue_pda), recent_blockhash)).await.unwrap();
+
+	// Queued payouts leave the booked balance so nothing else can spend them
+	let get_total_balance = |account: Account| TreasuryConfig::try_deserialize(&mut account.data.as_ref()).unwrap().total_balance;
+	let total_balance = get_total_balance(banks_client.get_account(treasury_pda).await.unwrap().unwrap());
+	assert_eq!(total_balance, 1_000_000_000 - 4 * payout_amount);
+
+	let prune_ix = |sequence: u64, recent_blockhash| {
+		treasury_vault_ix_interface::prune_queued_payout_ix_setup(
+			treasury_pda,
+			payout_queue_pda,
+			&admin_keypair,
+			sequence,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	banks_client.process_transaction(prune_ix(2, recent_blockhash)).await.unwrap();
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(prune_ix(2, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let total_balance = get_total_balance(banks_client.get_account(treasury_pda).await.unwrap().unwrap());
+	assert_eq!(total_balance, 1_000_000_000 - 3 * payout_amount);
+
+	let payout_queue_account = banks_client.get_account(payout_queue_pda).await.unwrap().unwrap();
+	let payout_queue = PendingPayoutQueue::try_deserialize(&mut payout_queue_account.data.as_ref()).unwrap();
+	let queued: Vec<Pubkey> = payout_queue.entries.iter().map(|entry| entry.recipient).collect();
+	assert_eq!(queued, vec![recipient_pdas[1], recipient_pdas[0]]);
+
+	// Nothing drains until the limit window frees up
+	let result = banks_client.process_transaction(drain_ix(&[1, 0], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Each day drains one payout, highest priority first
+	clock.unix_timestamp = now + 86_400;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = banks_client.process_transaction(drain_ix(&[0], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(drain_ix(&[1, 0], recent_blockhash)).await.unwrap();
+
+	clock.unix_timestamp = now + 2 * 86_400;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let result = banks_client.process_transaction(drain_ix(&[0], recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payout_queue_account = banks_client.get_account(payout_queue_pda).await.unwrap().unwrap();
+	let payout_queue = PendingPayoutQueue::try_deserialize(&mut payout_queue_account.data.as_ref()).unwrap();
+	assert!(payout_queue.entries.is_empty());
+	assert_eq!(payout_queue.next_sequence, 3);
+
+	let total_balance = get_total_balance(banks_client.get_account(treasury_pda).await.unwrap().unwrap());
+	assert_eq!(total_balance, 1_000_000_000 - 3 * payout_amount);
+
+	for (recipient_pda, expected) in recipient_pdas.iter().zip([2 * payout_amount, payout_amount]) {
+		let recipient_account = banks_client.get_account(*recipient_pda).await.unwrap().unwrap();
+		let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+		assert_eq!(recipient.total_received, expected);
+	}
+}
@@ -73,6 +73,8 @@ async fn emergency_withdraw_sol_ix_success() {
 		&admin_keypair,
 		amount,
 		&treasury_seed_name,
+		None,
+		None,
 		recent_blockhash,
 	);
 
@@ -114,6 +114,8 @@ async fn emergency_withdraw_token_ix_success() {
 		csl_spl_token_v0_0_0_pubkey,
 		amount,
 		&treasury_seed_name,
+		None,
+		None,
 		recent_blockhash,
 	);
 
@@ -8,10 +8,13 @@ use {
 		csl_spl_token_ix_interface,
 		csl_spl_assoc_token_ix_interface,
 	},
+    anchor_lang::AccountDeserialize,
     solana_program_test::tokio,
     solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
     },
+    treasury_vault::{OraclePricing, PayoutSchedule, PayoutSplit, Recipient, RecipientCaps, Recurrence, ScheduleStatus},
 };
 
 
@@ -94,14 +97,911 @@ async fn execute_sol_payout_ix_success() {
 		recipient_pda,
 		payout_schedule_pda,
 		recipient_address_pubkey,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+}
+
+
+#[tokio::test]
+async fn execute_sol_payout_ix_catch_up() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("payroll");
+	let payout_amount: u64 = 1_000;
+	let daily_limit: u64 = 2_500;
+	let start_time: i64 = 1_736_499_600;
+	let interval_seconds: u64 = 1_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let schedule_pda = |schedule_id: u64| Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [admin_pubkey, recipient_address_pubkey] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+
+	// Spending limit windows start when the treasury is created
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	clock.unix_timestamp = start_time;
+	context.set_sysvar(&clock);
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Payroll"),
+		admin_pubkey,
+		daily_limit,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 2_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the payout can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 2_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Engineer"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	for (schedule_id, max_executions) in [(1u64, 5u64), (2, 0)] {
+		let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			schedule_pda(schedule_id),
+			&admin_keypair,
+			system_program::ID,
+			recipient_address_pubkey,
+			schedule_id,
+			payout_amount,
+			None,
+			start_time,
+			interval_seconds,
+			max_executions,
+			Recurrence::Interval,
+			0,
+			None,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	// Three periods are due but the daily limit only covers two; the rest
+	// are paid on later days until max_executions is reached
+	for (now, paid, executions) in [
+		(start_time + 2_500, 2_000, 2),
+		(start_time + 2_500 + 86_400, 2_000, 4),
+		(start_time + 2_500 + 2 * 86_400, 1_000, 5),
+	] {
+		clock.unix_timestamp = now;
+		context.set_sysvar(&clock);
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+		let before = banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+		let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+			&admin_keypair,
+			treasury_pda,
+			recipient_pda,
+			schedule_pda(1),
+			recipient_address_pubkey,
+			recipient_address_pubkey,
+			1,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			&[],
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+		let after = banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+
+		// ASSERTIONS
+		assert_eq!(after - before, paid);
+
+		let schedule_account = banks_client.get_account(schedule_pda(1)).await.unwrap().unwrap();
+		let schedule = PayoutSchedule::try_deserialize(&mut schedule_account.data.as_ref()).unwrap();
+		assert_eq!(schedule.executions, executions);
+	}
+
+	let schedule_account = banks_client.get_account(schedule_pda(1)).await.unwrap().unwrap();
+	let schedule = PayoutSchedule::try_deserialize(&mut schedule_account.data.as_ref()).unwrap();
+	assert_eq!(schedule.status, ScheduleStatus::Completed);
+
+	// With skip-missed, the late execution pays one period and drops the rest
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let ix = treasury_vault_ix_interface::update_treasury_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		Some(true),
+		None,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let before = banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		schedule_pda(2),
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		2,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+	let after = banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+	assert_eq!(after - before, payout_amount);
+
+	let schedule_account = banks_client.get_account(schedule_pda(2)).await.unwrap().unwrap();
+	let schedule = PayoutSchedule::try_deserialize(&mut schedule_account.data.as_ref()).unwrap();
+	assert_eq!(schedule.executions, 176);
+	assert_eq!(schedule.next_due_time(), Some(start_time + 176_000));
+}
+
+
+#[tokio::test]
+async fn execute_sol_payout_ix_split() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("revenue");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_001;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let wallets = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let recipient_pdas = wallets.map(|wallet| Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			wallet.as_ref(),
+		],
+		&treasury_vault::ID,
+	).0);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pdas[0].as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [admin_pubkey, wallets[0], wallets[1], wallets[2]] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Revenue share"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 2_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the payout can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 2_000_000_000).await;
+
+	for (wallet, recipient_pda) in wallets.iter().zip(recipient_pdas) {
+		let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			&admin_keypair,
+			system_program::ID,
+			*wallet,
+			&String::from("Partner"),
+			0,
+			0,
+			[0u8; 32],
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			None,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pdas[0],
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		wallets[0],
+		schedule_id,
+		payout_amount,
+		None,
+		0,
+		0,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Shares above 100% are rejected
+	for (first_bps, second_bps) in [(6_000u16, 5_000u16), (3_333, 3_333)] {
+		let ix = treasury_vault_ix_interface::update_payout_schedule_ix_setup(
+			treasury_pda,
+			recipient_pdas[0],
+			payout_schedule_pda,
+			&admin_keypair,
+			wallets[0],
+			schedule_id,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(vec![
+				PayoutSplit { recipient: recipient_pdas[1], share_bps: first_bps },
+				PayoutSplit { recipient: recipient_pdas[2], share_bps: second_bps },
+			]),
+			None,
+			None,
+			None,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			recent_blockhash,
+		);
+		assert_eq!(banks_client.process_transaction(ix).await.is_ok(), first_bps + second_bps <= 10_000);
+	}
+
+	// Every split's accounts must be provided
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pdas[0],
+		payout_schedule_pda,
+		wallets[0],
+		wallets[0],
 		schedule_id,
 		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[recipient_pdas[1], wallets[1]],
 		recent_blockhash,
 	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
 
+	let mut before = [0u64; 3];
+	for (index, wallet) in wallets.iter().enumerate() {
+		before[index] = banks_client.get_balance(*wallet).await.unwrap();
+	}
+
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pdas[0],
+		payout_schedule_pda,
+		wallets[0],
+		wallets[0],
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[recipient_pdas[1], wallets[1], recipient_pdas[2], wallets[2]],
+		recent_blockhash,
+	);
 	let result = banks_client.process_transaction(ix).await;
 
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	// Each split rounds down; the schedule's recipient keeps the dust
+	for (index, expected) in [335u64, 333, 333].iter().enumerate() {
+		let after = banks_client.get_balance(wallets[index]).await.unwrap();
+		assert_eq!(after - before[index], *expected);
+
+		let recipient_account = banks_client.get_account(recipient_pdas[index]).await.unwrap().unwrap();
+		let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+		assert_eq!(recipient.total_received, *expected);
+	}
+}
+
+
+/// Pyth-style price account quoting at `price` * 10^-8 USD
+fn mock_price_feed(price: i64, confidence: u64, ema_price: i64, publish_time: i64) -> Account {
+	let mut data = vec![0u8; 240];
+	data[0..4].copy_from_slice(&0xa1b2_c3d4u32.to_le_bytes());
+	data[8..12].copy_from_slice(&3u32.to_le_bytes());
+	data[20..24].copy_from_slice(&(-8i32).to_le_bytes());
+	data[48..56].copy_from_slice(&ema_price.to_le_bytes());
+	data[96..104].copy_from_slice(&publish_time.to_le_bytes());
+	data[208..216].copy_from_slice(&price.to_le_bytes());
+	data[216..224].copy_from_slice(&confidence.to_le_bytes());
+	data[224..228].copy_from_slice(&1u32.to_le_bytes());
+
+	Account {
+		lamports: 10_000_000,
+		data,
+		owner: Pubkey::new_unique(),
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+#[tokio::test]
+async fn execute_sol_payout_ix_oracle_priced() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("payroll");
+	let schedule_id: u64 = 1;
+	let usd_amount: u64 = 300_000_000; // $300.00
+	let sol_price: i64 = 15_000_000_000; // $150.00
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+	let price_feed_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let now = banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Payroll"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 5_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the payout can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 5_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		usd_amount,
+		None,
+		0,
+		0,
+		0,
+		Recurrence::Interval,
+		0,
+		Some(OraclePricing {
+			price_feed: price_feed_pubkey,
+			max_staleness_seconds: 60,
+			max_confidence_bps: 100,
+			max_slippage_bps: 500,
+		}),
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let execute_ix = |price_feed: Option<Pubkey>, recent_blockhash| treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		price_feed,
+		None,
+		&[],
+		recent_blockhash,
+	);
+
+	// The price feed is required
+	let result = banks_client.process_transaction(execute_ix(None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Stale, uncertain and outlying prices are rejected
+	for feed in [
+		mock_price_feed(sol_price, 10_000_000, sol_price, now - 61),
+		mock_price_feed(sol_price, 300_000_000, sol_price, now),
+		mock_price_feed(12_000_000_000, 10_000_000, sol_price, now),
+	] {
+		context.set_account(&price_feed_pubkey, &feed.into());
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+		let result = banks_client.process_transaction(execute_ix(Some(price_feed_pubkey), recent_blockhash)).await;
+		assert!(result.is_err());
+	}
+
+	context.set_account(&price_feed_pubkey, &mock_price_feed(sol_price, 10_000_000, 14_500_000_000, now).into());
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(execute_ix(Some(price_feed_pubkey), recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// $300 at $150 per SOL
+	let recipient_balance = banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+	assert_eq!(recipient_balance, 2_000_000_000);
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.total_received, 2_000_000_000);
+}
+
+#[tokio::test]
+async fn execute_sol_payout_ix_recipient_caps() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("grants");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_000_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let now = clock.unix_timestamp;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Grants"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the payout can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	// Hold every payout to recipients added in the last day
+	let ix = treasury_vault_ix_interface::update_treasury_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		Some(86_400),
+		Some(0),
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Grantee"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		None,
+		0,
+		0,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// A per period cap needs a period length
+	for (period_seconds, expect_ok) in [(0u64, false), (86_400, true)] {
+		let ix = treasury_vault_ix_interface::update_recipient_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			&admin_keypair,
+			recipient_address_pubkey,
+			None,
+			None,
+			None,
+			Some(RecipientCaps {
+				per_payout: 0,
+				per_period: 1_500_000,
+				period_seconds,
+				lifetime: 2_500_000,
+			}),
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		);
+		assert_eq!(banks_client.process_transaction(ix).await.is_ok(), expect_ok);
+	}
+
+	// Held during the cooling period, then one payout per period until the lifetime cap
+	for (time, expect_ok) in [
+		(now, false),
+		(now + 86_400, true),
+		(now + 86_400, false),
+		(now + 2 * 86_400, true),
+		(now + 3 * 86_400, false),
+	] {
+		clock.unix_timestamp = time;
+		context.set_sysvar(&clock);
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+		let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+			&admin_keypair,
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			recipient_address_pubkey,
+			recipient_address_pubkey,
+			schedule_id,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			&[],
+			recent_blockhash,
+		);
+		assert_eq!(banks_client.process_transaction(ix).await.is_ok(), expect_ok);
+	}
+
+	// ASSERTIONS
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.total_received, 2 * payout_amount);
+	assert_eq!(recipient.period_received, payout_amount);
 }
@@ -97,6 +97,15 @@ async fn execute_token_payout_ix_success() {
 		&treasury_vault::ID,
 	);
 
+	let (payout_destination_pda, _payout_destination_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payout_destination",
+			recipient_pda.as_ref(),
+			token_mint_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		fee_payer_pubkey,
@@ -137,6 +146,10 @@ async fn execute_token_payout_ix_success() {
 		recipient_address,
 		schedule_id,
 		&treasury_seed_name,
+		None,
+		payout_destination_pda,
+		None,
+		&[],
 		recent_blockhash,
 	);
 
@@ -127,6 +127,7 @@ async fn initialize_token_vault_ix_success() {
 		token_program_pubkey,
 		csl_spl_assoc_token_v0_0_0_pubkey,
 		&treasury_seed_name,
+		None,
 		recent_blockhash,
 	);
 
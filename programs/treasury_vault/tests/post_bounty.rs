@@ -0,0 +1,290 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::{AccountDeserialize, AccountSerialize},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{
+        Bounty, BountyClaim, BountyStatus, Recipient, TreasuryConfig,
+    },
+};
+
+
+#[tokio::test]
+async fn post_bounty_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("bounties");
+	let description_hash: [u8; 32] = [3u8; 32];
+	let evidence_hash: [u8; 32] = [5u8; 32];
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+	let hunter_keypairs = [Keypair::new(), Keypair::new()];
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let hunter_pubkeys: Vec<Pubkey> = hunter_keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+	let outsider_pubkey = outsider_keypair.pubkey();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let recipient_pda = |hunter: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			hunter.as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	let bounty_pda = |bounty_id: u64| Pubkey::find_program_address(
+		&[
+			b"bounty",
+			treasury_pda.as_ref(),
+			bounty_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	let bounty_claim_pda = |bounty_id: u64, hunter: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"bounty_claim",
+			bounty_pda(bounty_id).as_ref(),
+			recipient_pda(hunter).as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, treasurer_pubkey, outsider_pubkey].into_iter().chain(hunter_pubkeys.clone()) {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let now = clock.unix_timestamp;
+
+	// A daily limit of 60_000_000 leaves room for only one of the two rewards
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Bug bounties"),
+		treasurer_pubkey,
+		60_000_000,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so rewards can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	for hunter in &hunter_pubkeys {
+		let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+			treasury_pda,
+			recipient_pda(hunter),
+			&admin_keypair,
+			system_program::ID,
+			*hunter,
+			&String::from("Hunter"),
+			0,
+			0,
+			[0u8; 32],
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			None,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	let post_ix = |bounty_id: u64, reward: u64, deadline: i64| {
+		treasury_vault_ix_interface::post_bounty_ix_setup(
+			treasury_pda,
+			bounty_pda(bounty_id),
+			None,
+			&treasurer_keypair,
+			system_program::ID,
+			bounty_id,
+			None,
+			reward,
+			deadline,
+			description_hash,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let claim_ix = |bounty_id: u64, hunter: &Keypair, recent_blockhash| {
+		treasury_vault_ix_interface::submit_bounty_claim_ix_setup(
+			treasury_pda,
+			bounty_pda(bounty_id),
+			recipient_pda(&hunter.pubkey()),
+			bounty_claim_pda(bounty_id, &hunter.pubkey()),
+			hunter,
+			system_program::ID,
+			bounty_id,
+			evidence_hash,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let accept_ix = |bounty_id: u64, hunter: &Pubkey, reviewer: &Keypair, recent_blockhash| {
+		treasury_vault_ix_interface::accept_sol_bounty_claim_ix_setup(
+			treasury_pda,
+			bounty_pda(bounty_id),
+			recipient_pda(hunter),
+			bounty_claim_pda(bounty_id, hunter),
+			*hunter,
+			reviewer,
+			bounty_id,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let expire_ix = |bounty_id: u64, recent_blockhash| {
+		treasury_vault_ix_interface::expire_bounty_ix_setup(
+			&outsider_keypair,
+			treasury_pda,
+			bounty_pda(bounty_id),
+			None,
+			bounty_id,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let result = banks_client.process_transaction(post_ix(1, 50_000_000, now)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(post_ix(1, 50_000_000, now + 1_000)).await.unwrap();
+	banks_client.process_transaction(post_ix(2, 30_000_000, now + 1_000)).await.unwrap();
+
+	// Only whitelisted recipients can hunt
+	let result = banks_client.process_transaction(claim_ix(1, &outsider_keypair, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(claim_ix(1, &hunter_keypairs[0], recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(claim_ix(1, &hunter_keypairs[1], recent_blockhash)).await.unwrap();
+	banks_client.process_transaction(claim_ix(2, &hunter_keypairs[0], recent_blockhash)).await.unwrap();
+
+	// Only reviewers accept claims, and a bounty is awarded once
+	let result = banks_client.process_transaction(accept_ix(1, &hunter_pubkeys[1], &outsider_keypair, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(accept_ix(1, &hunter_pubkeys[1], &treasurer_keypair, recent_blockhash)).await.unwrap();
+
+	let result = banks_client.process_transaction(accept_ix(1, &hunter_pubkeys[0], &treasurer_keypair, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Rewards are held to the spending limits
+	let result = banks_client.process_transaction(accept_ix(2, &hunter_pubkeys[0], &treasurer_keypair, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Only claims submitted by the deadline can be accepted
+	banks_client.process_transaction(post_ix(3, 5_000_000, now + 1_000)).await.unwrap();
+	banks_client.process_transaction(claim_ix(3, &hunter_keypairs[0], recent_blockhash)).await.unwrap();
+
+	let mut bounty_claim_account = banks_client.get_account(bounty_claim_pda(3, &hunter_pubkeys[0])).await.unwrap().unwrap();
+	let mut bounty_claim = BountyClaim::try_deserialize(&mut bounty_claim_account.data.as_ref()).unwrap();
+	bounty_claim.submitted_at = now + 1_001;
+	let mut data = Vec::new();
+	bounty_claim.try_serialize(&mut data).unwrap();
+	bounty_claim_account.data[..data.len()].copy_from_slice(&data);
+	context.set_account(&bounty_claim_pda(3, &hunter_pubkeys[0]), &bounty_claim_account.into());
+
+	let result = banks_client.process_transaction(accept_ix(3, &hunter_pubkeys[0], &treasurer_keypair, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// The unawarded bounty expires after its deadline, freeing its reward
+	let result = banks_client.process_transaction(expire_ix(2, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	clock.unix_timestamp = now + 1_001;
+	context.set_sysvar(&clock);
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(claim_ix(2, &hunter_keypairs[1], recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(expire_ix(3, recent_blockhash)).await.unwrap();
+
+	let result = banks_client.process_transaction(expire_ix(2, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let bounty_account = banks_client.get_account(bounty_pda(1)).await.unwrap().unwrap();
+	let bounty = Bounty::try_deserialize(&mut bounty_account.data.as_ref()).unwrap();
+	assert_eq!(bounty.status, BountyStatus::Awarded);
+	assert_eq!(bounty.claim_count, 2);
+	assert_eq!(bounty.winner, Some(recipient_pda(&hunter_pubkeys[1])));
+	assert_eq!(bounty.description_hash, description_hash);
+
+	let bounty_account = banks_client.get_account(bounty_pda(2)).await.unwrap().unwrap();
+	let bounty = Bounty::try_deserialize(&mut bounty_account.data.as_ref()).unwrap();
+	assert_eq!(bounty.status, BountyStatus::Expired);
+	assert_eq!(bounty.winner, None);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.total_balance, 950_000_000);
+	assert_eq!(treasury.open_bounties, 0);
+
+	let recipient_account = banks_client.get_account(recipient_pda(&hunter_pubkeys[1])).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.total_received, 50_000_000);
+}
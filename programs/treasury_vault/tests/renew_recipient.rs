@@ -0,0 +1,301 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{Recipient, Recurrence},
+};
+
+
+#[tokio::test]
+async fn renew_recipient_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("vendors");
+	let schedule_id: u64 = 1;
+	let stream_id: u64 = 1;
+	let payout_amount: u64 = 1_000;
+	let attestation_ref: [u8; 32] = [7u8; 32];
+	let renewed_attestation_ref: [u8; 32] = [9u8; 32];
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (streaming_schedule_pda, _streaming_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"stream",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		recipient_address_pubkey,
+		Account {
+			lamports: 1_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let recent_blockhash = context.last_blockhash;
+	let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+	let valid_until = clock.unix_timestamp + 100;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Vendor payments"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	context.banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 2_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	context.banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so payouts can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 2_000_000_000).await;
+
+	// An attestation that has already lapsed is refused
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Vendor"),
+		0,
+		clock.unix_timestamp - 1,
+		attestation_ref,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	assert!(context.banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Vendor"),
+		0,
+		valid_until,
+		attestation_ref,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	context.banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		None,
+		clock.unix_timestamp,
+		86_400,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	context.banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_streaming_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		streaming_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		stream_id,
+		1_000_000,
+		10_000,
+		clock.unix_timestamp,
+		clock.unix_timestamp,
+		100,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	context.banks_client.process_transaction(ix).await.unwrap();
+
+	// Move past the whitelist expiry
+	let mut expired_clock = clock.clone();
+	expired_clock.unix_timestamp = valid_until + 1;
+	context.set_sysvar(&expired_clock);
+
+	// The stream can still be cancelled, withholding what vested for the expired recipient
+	let before = context.banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+	let ix = treasury_vault_ix_interface::cancel_stream_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		streaming_schedule_pda,
+		&admin_keypair,
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		stream_id,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	context.banks_client.process_transaction(ix).await.unwrap();
+	assert!(context.banks_client.get_account(streaming_schedule_pda).await.unwrap().is_none());
+	assert_eq!(context.banks_client.get_balance(recipient_address_pubkey).await.unwrap(), before);
+
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	assert!(context.banks_client.process_transaction(ix).await.is_err());
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let ix = treasury_vault_ix_interface::renew_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		recipient_address_pubkey,
+		expired_clock.unix_timestamp + 365 * 86_400,
+		renewed_attestation_ref,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recipient_account = context.banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.valid_until, expired_clock.unix_timestamp + 365 * 86_400);
+	assert_eq!(recipient.attestation_ref, renewed_attestation_ref);
+	assert_eq!(recipient.attested_at, expired_clock.unix_timestamp);
+
+	// Payouts resume once the entry is renewed
+	let before = context.banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	context.banks_client.process_transaction(ix).await.unwrap();
+	let after = context.banks_client.get_balance(recipient_address_pubkey).await.unwrap();
+	assert_eq!(after - before, payout_amount);
+}
@@ -0,0 +1,271 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::{AccountDeserialize, AnchorDeserialize},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{KeeperMode, PayoutDue, Recurrence, TreasuryConfig},
+};
+
+
+#[tokio::test]
+async fn set_keeper_config_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("keepers");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_000_000;
+	let keeper_tip: u64 = 5_000;
+	let keeper_tip_period_cap: u64 = 12_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let keeper_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let keeper_pubkey = keeper_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, keeper_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Keeper run payouts"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so payouts can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Vendor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		None,
+		0,
+		0,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let execute_ix = |recent_blockhash| {
+		treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+			&keeper_keypair,
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			recipient_address_pubkey,
+			recipient_address_pubkey,
+			schedule_id,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			&[],
+			recent_blockhash,
+		)
+	};
+
+	// Allowlisted by default, so an outside keeper cannot execute
+	let result = banks_client.process_transaction(execute_ix(recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// A tip needs a period to cap it over
+	let ix = treasury_vault_ix_interface::set_keeper_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		KeeperMode::Permissionless,
+		keeper_tip,
+		0,
+		keeper_tip_period_cap,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	// Only the config permission can change the keeper mode
+	let ix = treasury_vault_ix_interface::set_keeper_config_ix_setup(
+		treasury_pda,
+		&keeper_keypair,
+		KeeperMode::Permissionless,
+		keeper_tip,
+		86_400,
+		keeper_tip_period_cap,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+	assert!(result.is_err());
+
+	let ix = treasury_vault_ix_interface::set_keeper_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		KeeperMode::Permissionless,
+		keeper_tip,
+		86_400,
+		keeper_tip_period_cap,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Keepers can see the payout is due and what it tips
+	let ix = treasury_vault_ix_interface::check_payout_due_ix_setup(
+		&keeper_keypair,
+		treasury_pda,
+		payout_schedule_pda,
+		schedule_id,
+		&treasury_seed_name,
+		recent_blockhash,
+	);
+	let simulation = banks_client.simulate_transaction(ix).await.unwrap();
+	let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+	let payout_due = PayoutDue::try_from_slice(&return_data.data).unwrap();
+	assert_eq!(payout_due.due_periods, 1);
+	assert_eq!(payout_due.keeper_tip, keeper_tip);
+
+	// Tips are paid in full until the period cap runs low, then partly, then not at all
+	let mut tips_paid = 0;
+	for expected_tip in [keeper_tip, keeper_tip, 2_000, 0] {
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+		banks_client.process_transaction(execute_ix(recent_blockhash)).await.unwrap();
+		tips_paid += expected_tip;
+
+		let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+		let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+		assert_eq!(treasury.keeper_tips_paid, tips_paid);
+	}
+
+	// The cap restarts with the next period
+	clock.unix_timestamp += 86_400;
+	context.set_sysvar(&clock);
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+	let keeper_balance = banks_client.get_balance(keeper_pubkey).await.unwrap();
+	let result = banks_client.process_transaction(execute_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	// The tip covers more than the keeper's transaction fee
+	let fee = 5_000;
+	assert_eq!(banks_client.get_balance(keeper_pubkey).await.unwrap(), keeper_balance - fee + keeper_tip);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.keeper_tips_paid, keeper_tip);
+	assert_eq!(treasury.total_balance, 1_000_000_000 - 5 * payout_amount - tips_paid - keeper_tip);
+}
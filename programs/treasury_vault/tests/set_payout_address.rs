@@ -0,0 +1,312 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{Recipient, Recurrence},
+};
+
+
+#[tokio::test]
+async fn set_payout_address_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("payroll");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let impostor_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = recipient_keypair.pubkey();
+	let payout_address_pubkey = Pubkey::new_unique();
+	let cosigned_payout_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	for wallet in [recipient_address_pubkey, impostor_keypair.pubkey(), payout_address_pubkey, cosigned_payout_address_pubkey] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 1_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Payroll"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 2_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the payout can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 2_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Engineer"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		None,
+		0,
+		0,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Only the recipient can move their payouts
+	let ix = treasury_vault_ix_interface::set_payout_address_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&impostor_keypair,
+		None,
+		None,
+		recipient_address_pubkey,
+		impostor_keypair.pubkey(),
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::set_payout_address_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&recipient_keypair,
+		None,
+		None,
+		recipient_address_pubkey,
+		payout_address_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.payout_address, payout_address_pubkey);
+
+	// The old wallet no longer receives payouts
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		recipient_address_pubkey,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let before = banks_client.get_balance(payout_address_pubkey).await.unwrap();
+	let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		recipient_address_pubkey,
+		payout_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+	let after = banks_client.get_balance(payout_address_pubkey).await.unwrap();
+	assert_eq!(after - before, payout_amount);
+
+	// Once the treasury requires a co-sign, the recipient alone cannot change it
+	let ix = treasury_vault_ix_interface::update_treasury_config_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		Some(true),
+		None,
+		None,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::set_payout_address_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&recipient_keypair,
+		None,
+		None,
+		recipient_address_pubkey,
+		cosigned_payout_address_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::set_payout_address_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&recipient_keypair,
+		Some(&impostor_keypair),
+		None,
+		recipient_address_pubkey,
+		cosigned_payout_address_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::set_payout_address_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&recipient_keypair,
+		Some(&admin_keypair),
+		None,
+		recipient_address_pubkey,
+		cosigned_payout_address_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.payout_address, cosigned_payout_address_pubkey);
+}
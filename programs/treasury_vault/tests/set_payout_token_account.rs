@@ -0,0 +1,361 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+		csl_spl_token_ix_interface,
+		csl_spl_assoc_token_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    anchor_spl::{
+        associated_token::{get_associated_token_address, spl_associated_token_account},
+        token::{spl_token, TokenAccount},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{PayoutDestination, Recurrence},
+};
+
+
+#[tokio::test]
+async fn set_payout_token_account_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("grants");
+	let schedule_id: u64 = 1;
+	let deposit_amount: u64 = 5_000_000;
+	let payout_amount: u64 = 1_000_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let vault_authority_keypair = Keypair::new();
+	let depositor_authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let mint_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let vault_authority_pubkey = vault_authority_keypair.pubkey();
+	let depositor_authority_pubkey = depositor_authority_keypair.pubkey();
+	let recipient_address_pubkey = recipient_keypair.pubkey();
+	let mint_pubkey = mint_keypair.pubkey();
+	let custody_pubkey = Pubkey::new_unique();
+	let vault_token_account = get_associated_token_address(&vault_authority_pubkey, &mint_pubkey);
+	let depositor_token_account = get_associated_token_address(&depositor_authority_pubkey, &mint_pubkey);
+	let recipient_token_account = get_associated_token_address(&recipient_address_pubkey, &mint_pubkey);
+	let custody_token_account = get_associated_token_address(&custody_pubkey, &mint_pubkey);
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (token_vault_pda, _token_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"token_vault",
+			treasury_pda.as_ref(),
+			mint_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_destination_pda, _payout_destination_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payout_destination",
+			recipient_pda.as_ref(),
+			mint_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for (wallet, owner) in [
+		(admin_pubkey, system_program::ID),
+		(recipient_address_pubkey, system_program::ID),
+		(vault_authority_pubkey, spl_token::ID),
+		(depositor_authority_pubkey, spl_token::ID),
+	] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: owner,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let rent = banks_client.get_rent().await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[
+			system_instruction::create_account(
+				&admin_pubkey,
+				&mint_pubkey,
+				rent.minimum_balance(spl_token::state::Mint::LEN),
+				spl_token::state::Mint::LEN as u64,
+				&spl_token::ID,
+			),
+			spl_token::instruction::initialize_mint(&spl_token::ID, &mint_pubkey, &admin_pubkey, None, 6).unwrap(),
+			spl_associated_token_account::instruction::create_associated_token_account(&admin_pubkey, &depositor_authority_pubkey, &mint_pubkey, &spl_token::ID),
+			spl_associated_token_account::instruction::create_associated_token_account(&admin_pubkey, &recipient_address_pubkey, &mint_pubkey, &spl_token::ID),
+			spl_associated_token_account::instruction::create_associated_token_account(&admin_pubkey, &custody_pubkey, &mint_pubkey, &spl_token::ID),
+			spl_token::instruction::mint_to(&spl_token::ID, &mint_pubkey, &depositor_token_account, &admin_pubkey, &[], deposit_amount).unwrap(),
+		],
+		Some(&admin_pubkey),
+		&[&admin_keypair, &mint_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Grants"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_token_vault_ix_setup(
+		treasury_pda,
+		token_vault_pda,
+		mint_pubkey,
+		&admin_keypair,
+		system_program::ID,
+		&admin_keypair,
+		vault_token_account,
+		vault_authority_pubkey,
+		mint_pubkey,
+		csl_spl_token_ix_interface::ID,
+		csl_spl_assoc_token_ix_interface::ID,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::deposit_token_ix_setup(
+		treasury_pda,
+		token_vault_pda,
+		mint_pubkey,
+		&admin_keypair,
+		depositor_token_account,
+		vault_token_account,
+		&depositor_authority_keypair,
+		csl_spl_token_ix_interface::ID,
+		deposit_amount,
+		&treasury_seed_name,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Grantee"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		Some(mint_pubkey),
+		0,
+		0,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Tokens cannot be sent to an arbitrary account
+	let ix = treasury_vault_ix_interface::execute_token_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		token_vault_pda,
+		mint_pubkey,
+		vault_token_account,
+		depositor_token_account,
+		&vault_authority_keypair,
+		csl_spl_token_ix_interface::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		payout_destination_pda,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	// Without a registered token account, the payout address's ATA is used
+	let ix = treasury_vault_ix_interface::execute_token_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		token_vault_pda,
+		mint_pubkey,
+		vault_token_account,
+		recipient_token_account,
+		&vault_authority_keypair,
+		csl_spl_token_ix_interface::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		payout_destination_pda,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::set_payout_token_account_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_destination_pda,
+		mint_pubkey,
+		custody_token_account,
+		&recipient_keypair,
+		None,
+		None,
+		system_program::ID,
+		recipient_address_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination_account = banks_client.get_account(payout_destination_pda).await.unwrap().unwrap();
+	let destination = PayoutDestination::try_deserialize(&mut destination_account.data.as_ref()).unwrap();
+	assert_eq!(destination.recipient, recipient_pda);
+	assert_eq!(destination.token_mint, mint_pubkey);
+	assert_eq!(destination.token_account, custody_token_account);
+
+	// The registered token account replaces the default one
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let ix = treasury_vault_ix_interface::execute_token_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		token_vault_pda,
+		mint_pubkey,
+		vault_token_account,
+		recipient_token_account,
+		&vault_authority_keypair,
+		csl_spl_token_ix_interface::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		payout_destination_pda,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	assert!(banks_client.process_transaction(ix).await.is_err());
+
+	let ix = treasury_vault_ix_interface::execute_token_payout_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		token_vault_pda,
+		mint_pubkey,
+		vault_token_account,
+		custody_token_account,
+		&vault_authority_keypair,
+		csl_spl_token_ix_interface::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		&treasury_seed_name,
+		None,
+		payout_destination_pda,
+		None,
+		&[],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	for (token_account, expected) in [(recipient_token_account, payout_amount), (custody_token_account, payout_amount)] {
+		let account = banks_client.get_account(token_account).await.unwrap().unwrap();
+		let tokens = TokenAccount::try_deserialize(&mut account.data.as_ref()).unwrap();
+		assert_eq!(tokens.amount, expected);
+	}
+}
@@ -0,0 +1,255 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{Policy, PolicyRule, Recipient, Recurrence},
+};
+
+
+#[tokio::test]
+async fn set_policy_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("payroll");
+	let schedule_id: u64 = 1;
+	let payout_amount: u64 = 1_000_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let policy_pda = treasury_vault_ix_interface::policy_pda(treasury_pda, None);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+	let mut clock = banks_client.get_sysvar::<Clock>().await.unwrap();
+	let now = clock.unix_timestamp;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Payroll"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so the payout can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Contractor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		payout_amount,
+		None,
+		0,
+		0,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let set_policy_ix = |rules: Vec<PolicyRule>, recent_blockhash| {
+		treasury_vault_ix_interface::set_policy_ix_setup(
+			treasury_pda,
+			policy_pda,
+			&admin_keypair,
+			system_program::ID,
+			None,
+			rules,
+			&treasury_seed_name,
+			None,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let execute_ix = |recent_blockhash| {
+		treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+			&admin_keypair,
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			recipient_address_pubkey,
+			recipient_address_pubkey,
+			schedule_id,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			&[],
+			recent_blockhash,
+		)
+	};
+
+	// Rules out of range are refused
+	let result = banks_client.process_transaction(set_policy_ix(
+		vec![PolicyRule::AllowedHours { weekdays: 0x7f, start_hour: 10, end_hour: 10 }],
+		recent_blockhash,
+	)).await;
+	assert!(result.is_err());
+
+	let ix = set_policy_ix(
+		vec![
+			PolicyRule::MaxPerTransaction { amount: 2 * payout_amount },
+			PolicyRule::PeriodCap { period_seconds: 86_400, cap: 2_500_000, spent: 0, period_start: 0 },
+			PolicyRule::AllowedDestinations { destinations: vec![recipient_address_pubkey] },
+			PolicyRule::AllowedHours { weekdays: 0x7f, start_hour: 0, end_hour: 24 },
+			PolicyRule::MinReserve { amount: 900_000_000 },
+		],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Two payouts fit the daily cap, a third waits for the next period
+	for (time, expect_ok) in [
+		(now, true),
+		(now, true),
+		(now, false),
+		(now + 86_400, true),
+	] {
+		clock.unix_timestamp = time;
+		context.set_sysvar(&clock);
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+		let result = banks_client.process_transaction(execute_ix(recent_blockhash)).await;
+		assert_eq!(result.is_ok(), expect_ok);
+	}
+
+	let policy_account = banks_client.get_account(policy_pda).await.unwrap().unwrap();
+	let policy = Policy::try_deserialize(&mut policy_account.data.as_ref()).unwrap();
+	assert_eq!(
+		policy.rules[1],
+		PolicyRule::PeriodCap { period_seconds: 86_400, cap: 2_500_000, spent: payout_amount, period_start: now + 86_400 },
+	);
+
+	// Each rule on its own turns the next payout away
+	let today = ((now + 86_400) / 86_400 + 3) % 7;
+	for rule in [
+		PolicyRule::MaxPerTransaction { amount: payout_amount - 1 },
+		PolicyRule::AllowedDestinations { destinations: vec![Pubkey::new_unique()] },
+		PolicyRule::AllowedHours { weekdays: 0x7f & !(1 << today) as u8, start_hour: 0, end_hour: 24 },
+		PolicyRule::MinReserve { amount: 997_000_000 - payout_amount + 1 },
+	] {
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+		banks_client.process_transaction(set_policy_ix(vec![rule], recent_blockhash)).await.unwrap();
+
+		let result = banks_client.process_transaction(execute_ix(recent_blockhash)).await;
+		assert!(result.is_err());
+	}
+
+	// Lifting the policy leaves only the treasury's spending limits
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	banks_client.process_transaction(set_policy_ix(vec![], recent_blockhash)).await.unwrap();
+
+	let result = banks_client.process_transaction(execute_ix(recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.total_received, 4 * payout_amount);
+}
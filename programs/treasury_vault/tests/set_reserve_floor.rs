@@ -0,0 +1,307 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{ApprovalAction, Recurrence, TreasuryConfig, PERMISSION_APPROVE},
+};
+
+
+#[tokio::test]
+async fn set_reserve_floor_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("reserves");
+	let schedule_id: u64 = 1;
+	let reserve_floor: u64 = 600_000_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let approver_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let approver_pubkey = approver_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (approver_member_pda, _approver_member_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"member",
+			treasury_pda.as_ref(),
+			approver_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (approval_request_pda, _approval_request_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"approval_request",
+			treasury_pda.as_ref(),
+			1u64.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for wallet in [admin_pubkey, approver_pubkey] {
+		program_test.add_account(
+			wallet,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Reserves"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so outflows can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_member_ix_setup(
+		treasury_pda,
+		approver_member_pda,
+		&admin_keypair,
+		system_program::ID,
+		approver_pubkey,
+		PERMISSION_APPROVE,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Supplier"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		300_000_000,
+		None,
+		0,
+		0,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::set_reserve_floor_ix_setup(
+		treasury_pda,
+		None,
+		&admin_keypair,
+		reserve_floor,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Payouts stop at the floor
+	for expect_ok in [true, false] {
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+		let ix = treasury_vault_ix_interface::execute_sol_payout_ix_setup(
+			&admin_keypair,
+			treasury_pda,
+			recipient_pda,
+			payout_schedule_pda,
+			recipient_address_pubkey,
+			recipient_address_pubkey,
+			schedule_id,
+			&treasury_seed_name,
+			None,
+			None,
+			None,
+			&[],
+			recent_blockhash,
+		);
+		assert_eq!(banks_client.process_transaction(ix).await.is_ok(), expect_ok);
+	}
+
+	let emergency_withdraw_ix = |amount: u64, approval_request: Option<Pubkey>, recent_blockhash| {
+		treasury_vault_ix_interface::emergency_withdraw_sol_ix_setup(
+			treasury_pda,
+			&admin_keypair,
+			amount,
+			&treasury_seed_name,
+			None,
+			approval_request,
+			recent_blockhash,
+		)
+	};
+
+	// An emergency withdrawal may go down to the floor but not below it unaided
+	let result = banks_client.process_transaction(emergency_withdraw_ix(100_000_000, None, recent_blockhash)).await;
+	assert!(result.is_ok());
+
+	let result = banks_client.process_transaction(emergency_withdraw_ix(500_000_000, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Going below the floor takes a request with two approvals
+	let ix = treasury_vault_ix_interface::create_approval_request_ix_setup(
+		treasury_pda,
+		approval_request_pda,
+		&admin_keypair,
+		system_program::ID,
+		1,
+		ApprovalAction::EmergencyWithdrawSol,
+		500_000_000,
+		None,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	for (approver, member) in [(&admin_keypair, None), (&approver_keypair, Some(approver_member_pda))] {
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+		let result = banks_client.process_transaction(
+			emergency_withdraw_ix(500_000_000, Some(approval_request_pda), recent_blockhash),
+		).await;
+		assert!(result.is_err());
+
+		let ix = treasury_vault_ix_interface::approve_request_ix_setup(
+			treasury_pda,
+			approval_request_pda,
+			approver,
+			1,
+			&treasury_seed_name,
+			member,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(
+		emergency_withdraw_ix(500_000_000, Some(approval_request_pda), recent_blockhash),
+	).await;
+	assert!(result.is_ok());
+
+	// Without a floor the rent-exempt minimum still stays in the account
+	let ix = treasury_vault_ix_interface::set_reserve_floor_ix_setup(
+		treasury_pda,
+		None,
+		&admin_keypair,
+		0,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let result = banks_client.process_transaction(emergency_withdraw_ix(100_000_001, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(emergency_withdraw_ix(100_000_000, None, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let rent = banks_client.get_rent().await.unwrap();
+	assert_eq!(treasury_account.lamports, rent.minimum_balance(treasury_account.data.len()));
+
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.total_balance, 0);
+	assert_eq!(treasury.reserve_floor, 0);
+}
@@ -0,0 +1,314 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::{
+        ApprovalAction, ApprovalTier, PaymentRequest, PaymentRequestStatus, Recipient,
+        MAX_PAYMENT_REQUEST_EVENTS,
+    },
+};
+
+
+#[tokio::test]
+async fn submit_payment_request_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("invoices");
+	let invoice_hash: [u8; 32] = [7u8; 32];
+	let invoice_uri: String = String::from("https://invoices.example/2024-001.pdf");
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+	let contractor_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let contractor_pubkey = contractor_keypair.pubkey();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			contractor_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let payment_request_pda = |request_id: u64| Pubkey::find_program_address(
+		&[
+			b"payment_request",
+			recipient_pda.as_ref(),
+			request_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	let approval_request_pda = |approval_request_id: u64| Pubkey::find_program_address(
+		&[
+			b"approval_request",
+			treasury_pda.as_ref(),
+			approval_request_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	).0;
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [admin_pubkey, treasurer_pubkey, contractor_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let mut banks_client = context.banks_client.clone();
+	let recent_blockhash = context.last_blockhash;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Accounts payable"),
+		treasurer_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[system_instruction::transfer(&admin_pubkey, &treasury_pda, 1_000_000_000)],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// Book the directly transferred lamports so payouts can debit total_balance
+	treasury_vault_ix_interface::book_total_balance(&mut context, treasury_pda, 1_000_000_000).await;
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		contractor_pubkey,
+		&String::from("Contractor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// The treasurer pays up to 1_000_000 alone; more needs the admin's approval
+	let ix = treasury_vault_ix_interface::set_approval_policy_ix_setup(
+		treasury_pda,
+		treasury_vault_ix_interface::approval_policy_pda(treasury_pda, None),
+		&admin_keypair,
+		system_program::ID,
+		None,
+		vec![
+			ApprovalTier { up_to: 1_000_000, require_admin: false, approvals: 0, delay_seconds: 0 },
+			ApprovalTier { up_to: u64::MAX, require_admin: true, approvals: 1, delay_seconds: 0 },
+		],
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let submit_ix = |request_id: u64, amount: u64, invoice_uri: &String| {
+		treasury_vault_ix_interface::submit_payment_request_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			payment_request_pda(request_id),
+			&contractor_keypair,
+			system_program::ID,
+			request_id,
+			amount,
+			None,
+			1_900_000_000,
+			invoice_hash,
+			invoice_uri,
+			&treasury_seed_name,
+			recent_blockhash,
+		)
+	};
+
+	let approve_ix = |request_id: u64, reviewer: &Keypair, amount: u64, approval_request: Option<Pubkey>, recent_blockhash| {
+		treasury_vault_ix_interface::approve_sol_payment_request_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			payment_request_pda(request_id),
+			contractor_pubkey,
+			reviewer,
+			request_id,
+			amount,
+			&treasury_seed_name,
+			None,
+			approval_request,
+			recent_blockhash,
+		)
+	};
+
+	let reject_ix = |request_id: u64, recent_blockhash| {
+		treasury_vault_ix_interface::reject_payment_request_ix_setup(
+			treasury_pda,
+			recipient_pda,
+			payment_request_pda(request_id),
+			&treasurer_keypair,
+			request_id,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		)
+	};
+
+	// The invoice URI has a length limit
+	let result = banks_client.process_transaction(submit_ix(1, 3_000_000, &"x".repeat(201))).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(submit_ix(1, 3_000_000, &invoice_uri)).await.unwrap();
+
+	// Only holders of the execute payout permission review requests
+	let result = banks_client.process_transaction(approve_ix(1, &contractor_keypair, 1_000_000, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// The invoice is above the treasurer's tier, so even a part of it within
+	// that tier needs the admin's approval
+	let result = banks_client.process_transaction(approve_ix(1, &treasurer_keypair, 1_000_000, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	for (approval_request_id, amount) in [(1u64, 1_000_000u64), (2, 2_000_000)] {
+		let ix = treasury_vault_ix_interface::create_approval_request_ix_setup(
+			treasury_pda,
+			approval_request_pda(approval_request_id),
+			&treasurer_keypair,
+			system_program::ID,
+			approval_request_id,
+			ApprovalAction::PayPaymentRequest { payment_request: payment_request_pda(1) },
+			amount,
+			None,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+
+		let ix = treasury_vault_ix_interface::approve_request_ix_setup(
+			treasury_pda,
+			approval_request_pda(approval_request_id),
+			&admin_keypair,
+			approval_request_id,
+			&treasury_seed_name,
+			None,
+			recent_blockhash,
+		);
+		banks_client.process_transaction(ix).await.unwrap();
+	}
+
+	// A partial approval pays that part and leaves the rest open
+	banks_client.process_transaction(approve_ix(1, &treasurer_keypair, 1_000_000, Some(approval_request_pda(1)), recent_blockhash)).await.unwrap();
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(approve_ix(1, &treasurer_keypair, 2_500_000, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(approve_ix(1, &treasurer_keypair, 2_000_000, Some(approval_request_pda(2)), recent_blockhash)).await.unwrap();
+
+	// A paid request is final
+	let result = banks_client.process_transaction(reject_ix(1, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	// Partial approvals stop short of the history cap, leaving room for the final review
+	banks_client.process_transaction(submit_ix(3, 1_000_000, &invoice_uri)).await.unwrap();
+	for _ in 1..MAX_PAYMENT_REQUEST_EVENTS - 1 {
+		let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+		banks_client.process_transaction(approve_ix(3, &treasurer_keypair, 100_000, None, recent_blockhash)).await.unwrap();
+	}
+
+	let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+	let result = banks_client.process_transaction(approve_ix(3, &treasurer_keypair, 100_000, None, recent_blockhash)).await;
+	assert!(result.is_err());
+
+	banks_client.process_transaction(reject_ix(3, recent_blockhash)).await.unwrap();
+
+	banks_client.process_transaction(submit_ix(2, 500_000, &invoice_uri)).await.unwrap();
+	let result = banks_client.process_transaction(reject_ix(2, recent_blockhash)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payment_request_account = banks_client.get_account(payment_request_pda(1)).await.unwrap().unwrap();
+	let payment_request = PaymentRequest::try_deserialize(&mut payment_request_account.data.as_ref()).unwrap();
+	assert_eq!(payment_request.status, PaymentRequestStatus::Paid);
+	assert_eq!(payment_request.paid_amount, 3_000_000);
+	assert_eq!(payment_request.invoice_hash, invoice_hash);
+	assert_eq!(payment_request.invoice_uri, invoice_uri);
+	let history: Vec<(PaymentRequestStatus, Pubkey, u64)> = payment_request.history
+		.iter()
+		.map(|event| (event.status, event.actor, event.amount))
+		.collect();
+	assert_eq!(history, vec![
+		(PaymentRequestStatus::Pending, contractor_pubkey, 3_000_000),
+		(PaymentRequestStatus::PartiallyPaid, treasurer_pubkey, 1_000_000),
+		(PaymentRequestStatus::Paid, treasurer_pubkey, 2_000_000),
+	]);
+
+	let payment_request_account = banks_client.get_account(payment_request_pda(2)).await.unwrap().unwrap();
+	let payment_request = PaymentRequest::try_deserialize(&mut payment_request_account.data.as_ref()).unwrap();
+	assert_eq!(payment_request.status, PaymentRequestStatus::Rejected);
+	assert_eq!(payment_request.paid_amount, 0);
+	assert_eq!(payment_request.history.len(), 2);
+
+	let payment_request_account = banks_client.get_account(payment_request_pda(3)).await.unwrap().unwrap();
+	let payment_request = PaymentRequest::try_deserialize(&mut payment_request_account.data.as_ref()).unwrap();
+	assert_eq!(payment_request.status, PaymentRequestStatus::Rejected);
+	assert_eq!(payment_request.paid_amount, 600_000);
+	assert_eq!(payment_request.history.len(), MAX_PAYMENT_REQUEST_EVENTS);
+
+	let recipient_account = banks_client.get_account(recipient_pda).await.unwrap().unwrap();
+	let recipient = Recipient::try_deserialize(&mut recipient_account.data.as_ref()).unwrap();
+	assert_eq!(recipient.total_received, 3_600_000);
+}
@@ -0,0 +1,177 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+		csl_spl_token_ix_interface,
+		csl_spl_assoc_token_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    anchor_spl::{
+        associated_token::{get_associated_token_address, spl_associated_token_account},
+        token::{spl_token, TokenAccount},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, system_program, transaction::Transaction,
+    },
+    treasury_vault::TreasuryConfig,
+};
+
+
+#[tokio::test]
+async fn sweep_token_vault_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("event_treasury");
+	let deposit_amount: u64 = 5_000_000;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let vault_authority_keypair = Keypair::new();
+	let mint_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let vault_authority_pubkey = vault_authority_keypair.pubkey();
+	let mint_pubkey = mint_keypair.pubkey();
+	let successor_pubkey = Pubkey::new_unique();
+	let vault_token_account = get_associated_token_address(&vault_authority_pubkey, &mint_pubkey);
+	let successor_token_account = get_associated_token_address(&successor_pubkey, &mint_pubkey);
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (token_vault_pda, _token_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"token_vault",
+			treasury_pda.as_ref(),
+			mint_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+	let rent = banks_client.get_rent().await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[
+			system_instruction::create_account(
+				&admin_pubkey,
+				&mint_pubkey,
+				rent.minimum_balance(spl_token::state::Mint::LEN),
+				spl_token::state::Mint::LEN as u64,
+				&spl_token::ID,
+			),
+			spl_token::instruction::initialize_mint(&spl_token::ID, &mint_pubkey, &admin_pubkey, None, 6).unwrap(),
+			spl_associated_token_account::instruction::create_associated_token_account(&admin_pubkey, &successor_pubkey, &mint_pubkey, &spl_token::ID),
+		],
+		Some(&admin_pubkey),
+		&[&admin_keypair, &mint_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Per-event treasury"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::initialize_token_vault_ix_setup(
+		treasury_pda,
+		token_vault_pda,
+		mint_pubkey,
+		&admin_keypair,
+		system_program::ID,
+		&admin_keypair,
+		vault_token_account,
+		vault_authority_pubkey,
+		mint_pubkey,
+		csl_spl_token_ix_interface::ID,
+		csl_spl_assoc_token_ix_interface::ID,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = Transaction::new_signed_with_payer(
+		&[spl_token::instruction::mint_to(&spl_token::ID, &mint_pubkey, &vault_token_account, &admin_pubkey, &[], deposit_amount).unwrap()],
+		Some(&admin_pubkey),
+		&[&admin_keypair],
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::begin_decommission_ix_setup(
+		treasury_pda,
+		&admin_keypair,
+		successor_pubkey,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::sweep_token_vault_ix_setup(
+		treasury_pda,
+		token_vault_pda,
+		mint_pubkey,
+		&admin_keypair,
+		vault_token_account,
+		successor_token_account,
+		successor_pubkey,
+		&vault_authority_keypair,
+		csl_spl_token_ix_interface::ID,
+		&treasury_seed_name,
+		None,
+		recent_blockhash,
+	);
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(banks_client.get_account(token_vault_pda).await.unwrap().is_none());
+
+	let successor_account = banks_client.get_account(successor_token_account).await.unwrap().unwrap();
+	let successor_tokens = TokenAccount::try_deserialize(&mut successor_account.data.as_ref()).unwrap();
+	assert_eq!(successor_tokens.amount, deposit_amount);
+
+	let treasury_account = banks_client.get_account(treasury_pda).await.unwrap().unwrap();
+	let treasury = TreasuryConfig::try_deserialize(&mut treasury_account.data.as_ref()).unwrap();
+	assert_eq!(treasury.token_vault_count, 0);
+}
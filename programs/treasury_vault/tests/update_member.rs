@@ -0,0 +1,228 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_vault_ix_interface,
+	},
+    anchor_lang::AccountDeserialize,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+    treasury_vault::{
+        Member, Recurrence, PERMISSION_CONFIG, PERMISSION_CREATE_SCHEDULE, PERMISSION_EMERGENCY,
+        PERMISSION_MANAGE_RECIPIENTS,
+    },
+};
+
+
+#[tokio::test]
+async fn update_member_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let treasury_seed_name: String = String::from("operations");
+	let schedule_id: u64 = 1;
+
+	// KEYPAIR
+	let admin_keypair = Keypair::new();
+	let operator_keypair = Keypair::new();
+	let manager_keypair = Keypair::new();
+
+	// PUBKEY
+	let admin_pubkey = admin_keypair.pubkey();
+	let operator_pubkey = operator_keypair.pubkey();
+	let manager_pubkey = manager_keypair.pubkey();
+	let recipient_address_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			treasury_seed_name.as_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (member_pda, _member_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"member",
+			treasury_pda.as_ref(),
+			operator_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (manager_member_pda, _manager_member_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"member",
+			treasury_pda.as_ref(),
+			manager_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (recipient_pda, _recipient_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recipient",
+			treasury_pda.as_ref(),
+			recipient_address_pubkey.as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	let (payout_schedule_pda, _payout_schedule_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"schedule",
+			treasury_pda.as_ref(),
+			recipient_pda.as_ref(),
+			schedule_id.to_le_bytes().as_ref(),
+		],
+		&treasury_vault::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		admin_pubkey,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	for pubkey in [operator_pubkey, manager_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 10_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_vault_ix_interface::initialize_treasury_ix_setup(
+		&admin_keypair,
+		treasury_pda,
+		system_program::ID,
+		&treasury_seed_name,
+		&String::from("Operations"),
+		admin_pubkey,
+		u64::MAX,
+		u64::MAX,
+		u64::MAX,
+		false,
+		None,
+		0,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_recipient_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		&admin_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		&String::from("Vendor"),
+		0,
+		0,
+		[0u8; 32],
+		&treasury_seed_name,
+		None,
+		None,
+		None,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_member_ix_setup(
+		treasury_pda,
+		member_pda,
+		&admin_keypair,
+		system_program::ID,
+		operator_pubkey,
+		PERMISSION_MANAGE_RECIPIENTS,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let ix = treasury_vault_ix_interface::add_member_ix_setup(
+		treasury_pda,
+		manager_member_pda,
+		&admin_keypair,
+		system_program::ID,
+		manager_pubkey,
+		PERMISSION_CONFIG,
+		&treasury_seed_name,
+		None,
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+
+	// A config manager grants ordinary permissions but no privileged one it lacks
+	let update_ix = |permissions: u16| {
+		treasury_vault_ix_interface::update_member_ix_setup(
+			treasury_pda,
+			member_pda,
+			&manager_keypair,
+			operator_pubkey,
+			permissions,
+			&treasury_seed_name,
+			Some(manager_member_pda),
+			None,
+			recent_blockhash,
+		)
+	};
+
+	let result = banks_client.process_transaction(update_ix(PERMISSION_MANAGE_RECIPIENTS | PERMISSION_EMERGENCY)).await;
+	assert!(result.is_err());
+
+	let result = banks_client.process_transaction(update_ix(PERMISSION_MANAGE_RECIPIENTS | PERMISSION_CREATE_SCHEDULE)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let member_account = banks_client.get_account(member_pda).await.unwrap().unwrap();
+	let member = Member::try_deserialize(&mut member_account.data.as_ref()).unwrap();
+	assert_eq!(member.permissions, PERMISSION_MANAGE_RECIPIENTS | PERMISSION_CREATE_SCHEDULE);
+
+	// The newly granted permission takes effect immediately
+	let ix = treasury_vault_ix_interface::create_payout_schedule_ix_setup(
+		treasury_pda,
+		recipient_pda,
+		payout_schedule_pda,
+		&operator_keypair,
+		system_program::ID,
+		recipient_address_pubkey,
+		schedule_id,
+		1_000,
+		None,
+		0,
+		86_400,
+		0,
+		Recurrence::Interval,
+		0,
+		None,
+		&treasury_seed_name,
+		Some(member_pda),
+		None,
+		recent_blockhash,
+	);
+	banks_client.process_transaction(ix).await.unwrap();
+}
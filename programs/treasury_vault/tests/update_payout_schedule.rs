@@ -105,7 +105,16 @@ async fn update_payout_schedule_ix_success() {
 		interval_seconds,
 		max_executions,
 		is_active,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
 		&treasury_seed_name,
+		None,
+		None,
+		None,
 		recent_blockhash,
 	);
 
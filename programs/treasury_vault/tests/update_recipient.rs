@@ -88,7 +88,9 @@ async fn update_recipient_ix_success() {
 		name,
 		role,
 		is_active,
+		None,
 		&treasury_seed_name,
+		None,
 		recent_blockhash,
 	);
 
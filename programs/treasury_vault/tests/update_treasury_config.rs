@@ -44,6 +44,10 @@ async fn update_treasury_config_ix_success() {
 	let require_token_gate = None;
 	let token_gate_mint = None;
 	let token_gate_amount = None;
+	let deactivation_policy = None;
+	let attester = None;
+	let require_payout_cosign = None;
+	let skip_missed_payouts = None;
 	let treasury_seed_name: String = Default::default();
 
 	// KEYPAIR
@@ -88,7 +92,15 @@ async fn update_treasury_config_ix_success() {
 		require_token_gate,
 		token_gate_mint,
 		token_gate_amount,
+		deactivation_policy,
+		attester,
+		require_payout_cosign,
+		skip_missed_payouts,
+		None,
+		None,
 		&treasury_seed_name,
+		None,
+		None,
 		recent_blockhash,
 	);
 
@@ -92,6 +92,7 @@ async fn withdraw_from_stream_ix_success() {
 		recipient_pda,
 		streaming_schedule_pda,
 		&recipient_signer_keypair,
+		recipient_signer_keypair.pubkey(),
 		stream_id,
 		&treasury_seed_name,
 		recent_blockhash,